@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use svm_merkle_tree::{DomainSeparation, HashingAlgorithm, MerkleHasher, MerkleProof, PairingMode};
+
+declare_id!("77FAYh4iohyoUxhE46QMaVfFfuPg6CR4vdeDut7JSVY1");
+
+pub const DISTRIBUTOR_SEED: &[u8] = b"distributor";
+// Keeps the claims bitmap within the 10KiB an account can be created with
+pub const MAX_NUM_NODES: u32 = 65536;
+
+// The leaf of a claim: claimant || amount, added to a MerkleTree with add_leaf
+pub fn claim_leaf(claimant: &Pubkey, amount: u64) -> Vec<u8> {
+    [&claimant.to_bytes()[..], &amount.to_le_bytes()].concat()
+}
+
+#[program]
+pub mod merkle_distributor {
+    use super::*;
+
+    // Create a distributor for a MerkleTree of claims with the given root, hashing params and domain
+    // separation mode. The
+    // vault is any token account of the mint owned by the distributor, which must be funded before
+    // claims open.
+    pub fn new_distributor(ctx: Context<NewDistributor>, root: [u8;32], algorithm: HashingAlgorithm, hash_size: u8, domain_separation: DomainSeparation, num_nodes: u32) -> Result<()> {
+        require!(num_nodes > 0 && num_nodes <= MAX_NUM_NODES, DistributorError::InvalidNumNodes);
+        let distributor = &mut ctx.accounts.distributor;
+        distributor.admin = ctx.accounts.admin.key();
+        distributor.mint = ctx.accounts.mint.key();
+        distributor.vault = ctx.accounts.vault.key();
+        distributor.root = root;
+        distributor.hash_size = algorithm.normalize_hash_size(hash_size);
+        distributor.algorithm = algorithm;
+        distributor.domain_separation = domain_separation;
+        distributor.num_nodes = num_nodes;
+        distributor.num_claimed = 0;
+        distributor.total_claimed = 0;
+        distributor.bump = ctx.bumps.distributor;
+        distributor.claimed = vec![0u8; MerkleDistributor::bitmap_len(num_nodes)];
+        Ok(())
+    }
+
+    // Claim amount to the claimant's token account with a proof of their leaf
+    pub fn claim(ctx: Context<Claim>, amount: u64, proof: MerkleProof) -> Result<()> {
+        let distributor = &mut ctx.accounts.distributor;
+        distributor.verify(&ctx.accounts.claimant.key(), amount, &proof)?;
+        distributor.set_claimed(proof.get_index())?;
+        distributor.num_claimed += 1;
+        distributor.total_claimed = distributor.total_claimed.checked_add(amount).ok_or(DistributorError::ArithmeticOverflow)?;
+        let (admin, mint, bump) = (distributor.admin, distributor.mint, distributor.bump);
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.claimant_token_account.to_account_info(),
+                    authority: ctx.accounts.distributor.to_account_info()
+                },
+                &[&[DISTRIBUTOR_SEED, admin.as_ref(), mint.as_ref(), &[bump]]]
+            ),
+            amount
+        )
+    }
+
+    // Return everything left in the vault to the admin
+    pub fn clawback(ctx: Context<Clawback>) -> Result<()> {
+        let distributor = &ctx.accounts.distributor;
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: distributor.to_account_info()
+                },
+                &[&[DISTRIBUTOR_SEED, distributor.admin.as_ref(), distributor.mint.as_ref(), &[distributor.bump]]]
+            ),
+            ctx.accounts.vault.amount
+        )
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(root: [u8;32], algorithm: HashingAlgorithm, hash_size: u8, domain_separation: DomainSeparation, num_nodes: u32)]
+pub struct NewDistributor<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = admin,
+        space = MerkleDistributor::space(num_nodes),
+        seeds = [DISTRIBUTOR_SEED, admin.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+    #[account(token::mint = mint, token::authority = distributor)]
+    pub vault: Account<'info, TokenAccount>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    pub claimant: Signer<'info>,
+    #[account(mut, has_one = vault)]
+    pub distributor: Account<'info, MerkleDistributor>,
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = distributor.mint)]
+    pub claimant_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>
+}
+
+#[derive(Accounts)]
+pub struct Clawback<'info> {
+    pub admin: Signer<'info>,
+    #[account(has_one = admin, has_one = vault)]
+    pub distributor: Account<'info, MerkleDistributor>,
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = distributor.mint)]
+    pub destination: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>
+}
+
+#[account]
+pub struct MerkleDistributor {
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub root: [u8;32],
+    pub algorithm: HashingAlgorithm,
+    pub hash_size: u8,
+    pub domain_separation: DomainSeparation,
+    pub num_nodes: u32,
+    pub num_claimed: u32,
+    pub total_claimed: u64,
+    pub bump: u8,
+    // One bit per leaf, set once it has been claimed
+    pub claimed: Vec<u8>
+}
+
+impl MerkleDistributor {
+    pub fn bitmap_len(num_nodes: u32) -> usize {
+        num_nodes.div_ceil(8) as usize
+    }
+
+    pub fn space(num_nodes: u32) -> usize {
+        8 + 32 + 32 + 32 + 32 + 1 + 1 + 1 + 4 + 4 + 8 + 1 + 4 + Self::bitmap_len(num_nodes)
+    }
+
+    pub fn is_claimed(&self, index: u32) -> bool {
+        index < self.num_nodes && self.claimed[index as usize / 8] & (1 << (index % 8)) != 0
+    }
+
+    pub fn set_claimed(&mut self, index: u32) -> Result<()> {
+        require!(index < self.num_nodes, DistributorError::InvalidProof);
+        require!(!self.is_claimed(index), DistributorError::AlreadyClaimed);
+        self.claimed[index as usize / 8] |= 1 << (index % 8);
+        Ok(())
+    }
+
+    // The proof must use this distributor's hashing params and domain separation mode, and pair by
+    // index so that each leaf has exactly one index in the bitmap. Checking it against the leaf
+    // count rejects proofs with high index bits the tree never reads.
+    pub fn verify(&self, claimant: &Pubkey, amount: u64, proof: &MerkleProof) -> Result<()> {
+        require!(
+            proof.get_algorithm() == self.algorithm
+                && proof.get_hash_size() == self.hash_size
+                && proof.get_pairing_mode() == PairingMode::Indexed
+                && proof.get_domain_separation() == self.domain_separation,
+            DistributorError::InvalidProof
+        );
+        let root = proof.merklize_strict(&claim_leaf(claimant, amount), self.num_nodes).map_err(|_| DistributorError::InvalidProof)?;
+        require!(root == self.root, DistributorError::InvalidProof);
+        Ok(())
+    }
+}
+
+#[error_code]
+pub enum DistributorError {
+    #[msg("Number of nodes must be between 1 and MAX_NUM_NODES")]
+    InvalidNumNodes,
+    #[msg("Invalid proof")]
+    InvalidProof,
+    #[msg("Leaf already claimed")]
+    AlreadyClaimed,
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow
+}
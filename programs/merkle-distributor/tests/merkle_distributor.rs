@@ -0,0 +1,178 @@
+use anchor_lang::{error::ErrorCode, system_program, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token::{self, error::TokenError};
+use merkle_distributor::{claim_leaf, DistributorError, DISTRIBUTOR_SEED};
+use solana_program_test::{processor, tokio, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account_info::AccountInfo, entrypoint::ProgramResult, instruction::{Instruction, InstructionError}, program_pack::Pack,
+    pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction, transaction::{Transaction, TransactionError}
+};
+use svm_merkle_tree::{DomainSeparation, HashingAlgorithm, MerkleProof, MerkleTree, PairingMode};
+
+// Anchor's entrypoint wants accounts that live as long as their data
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    merkle_distributor::entry(program_id, accounts, data)
+}
+
+async fn process(context: &mut ProgramTestContext, instructions: &[Instruction], signers: &[&Keypair]) -> Result<(), BanksClientError> {
+    // A fresh blockhash keeps retried transactions from being deduplicated
+    let blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let transaction = Transaction::new_signed_with_payer(instructions, Some(&context.payer.pubkey()), &all_signers, blockhash);
+    context.banks_client.process_transaction(transaction).await
+}
+
+// Check that a transaction failed in its first instruction with the given custom error code
+fn assert_error(result: Result<(), BanksClientError>, code: u32) {
+    assert_eq!(TransactionError::InstructionError(0, InstructionError::Custom(code)), result.unwrap_err().unwrap());
+}
+
+async fn create_mint(context: &mut ProgramTestContext, mint: &Keypair) {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let instructions = [
+        system_instruction::create_account(&context.payer.pubkey(), &mint.pubkey(), rent.minimum_balance(spl_token::state::Mint::LEN), spl_token::state::Mint::LEN as u64, &spl_token::ID),
+        spl_token::instruction::initialize_mint(&spl_token::ID, &mint.pubkey(), &context.payer.pubkey(), None, 0).unwrap()
+    ];
+    process(context, &instructions, &[mint]).await.unwrap();
+}
+
+async fn create_token_account(context: &mut ProgramTestContext, mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+    let account = Keypair::new();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let instructions = [
+        system_instruction::create_account(&context.payer.pubkey(), &account.pubkey(), rent.minimum_balance(spl_token::state::Account::LEN), spl_token::state::Account::LEN as u64, &spl_token::ID),
+        spl_token::instruction::initialize_account3(&spl_token::ID, &account.pubkey(), mint, owner).unwrap()
+    ];
+    process(context, &instructions, &[&account]).await.unwrap();
+    account.pubkey()
+}
+
+async fn mint_to(context: &mut ProgramTestContext, mint: &Pubkey, account: &Pubkey, amount: u64) {
+    let payer = context.payer.pubkey();
+    let instruction = spl_token::instruction::mint_to(&spl_token::ID, mint, account, &payer, &[], amount).unwrap();
+    process(context, &[instruction], &[]).await.unwrap();
+}
+
+async fn balance(context: &mut ProgramTestContext, account: &Pubkey) -> u64 {
+    let account = context.banks_client.get_account(*account).await.unwrap().unwrap();
+    spl_token::state::Account::unpack(&account.data).unwrap().amount
+}
+
+fn claim_instruction(distributor: &Pubkey, vault: &Pubkey, claimant: &Pubkey, claimant_token_account: &Pubkey, amount: u64, proof: MerkleProof) -> Instruction {
+    Instruction {
+        program_id: merkle_distributor::ID,
+        accounts: merkle_distributor::accounts::Claim {
+            claimant: *claimant,
+            distributor: *distributor,
+            vault: *vault,
+            claimant_token_account: *claimant_token_account,
+            token_program: spl_token::ID
+        }.to_account_metas(None),
+        data: merkle_distributor::instruction::Claim { amount, proof }.data()
+    }
+}
+
+fn clawback_instruction(admin: &Pubkey, distributor: &Pubkey, vault: &Pubkey, destination: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: merkle_distributor::ID,
+        accounts: merkle_distributor::accounts::Clawback {
+            admin: *admin,
+            distributor: *distributor,
+            vault: *vault,
+            destination: *destination,
+            token_program: spl_token::ID
+        }.to_account_metas(None),
+        data: merkle_distributor::instruction::Clawback {}.data()
+    }
+}
+
+#[tokio::test]
+async fn merkle_distributor_test() {
+    let mut context = ProgramTest::new("merkle_distributor", merkle_distributor::ID, processor!(process_instruction)).start_with_context().await;
+    let admin = context.payer.pubkey();
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint).await;
+
+    // A truncated keccak tree of five claims
+    let claimants: Vec<Keypair> = (0..5).map(|_| Keypair::new()).collect();
+    let amounts: Vec<u64> = (1..=5).map(|i| i * 100).collect();
+    let mut tree = MerkleTree::new(HashingAlgorithm::Keccak, 20).with_domain_separation(DomainSeparation::Tagged);
+    for (claimant, amount) in claimants.iter().zip(amounts.iter()) {
+        tree.add_leaf(&claim_leaf(&claimant.pubkey(), *amount));
+    }
+    tree.merklize().unwrap();
+    let root: [u8; 32] = tree.get_merkle_root().unwrap().try_into().unwrap();
+
+    let (distributor, _) = Pubkey::find_program_address(&[DISTRIBUTOR_SEED, admin.as_ref(), mint.pubkey().as_ref()], &merkle_distributor::ID);
+    let vault = create_token_account(&mut context, &mint.pubkey(), &distributor).await;
+    let new_distributor = Instruction {
+        program_id: merkle_distributor::ID,
+        accounts: merkle_distributor::accounts::NewDistributor {
+            admin,
+            mint: mint.pubkey(),
+            distributor,
+            vault,
+            system_program: system_program::ID
+        }.to_account_metas(None),
+        data: merkle_distributor::instruction::NewDistributor {
+            root,
+            algorithm: HashingAlgorithm::Keccak,
+            hash_size: 20,
+            domain_separation: DomainSeparation::Tagged,
+            num_nodes: claimants.len() as u32
+        }.data()
+    };
+    process(&mut context, &[new_distributor], &[]).await.unwrap();
+    mint_to(&mut context, &mint.pubkey(), &vault, amounts.iter().sum()).await;
+
+    let mut token_accounts = vec![];
+    for claimant in claimants.iter() {
+        token_accounts.push(create_token_account(&mut context, &mint.pubkey(), &claimant.pubkey()).await);
+    }
+
+    for i in 0..3 {
+        let proof = tree.merkle_proof_index(i).unwrap();
+        // Neither a different amount nor someone else's proof verifies
+        let inflated = claim_instruction(&distributor, &vault, &claimants[i].pubkey(), &token_accounts[i], amounts[i] + 1, proof.clone());
+        assert_error(process(&mut context, &[inflated], &[&claimants[i]]).await, DistributorError::InvalidProof.into());
+        let stolen = claim_instruction(&distributor, &vault, &claimants[4].pubkey(), &token_accounts[4], amounts[i], proof.clone());
+        assert_error(process(&mut context, &[stolen], &[&claimants[4]]).await, DistributorError::InvalidProof.into());
+
+        let claim = claim_instruction(&distributor, &vault, &claimants[i].pubkey(), &token_accounts[i], amounts[i], proof.clone());
+        process(&mut context, &[claim], &[&claimants[i]]).await.unwrap();
+        assert_eq!(amounts[i], balance(&mut context, &token_accounts[i]).await);
+
+        // Each leaf may only be claimed once
+        let claim_again = claim_instruction(&distributor, &vault, &claimants[i].pubkey(), &token_accounts[i], amounts[i], proof);
+        assert_error(process(&mut context, &[claim_again], &[&claimants[i]]).await, DistributorError::AlreadyClaimed.into());
+    }
+
+    // Proofs must index a single leaf
+    let proof = tree.merkle_proof_index(3).unwrap();
+    let aliased = MerkleProof::new(HashingAlgorithm::Keccak, 20, 3 + 8, proof.get_pairing_hashes()).with_domain_separation(DomainSeparation::Tagged);
+    let claim = claim_instruction(&distributor, &vault, &claimants[3].pubkey(), &token_accounts[3], amounts[3], aliased);
+    assert_error(process(&mut context, &[claim], &[&claimants[3]]).await, DistributorError::InvalidProof.into());
+
+    // Proofs must use the distributor's domain separation mode and pair by index
+    let untagged = proof.clone().with_domain_separation(DomainSeparation::Untagged);
+    let claim = claim_instruction(&distributor, &vault, &claimants[3].pubkey(), &token_accounts[3], amounts[3], untagged);
+    assert_error(process(&mut context, &[claim], &[&claimants[3]]).await, DistributorError::InvalidProof.into());
+    let sorted = proof.clone().with_pairing_mode(PairingMode::Sorted);
+    let claim = claim_instruction(&distributor, &vault, &claimants[3].pubkey(), &token_accounts[3], amounts[3], sorted);
+    assert_error(process(&mut context, &[claim], &[&claimants[3]]).await, DistributorError::InvalidProof.into());
+
+    // Only the admin may claw back what is left
+    let admin_account = create_token_account(&mut context, &mint.pubkey(), &admin).await;
+    let outsider = Keypair::new();
+    let mut clawback = clawback_instruction(&outsider.pubkey(), &distributor, &vault, &token_accounts[4]);
+    assert_error(process(&mut context, &[clawback], &[&outsider]).await, ErrorCode::ConstraintHasOne.into());
+    clawback = clawback_instruction(&admin, &distributor, &vault, &admin_account);
+    process(&mut context, &[clawback], &[]).await.unwrap();
+    assert_eq!(amounts[3] + amounts[4], balance(&mut context, &admin_account).await);
+    assert_eq!(0, balance(&mut context, &vault).await);
+
+    // Later claims find the vault empty
+    let claim = claim_instruction(&distributor, &vault, &claimants[3].pubkey(), &token_accounts[3], amounts[3], proof);
+    assert_error(process(&mut context, &[claim], &[&claimants[3]]).await, TokenError::InsufficientFunds as u32);
+}
@@ -0,0 +1,326 @@
+#[cfg(not(target_os = "solana"))]
+use anyhow::Result;
+#[cfg(target_os = "solana")]
+use anchor_lang::Result;
+use crate::{BitcoinHeader, HashingAlgorithm, MerkleError, MerkleTree, BITCOIN_HEADER_SIZE};
+
+// Script prefix of the BIP141 witness commitment output: OP_RETURN, a 36 byte push and 0xaa21a9ed
+pub const WITNESS_COMMITMENT_HEADER: [u8;6] = [0x6a, 0x24, 0xaa, 0x21, 0xa9, 0xed];
+
+// Bitcoin displays hashes in reverse byte order. Convert an internal hash to its displayed hex.
+pub fn to_reversed_hex(hash: &[u8]) -> String {
+    hex::encode(hash.iter().rev().cloned().collect::<Vec<u8>>())
+}
+
+// Convert a displayed hash, such as a txid from a block explorer, to internal byte order
+pub fn from_reversed_hex(s: &str) -> Result<[u8;32]> {
+    let mut hash: [u8;32] = hex::decode(s)
+        .ok()
+        .and_then(|h| h.try_into().ok())
+        .ok_or(MerkleError::InvalidHashSize)?;
+    hash.reverse();
+    Ok(hash)
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize
+}
+
+impl<'a> Reader<'a> {
+    fn read(&mut self, n: usize) -> Result<&'a [u8]> {
+        let end = self.offset.checked_add(n).ok_or(MerkleError::InvalidBlock)?;
+        let b = self.data.get(self.offset..end).ok_or(MerkleError::InvalidBlock)?;
+        self.offset = end;
+        Ok(b)
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.read(4)?.try_into().unwrap()))
+    }
+
+    fn read_u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.read(8)?.try_into().unwrap()))
+    }
+
+    fn read_compact_size(&mut self) -> Result<usize> {
+        let n = match self.read(1)?[0] {
+            0xfd => u16::from_le_bytes(self.read(2)?.try_into().unwrap()) as u64,
+            0xfe => self.read_u32()? as u64,
+            0xff => self.read_u64()?,
+            n => n as u64
+        };
+        usize::try_from(n).map_err(|_| MerkleError::InvalidBlock.into())
+    }
+
+    fn read_bytes(&mut self) -> Result<Vec<u8>> {
+        let n = self.read_compact_size()?;
+        Ok(self.read(n)?.to_vec())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BitcoinTxIn {
+    pub prev_txid: [u8;32],
+    pub prev_vout: u32,
+    pub script_sig: Vec<u8>,
+    pub sequence: u32,
+    pub witness: Vec<Vec<u8>>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BitcoinTxOut {
+    pub value: u64,
+    pub script_pubkey: Vec<u8>
+}
+
+// A Bitcoin transaction with its txid, which excludes witness data, and its wtxid, which includes it
+#[derive(Debug, Clone, PartialEq)]
+pub struct BitcoinTransaction {
+    pub version: i32,
+    pub inputs: Vec<BitcoinTxIn>,
+    pub outputs: Vec<BitcoinTxOut>,
+    pub lock_time: u32,
+    txid: [u8;32],
+    wtxid: [u8;32]
+}
+
+impl BitcoinTransaction {
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader { data, offset: 0 };
+        let tx = Self::read(&mut reader)?;
+        if reader.offset != data.len() {
+            return Err(MerkleError::InvalidBlock.into())
+        }
+        Ok(tx)
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self> {
+        let start = reader.offset;
+        let version = i32::from_le_bytes(reader.read(4)?.try_into().unwrap());
+        // A zero input count is the BIP144 marker, followed by a flag of 1
+        let segwit = reader.data.get(reader.offset) == Some(&0);
+        if segwit && reader.read(2)? != [0, 1] {
+            return Err(MerkleError::InvalidBlock.into())
+        }
+        let body_start = reader.offset;
+        let mut inputs = vec![];
+        for _ in 0..reader.read_compact_size()? {
+            inputs.push(BitcoinTxIn {
+                prev_txid: reader.read(32)?.try_into().unwrap(),
+                prev_vout: reader.read_u32()?,
+                script_sig: reader.read_bytes()?,
+                sequence: reader.read_u32()?,
+                witness: vec![]
+            });
+        }
+        let mut outputs = vec![];
+        for _ in 0..reader.read_compact_size()? {
+            outputs.push(BitcoinTxOut {
+                value: reader.read_u64()?,
+                script_pubkey: reader.read_bytes()?
+            });
+        }
+        let body_end = reader.offset;
+        if segwit {
+            for input in inputs.iter_mut() {
+                for _ in 0..reader.read_compact_size()? {
+                    input.witness.push(reader.read_bytes()?);
+                }
+            }
+        }
+        let lock_time = reader.read_u32()?;
+        let raw = &reader.data[start..reader.offset];
+        // The txid hashes the legacy serialization, without the marker, flag and witnesses
+        let stripped = [&raw[..4], &reader.data[body_start..body_end], &raw[raw.len() - 4..]].concat();
+        Ok(Self {
+            version,
+            inputs,
+            outputs,
+            lock_time,
+            txid: HashingAlgorithm::Sha256d.hash(&stripped, 32).try_into().unwrap(),
+            wtxid: HashingAlgorithm::Sha256d.hash(raw, 32).try_into().unwrap()
+        })
+    }
+
+    // Transaction id in internal byte order
+    pub fn txid(&self) -> [u8;32] {
+        self.txid
+    }
+
+    // Witness transaction id in internal byte order. Equal to the txid without witness data.
+    pub fn wtxid(&self) -> [u8;32] {
+        self.wtxid
+    }
+
+    pub fn has_witness(&self) -> bool {
+        self.inputs.iter().any(|input| !input.witness.is_empty())
+    }
+}
+
+// A serialized Bitcoin block, parsed into its header and transactions
+#[derive(Debug, Clone, PartialEq)]
+pub struct BitcoinBlock {
+    pub header: BitcoinHeader,
+    pub transactions: Vec<BitcoinTransaction>
+}
+
+impl BitcoinBlock {
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let header = BitcoinHeader::parse(data.get(..BITCOIN_HEADER_SIZE).ok_or(MerkleError::InvalidBlock)?)?;
+        let mut reader = Reader { data, offset: BITCOIN_HEADER_SIZE };
+        let mut transactions = vec![];
+        for _ in 0..reader.read_compact_size()? {
+            transactions.push(BitcoinTransaction::read(&mut reader)?);
+        }
+        if transactions.is_empty() || reader.offset != data.len() {
+            return Err(MerkleError::InvalidBlock.into())
+        }
+        Ok(Self {
+            header,
+            transactions
+        })
+    }
+
+    fn tree(hashes: Vec<[u8;32]>) -> Result<MerkleTree> {
+        let mut tree = MerkleTree::new(HashingAlgorithm::Sha256d, 32);
+        tree.add_hashes(hashes.into_iter().map(|h| h.to_vec()).collect())?;
+        tree.merklize_strict()?;
+        Ok(tree)
+    }
+
+    // The tree of txids committed to by the header. Returns MutatedTree for a block with a
+    // duplicated tail of transactions (CVE-2012-2459).
+    pub fn merkle_tree(&self) -> Result<MerkleTree> {
+        Self::tree(self.transactions.iter().map(|tx| tx.txid()).collect())
+    }
+
+    // The tree of wtxids committed to by the coinbase, in which the coinbase wtxid is zero
+    pub fn witness_merkle_tree(&self) -> Result<MerkleTree> {
+        let hashes = self.transactions.iter().enumerate().map(|(i, tx)| match i {
+            0 => [0u8;32],
+            _ => tx.wtxid()
+        }).collect();
+        Self::tree(hashes)
+    }
+
+    pub fn verify_merkle_root(&self) -> Result<()> {
+        if self.merkle_tree()?.get_merkle_root()? != self.header.merkle_root {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        Ok(())
+    }
+
+    // Check the BIP141 witness commitment: the last coinbase output starting with the commitment
+    // header must hold sha256d(witness root || witness reserved value). A block without a
+    // commitment must not contain witness data.
+    pub fn verify_witness_commitment(&self) -> Result<()> {
+        let coinbase = &self.transactions[0];
+        let commitment = coinbase.outputs.iter().rev().find(|output| {
+            output.script_pubkey.len() >= 38 && output.script_pubkey.starts_with(&WITNESS_COMMITMENT_HEADER)
+        });
+        let commitment = match commitment {
+            Some(output) => &output.script_pubkey[6..38],
+            None => return match self.transactions.iter().any(|tx| tx.has_witness()) {
+                true => Err(MerkleError::InvalidWitnessCommitment.into()),
+                false => Ok(())
+            }
+        };
+        // The coinbase witness must be a single 32 byte reserved value
+        let reserved_value = match coinbase.inputs.first().map(|input| input.witness.as_slice()) {
+            Some([value]) if value.len() == 32 => value,
+            _ => return Err(MerkleError::InvalidWitnessCommitment.into())
+        };
+        let witness_root = self.witness_merkle_tree()?.get_merkle_root()?;
+        if HashingAlgorithm::Sha256d.hash(&[&witness_root[..], reserved_value].concat(), 32) != commitment {
+            return Err(MerkleError::InvalidWitnessCommitment.into())
+        }
+        Ok(())
+    }
+
+    // Check the header's Merkle root and, if present, the witness commitment
+    pub fn verify(&self) -> Result<()> {
+        self.verify_merkle_root()?;
+        self.verify_witness_commitment()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{compact_size, HashingAlgorithm, BITCOIN_HEADER_SIZE};
+    use hex_literal::hex;
+
+    use super::{from_reversed_hex, to_reversed_hex, BitcoinBlock, BitcoinTransaction, WITNESS_COMMITMENT_HEADER};
+
+    const GENESIS_BLOCK: [u8; 285] = hex!("0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a29ab5f49ffff001d1dac2b7c0101000000010000000000000000000000000000000000000000000000000000000000000000ffffffff4d04ffff001d0104455468652054696d65732030332f4a616e2f32303039204368616e63656c6c6f72206f6e206272696e6b206f66207365636f6e64206261696c6f757420666f722062616e6b73ffffffff0100f2052a01000000434104678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5fac00000000");
+
+    #[test]
+    fn bitcoin_genesis_block_test() {
+        let block = BitcoinBlock::parse(&GENESIS_BLOCK).unwrap();
+        assert_eq!(1, block.transactions.len());
+        let coinbase = &block.transactions[0];
+        assert_eq!("4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b", to_reversed_hex(&coinbase.txid()));
+        assert_eq!(coinbase.txid(), coinbase.wtxid());
+        assert_eq!(5_000_000_000, coinbase.outputs[0].value);
+        block.verify().unwrap();
+        // Trailing or missing bytes are rejected
+        assert!(BitcoinBlock::parse(&[&GENESIS_BLOCK[..], &[0]].concat()).is_err());
+        assert!(BitcoinBlock::parse(&GENESIS_BLOCK[..284]).is_err());
+    }
+
+    #[test]
+    fn bitcoin_reversed_hex_test() {
+        let txid = from_reversed_hex("4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b").unwrap();
+        assert_eq!(hex!("3ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a"), txid);
+        assert!(from_reversed_hex("4a5e1e").is_err());
+    }
+
+    // Serialize a transaction with one input and the given outputs, with or without a witness
+    fn transaction(prev_txid: [u8; 32], witness: &[Vec<u8>], outputs: &[(u64, Vec<u8>)]) -> (Vec<u8>, Vec<u8>) {
+        let input = [&prev_txid[..], &[0xff; 4], &[0x00], &[0xff; 4]].concat();
+        let outputs = [
+            compact_size(outputs.len() as u64),
+            outputs.iter().map(|(value, script)| [&value.to_le_bytes()[..], &compact_size(script.len() as u64), script].concat()).collect::<Vec<Vec<u8>>>().concat()
+        ].concat();
+        let witness = [
+            compact_size(witness.len() as u64),
+            witness.iter().map(|item| [compact_size(item.len() as u64), item.clone()].concat()).collect::<Vec<Vec<u8>>>().concat()
+        ].concat();
+        let stripped = [&2u32.to_le_bytes()[..], &[0x01], &input, &outputs, &[0u8; 4]].concat();
+        let full = [&2u32.to_le_bytes()[..], &[0x00, 0x01, 0x01], &input, &outputs, &witness, &[0u8; 4]].concat();
+        (stripped, full)
+    }
+
+    #[test]
+    fn bitcoin_witness_commitment_test() {
+        let (spend_stripped, spend) = transaction([7u8; 32], &[vec![0x30; 71], vec![0x02; 33]], &[(1000, vec![0x00, 0x14, 0xaa])]);
+        let spend_tx = BitcoinTransaction::parse(&spend).unwrap();
+        assert_eq!(HashingAlgorithm::Sha256d.hash(&spend_stripped, 32), spend_tx.txid().to_vec());
+        assert_eq!(HashingAlgorithm::Sha256d.hash(&spend, 32), spend_tx.wtxid().to_vec());
+        assert_ne!(spend_tx.txid(), spend_tx.wtxid());
+        // The witness root pairs a zero coinbase wtxid with the spend's wtxid
+        let witness_root = HashingAlgorithm::Sha256d.hash(&[&[0u8; 32][..], &spend_tx.wtxid()].concat(), 32);
+        let reserved_value = vec![0u8; 32];
+        let commitment = HashingAlgorithm::Sha256d.hash(&[witness_root, reserved_value.clone()].concat(), 32);
+        let block = |commitment: &[u8]| {
+            let (_, coinbase) = transaction([0u8; 32], std::slice::from_ref(&reserved_value), &[(50, vec![0x51]), (0, [&WITNESS_COMMITMENT_HEADER[..], commitment].concat())]);
+            let coinbase_tx = BitcoinTransaction::parse(&coinbase).unwrap();
+            let mut header = BitcoinBlock::parse(&GENESIS_BLOCK).unwrap().header;
+            header.merkle_root = HashingAlgorithm::Sha256d.hash(&[coinbase_tx.txid(), spend_tx.txid()].concat(), 32).try_into().unwrap();
+            [&header.to_bytes()[..], &[0x02], &coinbase, &spend].concat()
+        };
+        let valid = BitcoinBlock::parse(&block(&commitment)).unwrap();
+        valid.verify().unwrap();
+        // A tampered commitment or witness fails
+        assert!(BitcoinBlock::parse(&block(&[0u8; 32])).unwrap().verify().is_err());
+        let mut tampered = block(&commitment);
+        let last = tampered.len() - 5;
+        tampered[last] ^= 1;
+        let tampered = BitcoinBlock::parse(&tampered).unwrap();
+        tampered.verify_merkle_root().unwrap();
+        assert!(tampered.verify_witness_commitment().is_err());
+        // Witness data without a commitment fails
+        let no_commitment = BitcoinBlock::parse(&[&GENESIS_BLOCK[..BITCOIN_HEADER_SIZE], &[0x02], &GENESIS_BLOCK[BITCOIN_HEADER_SIZE + 1..], &spend].concat()).unwrap();
+        assert!(no_commitment.verify_witness_commitment().is_err());
+    }
+}
@@ -0,0 +1,211 @@
+#[cfg(not(target_arch = "wasm32"))]
+use anchor_lang::prelude::*;
+use crate::{DomainSeparation, HashingAlgorithm, MerkleError, MerkleProof, PairingMode, Result};
+
+pub const BITCOIN_HEADER_SIZE: usize = 80;
+// Easiest target allowed on mainnet
+pub const BITCOIN_MAINNET_POW_LIMIT_BITS: u32 = 0x1d00ffff;
+
+// Expand compact target bits into a big endian 256-bit target. Negative, zero and overflowing
+// targets are rejected.
+pub fn bits_to_target(bits: u32) -> Result<[u8;32]> {
+    let exponent = (bits >> 24) as usize;
+    let mantissa = bits & 0x007fffff;
+    if bits & 0x00800000 != 0 || mantissa == 0 {
+        return Err(MerkleError::InvalidBlockHeader.into())
+    }
+    let mut target = [0u8;32];
+    if exponent <= 3 {
+        target[28..].copy_from_slice(&(mantissa >> (8 * (3 - exponent))).to_be_bytes());
+    } else {
+        for (k, b) in mantissa.to_be_bytes()[1..].iter().enumerate() {
+            match (32 + k).checked_sub(exponent) {
+                Some(i) => target[i] = *b,
+                None if *b != 0 => return Err(MerkleError::InvalidBlockHeader.into()),
+                None => {}
+            }
+        }
+    }
+    if target == [0u8;32] {
+        return Err(MerkleError::InvalidBlockHeader.into())
+    }
+    Ok(target)
+}
+
+// An 80 byte Bitcoin block header. Hashes are kept in internal byte order, which is the reverse of
+// how block explorers display them.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub struct BitcoinHeader {
+    pub version: i32,
+    pub prev_block_hash: [u8;32],
+    pub merkle_root: [u8;32],
+    pub time: u32,
+    pub bits: u32,
+    pub nonce: u32
+}
+
+impl BitcoinHeader {
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        if data.len() != BITCOIN_HEADER_SIZE {
+            return Err(MerkleError::InvalidBlockHeader.into())
+        }
+        Ok(Self {
+            version: i32::from_le_bytes(data[0..4].try_into().unwrap()),
+            prev_block_hash: data[4..36].try_into().unwrap(),
+            merkle_root: data[36..68].try_into().unwrap(),
+            time: u32::from_le_bytes(data[68..72].try_into().unwrap()),
+            bits: u32::from_le_bytes(data[72..76].try_into().unwrap()),
+            nonce: u32::from_le_bytes(data[76..80].try_into().unwrap())
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [
+            &self.version.to_le_bytes()[..],
+            &self.prev_block_hash,
+            &self.merkle_root,
+            &self.time.to_le_bytes(),
+            &self.bits.to_le_bytes(),
+            &self.nonce.to_le_bytes()
+        ].concat()
+    }
+
+    pub fn block_hash(&self) -> [u8;32] {
+        HashingAlgorithm::Sha256d.hash(&self.to_bytes(), 32).try_into().unwrap()
+    }
+
+    pub fn target(&self) -> Result<[u8;32]> {
+        bits_to_target(self.bits)
+    }
+
+    // The block hash, read as a little endian integer, must not exceed the target
+    pub fn check_proof_of_work(&self) -> Result<()> {
+        self.check_block_hash(self.block_hash())
+    }
+
+    fn check_block_hash(&self, mut hash: [u8;32]) -> Result<()> {
+        hash.reverse();
+        if hash > self.target()? {
+            return Err(MerkleError::InsufficientProofOfWork.into())
+        }
+        Ok(())
+    }
+
+    // Verify a Sha256d MerkleProof from a txid, in internal byte order, to this header's Merkle root
+    // in a block of tx_count transactions. The count fixes the depth of the proof, so that the 64
+    // byte preimage of an inner node can't be passed off as a transaction, and rejects proofs
+    // through a duplicated pair (CVE-2012-2459). It must come from a trusted source, such as the
+    // block itself.
+    pub fn verify_transaction(&self, txid: &[u8;32], proof: &MerkleProof, tx_count: u32) -> Result<()> {
+        if proof.get_algorithm() != HashingAlgorithm::Sha256d
+            || proof.get_hash_size() != 32
+            || proof.get_pairing_mode() != PairingMode::Indexed
+            || proof.get_domain_separation() != DomainSeparation::Untagged {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        if proof.merklize_hash_strict(txid, tx_count)? != self.merkle_root {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        Ok(())
+    }
+}
+
+// Check that each header links to the one before it and meets its own proof of work. Every target
+// must also be at or below max_bits, or a forger could pick trivially easy bits. This does not
+// check difficulty adjustments, so callers should set max_bits from the difficulty they expect.
+pub fn verify_header_chain(headers: &[BitcoinHeader], max_bits: u32) -> Result<()> {
+    if headers.is_empty() {
+        return Err(MerkleError::TreeEmpty.into())
+    }
+    let max_target = bits_to_target(max_bits)?;
+    let mut prev_hash: Option<[u8;32]> = None;
+    for header in headers.iter() {
+        if header.target()? > max_target {
+            return Err(MerkleError::InsufficientProofOfWork.into())
+        }
+        // Each header is hashed once, for its own proof of work and the next header's link
+        let hash = header.block_hash();
+        header.check_block_hash(hash)?;
+        if prev_hash.is_some_and(|h| h != header.prev_block_hash) {
+            return Err(MerkleError::HeaderChainBroken.into())
+        }
+        prev_hash = Some(hash);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{HashingAlgorithm, MerkleProof};
+    use hex_literal::hex;
+
+    use super::{bits_to_target, verify_header_chain, BitcoinHeader, BITCOIN_MAINNET_POW_LIMIT_BITS};
+
+    const GENESIS: [u8; 80] = hex!("0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a29ab5f49ffff001d1dac2b7c");
+    const BLOCK_1: [u8; 80] = hex!("010000006fe28c0ab6f1b372c1a6a246ae63f74f931e8365e15a089c68d6190000000000982051fd1e4ba744bbbe680e1fee14677ba1a3c3540bf7b1cdb606e857233e0e61bc6649ffff001d01e36299");
+
+    fn reversed(mut hash: [u8; 32]) -> [u8; 32] {
+        hash.reverse();
+        hash
+    }
+
+    #[test]
+    fn bitcoin_header_chain_test() {
+        let genesis = BitcoinHeader::parse(&GENESIS).unwrap();
+        let block_1 = BitcoinHeader::parse(&BLOCK_1).unwrap();
+        assert_eq!(GENESIS.to_vec(), genesis.to_bytes());
+        assert_eq!(hex!("000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"), reversed(genesis.block_hash()));
+        assert_eq!(hex!("00000000839a8e6886ab5951d76f411475428afc90947ee320161bbf18eb6048"), reversed(block_1.block_hash()));
+        assert_eq!(hex!("00000000ffff0000000000000000000000000000000000000000000000000000"), genesis.target().unwrap());
+        verify_header_chain(&[genesis.clone(), block_1.clone()], BITCOIN_MAINNET_POW_LIMIT_BITS).unwrap();
+        // Out of order headers do not link
+        assert!(verify_header_chain(&[block_1.clone(), genesis.clone()], BITCOIN_MAINNET_POW_LIMIT_BITS).is_err());
+        // A different nonce no longer meets the target
+        let mut forged = block_1.clone();
+        forged.nonce += 1;
+        assert!(forged.check_proof_of_work().is_err());
+        // Nor can a forger lower the difficulty below the limit
+        let mut easy = block_1.clone();
+        easy.bits = 0x207fffff;
+        assert!(verify_header_chain(&[genesis, easy], BITCOIN_MAINNET_POW_LIMIT_BITS).is_err());
+        // Block 1 holds only its coinbase, so its txid is the Merkle root
+        let txid = reversed(hex!("0e3e2357e806b6cdb1f70b54c3a3a17b6714ee1f0e68bebb44a74b1efd512098"));
+        block_1.verify_transaction(&txid, &MerkleProof::new(HashingAlgorithm::Sha256d, 32, 0, vec![]), 1).unwrap();
+        assert!(block_1.verify_transaction(&txid, &MerkleProof::new(HashingAlgorithm::Sha256d, 32, 0, vec![]), 2).is_err());
+    }
+
+    #[test]
+    fn bitcoin_transaction_inclusion_test() {
+        // The two transactions of block 170
+        let coinbase = reversed(hex!("b1fea52486ce0c62bb442b530a3f0132b826c74e473d1f2c220bfa78111c5082"));
+        let payment = reversed(hex!("f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16"));
+        let header = BitcoinHeader {
+            version: 1,
+            prev_block_hash: [0u8; 32],
+            merkle_root: reversed(hex!("7dac2c5666815c17a3b36427de37bb9d2e2c5ccec3f8633eb91a4205cb4c10ff")),
+            time: 0,
+            bits: BITCOIN_MAINNET_POW_LIMIT_BITS,
+            nonce: 0
+        };
+        header.verify_transaction(&coinbase, &MerkleProof::new(HashingAlgorithm::Sha256d, 32, 0, payment.to_vec()), 2).unwrap();
+        header.verify_transaction(&payment, &MerkleProof::new(HashingAlgorithm::Sha256d, 32, 1, coinbase.to_vec()), 2).unwrap();
+        assert!(header.verify_transaction(&payment, &MerkleProof::new(HashingAlgorithm::Sha256d, 32, 0, coinbase.to_vec()), 2).is_err());
+        assert!(header.verify_transaction(&payment, &MerkleProof::new(HashingAlgorithm::Sha256, 32, 1, coinbase.to_vec()), 2).is_err());
+        assert!(header.verify_transaction(&payment, &MerkleProof::new(HashingAlgorithm::Sha256d, 32, 1, coinbase.to_vec()), 3).is_err());
+        // The 64 byte preimage of the root is not a transaction of a two transaction block
+        let root = HashingAlgorithm::Sha256d.hash(&[coinbase, payment].concat(), 32);
+        assert_eq!(header.merkle_root.to_vec(), root);
+        let forged = MerkleProof::new(HashingAlgorithm::Sha256d, 32, 0, vec![]);
+        assert!(header.verify_transaction(&header.merkle_root, &forged, 2).is_err());
+    }
+
+    #[test]
+    fn bitcoin_bits_to_target_test() {
+        assert_eq!(hex!("0000000000000000000000000000000000000000000000000000000000000012"), bits_to_target(0x01120000).unwrap());
+        assert_eq!(hex!("7fffff0000000000000000000000000000000000000000000000000000000000"), bits_to_target(0x207fffff).unwrap());
+        assert!(bits_to_target(0x04923456).is_err());
+        assert!(bits_to_target(0x01003456).is_err());
+        assert!(bits_to_target(0x22123456).is_err());
+    }
+}
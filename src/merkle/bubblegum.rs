@@ -0,0 +1,199 @@
+#[cfg(not(target_arch = "wasm32"))]
+use anchor_lang::prelude::*;
+use crate::{fill_in_proof_from_canopy, recompute, ConcurrentMerkleTree, CONCURRENT_MERKLE_TREE_MAX_DEPTH, HashingAlgorithm, MerkleError, MerkleHasher, Result};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub const BUBBLEGUM_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+pub const BUBBLEGUM_LEAF_SCHEMA_V1: u8 = 1;
+
+// Asset id of a compressed NFT, derived from its tree and nonce
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_asset_id(tree: &[u8;32], nonce: u64) -> [u8;32] {
+    Pubkey::find_program_address(&[b"asset", tree, &nonce.to_le_bytes()], &BUBBLEGUM_PROGRAM_ID).0.to_bytes()
+}
+
+// Data hash of a compressed NFT from its borsh serialized MetadataArgs:
+// keccak(keccak(metadata_args) || seller_fee_basis_points)
+pub fn bubblegum_data_hash(metadata_args: &[u8], seller_fee_basis_points: u16) -> [u8;32] {
+    let metadata_args_hash = HashingAlgorithm::Keccak.hash(metadata_args, 32);
+    HashingAlgorithm::Keccak.hashv(&[&metadata_args_hash, &seller_fee_basis_points.to_le_bytes()]).try_into().unwrap()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub struct BubblegumCreator {
+    pub address: [u8;32],
+    pub verified: bool,
+    pub share: u8
+}
+
+// Creator hash of a compressed NFT: keccak of each creator's address, verified flag and share
+pub fn bubblegum_creator_hash(creators: &[BubblegumCreator]) -> [u8;32] {
+    let creator_data: Vec<Vec<u8>> = creators.iter().map(|c| [&c.address[..], &[c.verified as u8], &[c.share]].concat()).collect();
+    let slices: Vec<&[u8]> = creator_data.iter().map(|c| c.as_slice()).collect();
+    HashingAlgorithm::Keccak.hashv(&slices).try_into().unwrap()
+}
+
+// Bubblegum's LeafSchema::V1, the leaf of a compressed NFT in its spl-account-compression tree
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub struct BubblegumLeafSchema {
+    pub id: [u8;32],
+    pub owner: [u8;32],
+    pub delegate: [u8;32],
+    pub nonce: u64,
+    pub data_hash: [u8;32],
+    pub creator_hash: [u8;32]
+}
+
+impl BubblegumLeafSchema {
+    pub fn new(id: [u8;32], owner: [u8;32], delegate: [u8;32], nonce: u64, data_hash: [u8;32], creator_hash: [u8;32]) -> Self {
+        Self {
+            id,
+            owner,
+            delegate,
+            nonce,
+            data_hash,
+            creator_hash
+        }
+    }
+
+    // keccak(version || id || owner || delegate || nonce || data_hash || creator_hash), as
+    // LeafSchema::to_node computes it
+    pub fn leaf_hash(&self) -> [u8;32] {
+        HashingAlgorithm::Keccak.hashv(&[
+            &[BUBBLEGUM_LEAF_SCHEMA_V1],
+            &self.id,
+            &self.owner,
+            &self.delegate,
+            &self.nonce.to_le_bytes(),
+            &self.data_hash,
+            &self.creator_hash
+        ]).try_into().unwrap()
+    }
+
+    // Verify the leaf at index against a root, completing a proof truncated by the tree's canopy.
+    // Pass an empty canopy for a full proof.
+    pub fn verify(&self, root: &[u8;32], proof: &[[u8;32]], canopy: &[[u8;32]], max_depth: u32, index: u32) -> Result<()> {
+        if max_depth > CONCURRENT_MERKLE_TREE_MAX_DEPTH {
+            return Err(MerkleError::InvalidTreeSize.into())
+        }
+        // Check the index before it is used to read the canopy
+        if index as u64 >= 1 << max_depth {
+            return Err(MerkleError::LeafOutOfRange.into())
+        }
+        let mut proof = proof.to_vec();
+        if !canopy.is_empty() {
+            fill_in_proof_from_canopy(canopy, max_depth, index, &mut proof)?;
+        }
+        if proof.len() != max_depth as usize {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        if recompute(self.leaf_hash(), &proof, index) != *root {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        Ok(())
+    }
+
+    // Verify the leaf at index against the data of its tree account, as spl-account-compression's
+    // verify_leaf does. The root may be any root still held in the tree's change log buffer.
+    pub fn verify_in_tree_account(&self, data: &[u8], root: [u8;32], proof: &[[u8;32]], index: u32) -> Result<()> {
+        let (header, tree, canopy) = ConcurrentMerkleTree::from_account_data(data)?;
+        let mut proof = proof.to_vec();
+        fill_in_proof_from_canopy(&canopy, header.max_depth, index, &mut proof)?;
+        tree.prove_leaf(root, self.leaf_hash(), &proof, index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{update_canopy, ConcurrentMerkleTree, ConcurrentMerkleTreeHeader, HashingAlgorithm};
+    use hex_literal::hex;
+
+    use super::{bubblegum_creator_hash, bubblegum_data_hash, get_asset_id, BubblegumCreator, BubblegumLeafSchema};
+
+    fn leaf(tree: &[u8; 32], nonce: u64) -> BubblegumLeafSchema {
+        let creators = [BubblegumCreator { address: [3u8; 32], verified: true, share: 100 }];
+        BubblegumLeafSchema::new(
+            get_asset_id(tree, nonce),
+            [nonce as u8; 32],
+            [nonce as u8; 32],
+            nonce,
+            bubblegum_data_hash(&nonce.to_le_bytes(), 500),
+            bubblegum_creator_hash(&creators)
+        )
+    }
+
+    #[test]
+    fn bubblegum_leaf_hash_test() {
+        let schema = leaf(&[9u8; 32], 4);
+        let preimage = [
+            &[1u8][..], &schema.id, &schema.owner, &schema.delegate, &4u64.to_le_bytes(), &schema.data_hash, &schema.creator_hash
+        ].concat();
+        assert_eq!(HashingAlgorithm::Keccak.hash(&preimage, 32), schema.leaf_hash().to_vec());
+        assert_eq!(HashingAlgorithm::Keccak.hash(&[[3u8; 32].to_vec(), vec![1, 100]].concat(), 32), bubblegum_creator_hash(&[BubblegumCreator { address: [3u8; 32], verified: true, share: 100 }]).to_vec());
+        assert_ne!(get_asset_id(&[9u8; 32], 4), get_asset_id(&[9u8; 32], 5));
+    }
+
+    #[test]
+    fn bubblegum_golden_test() {
+        // Golden hashes from mpl-bubblegum 1.4.0's get_asset_id, hash_metadata, hash_creators and
+        // LeafSchema::hash for the MetadataArgs of "Bubblegum #4": symbol BGUM, 500 basis points,
+        // an unverified collection and two creators
+        let metadata_args = hex!("0c000000427562626c6567756d202334040000004247554d1a00000068747470733a2f2f6578616d706c652e636f6d2f342e6a736f6ef401000101000100010005050505050505050505050505050505050505050505050505050505050505050000020000000303030303030303030303030303030303030303030303030303030303030303013c04040404040404040404040404040404040404040404040404040404040404040028");
+        let creators = [
+            BubblegumCreator { address: [3u8; 32], verified: true, share: 60 },
+            BubblegumCreator { address: [4u8; 32], verified: false, share: 40 }
+        ];
+        let id = get_asset_id(&[9u8; 32], 4);
+        assert_eq!(hex!("7341b34fbf9e404edbb397ec365c7e93322216229154bb024d892f68ab81cfd8"), id);
+        let data_hash = bubblegum_data_hash(&metadata_args, 500);
+        assert_eq!(hex!("7f9a128e566808f3793e5d372f97beaeae7b5350db7a8f6d04b2036c10e3a3d3"), data_hash);
+        let creator_hash = bubblegum_creator_hash(&creators);
+        assert_eq!(hex!("ef0a141916cbc8190c18c0e9ba25d52003285828682bc5e69be2458ba2bdd2b9"), creator_hash);
+        let schema = BubblegumLeafSchema::new(id, [1u8; 32], [2u8; 32], 4, data_hash, creator_hash);
+        assert_eq!(hex!("ac394c062814389068797b6265580b28212f9919af3d644baf9f725f302b7d67"), schema.leaf_hash());
+    }
+
+    #[test]
+    fn bubblegum_max_depth_test() {
+        let schema = leaf(&[9u8; 32], 0);
+        // Depths past what spl-account-compression supports are rejected rather than overflowing
+        for max_depth in [31, 63, 64, 200, u32::MAX] {
+            assert!(schema.verify(&[0u8; 32], &vec![[0u8; 32]; 64], &[], max_depth, 0).is_err());
+            assert!(schema.verify(&[0u8; 32], &[], &[[0u8; 32]; 2], max_depth, 0).is_err());
+        }
+    }
+
+    #[test]
+    fn bubblegum_proof_test() {
+        let tree_id = [9u8; 32];
+        let mut tree = ConcurrentMerkleTree::new(5, 8).unwrap();
+        tree.initialize().unwrap();
+        let mut canopy = vec![[0u8; 32]; 6];
+        let leaves: Vec<BubblegumLeafSchema> = (0..6).map(|nonce| leaf(&tree_id, nonce)).collect();
+        for schema in leaves.iter() {
+            tree.append(schema.leaf_hash()).unwrap();
+            update_canopy(&mut canopy, 5, tree.get_change_log()).unwrap();
+        }
+        let root = tree.get_root();
+        let hashes: Vec<[u8; 32]> = leaves.iter().map(|schema| schema.leaf_hash()).collect();
+        let data = [ConcurrentMerkleTreeHeader::new(5, 8, [7u8; 32], 1).to_bytes(), tree.to_bytes(), canopy.concat()].concat();
+        for (i, schema) in leaves.iter().enumerate() {
+            let full_proof = ConcurrentMerkleTree::merkle_proof_from_leaves(&hashes, 5, i as u32).unwrap();
+            schema.verify(&root, &full_proof, &[], 5, i as u32).unwrap();
+            // A canopy of depth 2 caches the top two nodes of every proof
+            schema.verify(&root, &full_proof[..3], &canopy, 5, i as u32).unwrap();
+            schema.verify_in_tree_account(&data, root, &full_proof[..3], i as u32).unwrap();
+            // A transferred leaf no longer verifies
+            let mut transferred = schema.clone();
+            transferred.owner = [0xff; 32];
+            assert!(transferred.verify(&root, &full_proof, &[], 5, i as u32).is_err());
+            assert!(transferred.verify_in_tree_account(&data, root, &full_proof[..3], i as u32).is_err());
+        }
+        assert!(leaves[0].verify(&root, &[], &[], 5, 0).is_err());
+        // Indices outside of the tree are rejected before the canopy is read
+        assert!(leaves[0].verify(&root, &[[0u8; 32]; 3], &canopy, 5, 32).is_err());
+        assert!(leaves[0].verify(&root, &[[0u8; 32]; 3], &canopy, 5, u32::MAX).is_err());
+    }
+}
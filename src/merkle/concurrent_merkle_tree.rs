@@ -0,0 +1,792 @@
+use crate::{HashingAlgorithm, MerkleError, Result};
+
+// A concurrent Merkle tree that is byte-compatible with spl-account-compression's
+// ConcurrentMerkleTree<MAX_DEPTH, MAX_BUFFER_SIZE>. Depth and buffer size are runtime parameters,
+// and the tree can be read from and written to the exact #[repr(C)] layout used on-chain.
+
+pub const EMPTY_NODE: [u8;32] = [0u8;32];
+pub const CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1: usize = 56;
+pub const CONCURRENT_MERKLE_TREE_MAX_DEPTH: u32 = 30;
+
+// (max_depth, max_buffer_size) pairs accepted by the spl-account-compression program
+pub const CONCURRENT_MERKLE_TREE_SIZES: [(u32, u32); 34] = [
+    (3, 8), (5, 8), (6, 16), (7, 16), (8, 16), (9, 16), (10, 32), (11, 32), (12, 32), (13, 32),
+    (14, 64), (14, 256), (14, 1024), (14, 2048), (15, 64), (16, 64), (17, 64), (18, 64), (19, 64),
+    (20, 64), (20, 256), (20, 1024), (20, 2048), (24, 64), (24, 256), (24, 512), (24, 1024),
+    (24, 2048), (26, 512), (26, 1024), (26, 2048), (30, 512), (30, 1024), (30, 2048)
+];
+
+// Nodes are always full keccak256 hashes
+fn hashv(left: &[u8;32], right: &[u8;32]) -> [u8;32] {
+    let mut node = [0u8;32];
+    node.copy_from_slice(&HashingAlgorithm::Keccak.hash(&[left.as_slice(), right.as_slice()].concat(), 32));
+    node
+}
+
+pub fn hash_to_parent(node: &mut [u8;32], sibling: &[u8;32], is_left: bool) {
+    *node = match is_left {
+        true => hashv(node, sibling),
+        false => hashv(sibling, node)
+    };
+}
+
+// Recompute the root of a tree from a leaf, its proof and its index
+pub fn recompute(leaf: [u8;32], proof: &[[u8;32]], index: u32) -> [u8;32] {
+    let mut node = leaf;
+    for (i, sibling) in proof.iter().enumerate() {
+        hash_to_parent(&mut node, sibling, (index >> i) & 1 == 0);
+    }
+    node
+}
+
+// The root of an empty subtree of the given height
+pub fn empty_node(level: u32) -> [u8;32] {
+    let mut node = EMPTY_NODE;
+    for _ in 0..level {
+        node = hashv(&node, &node);
+    }
+    node
+}
+
+fn empty_nodes(max_depth: u32) -> Vec<[u8;32]> {
+    let mut nodes = vec![EMPTY_NODE];
+    for i in 0..max_depth as usize {
+        nodes.push(hashv(&nodes[i], &nodes[i]));
+    }
+    nodes
+}
+
+// Pad a partial proof to the full depth of the tree with empty nodes
+pub fn fill_in_proof(proof: &[[u8;32]], max_depth: u32) -> Vec<[u8;32]> {
+    let mut full_proof = proof.to_vec();
+    for i in proof.len()..max_depth as usize {
+        full_proof.push(empty_node(i as u32));
+    }
+    full_proof.truncate(max_depth as usize);
+    full_proof
+}
+
+fn check_leaf_index(index: u32, max_depth: u32) -> Result<()> {
+    if index as u64 >= 1 << max_depth {
+        return Err(MerkleError::LeafOutOfRange.into())
+    }
+    Ok(())
+}
+
+// Number of proof nodes cached by a canopy, which is a full binary tree without its root
+fn get_cached_path_length(canopy: &[[u8;32]], max_depth: u32) -> Result<u32> {
+    if max_depth > CONCURRENT_MERKLE_TREE_MAX_DEPTH {
+        return Err(MerkleError::InvalidTreeSize.into())
+    }
+    let closest_power_of_2 = (canopy.len() + 2) as u64;
+    if closest_power_of_2 & (closest_power_of_2 - 1) != 0 || closest_power_of_2 > 1 << (max_depth + 1) {
+        return Err(MerkleError::CanopyLengthMismatch.into())
+    }
+    Ok(closest_power_of_2.trailing_zeros() - 1)
+}
+
+// Complete a proof that was truncated by the length of the canopy. Upper nodes are read from the
+// canopy, falling back to empty nodes where the canopy has not been written yet.
+pub fn fill_in_proof_from_canopy(canopy: &[[u8;32]], max_depth: u32, index: u32, proof: &mut Vec<[u8;32]>) -> Result<()> {
+    let path_len = get_cached_path_length(canopy, max_depth)?;
+    check_leaf_index(index, max_depth)?;
+    let mut node_idx = ((1u64 << max_depth) + index as u64) >> (max_depth - path_len);
+    let mut inferred_nodes = vec![];
+    while node_idx > 1 {
+        // node_idx - 2 maps to the canopy index
+        let shifted_index = node_idx as usize - 2;
+        let cached_idx = match shifted_index % 2 == 0 {
+            true => shifted_index + 1,
+            false => shifted_index - 1
+        };
+        match canopy[cached_idx] == EMPTY_NODE {
+            true => inferred_nodes.push(empty_node(max_depth - (63 - node_idx.leading_zeros()))),
+            false => inferred_nodes.push(canopy[cached_idx])
+        }
+        node_idx >>= 1;
+    }
+    // Only add as many canopy nodes as are needed to reach the depth of the tree
+    let overlap = (proof.len() + inferred_nodes.len()).saturating_sub(max_depth as usize);
+    proof.extend(inferred_nodes.iter().skip(overlap));
+    Ok(())
+}
+
+// Write the upper nodes of a changed path into the canopy
+pub fn update_canopy(canopy: &mut [[u8;32]], max_depth: u32, change_log: &ChangeLog) -> Result<()> {
+    let path_len = get_cached_path_length(canopy, max_depth)?;
+    for level in (max_depth - path_len..max_depth).rev() {
+        let node_idx = (1u64 << (max_depth - level)) + (change_log.index >> level) as u64;
+        canopy[node_idx as usize - 2] = change_log.path[level as usize];
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangeLog {
+    pub root: [u8;32],
+    pub path: Vec<[u8;32]>,
+    pub index: u32
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConcurrentMerkleTreePath {
+    pub proof: Vec<[u8;32]>,
+    pub leaf: [u8;32],
+    pub index: u32
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConcurrentMerkleTreeHeader {
+    pub max_buffer_size: u32,
+    pub max_depth: u32,
+    pub authority: [u8;32],
+    pub creation_slot: u64
+}
+
+#[derive(Debug, Clone)]
+pub struct ConcurrentMerkleTree {
+    max_depth: u32,
+    max_buffer_size: u32,
+    sequence_number: u64,
+    active_index: u64,
+    buffer_size: u64,
+    change_logs: Vec<ChangeLog>,
+    rightmost_proof: ConcurrentMerkleTreePath
+}
+
+impl ChangeLog {
+    fn new(max_depth: u32) -> Self {
+        Self {
+            root: EMPTY_NODE,
+            path: vec![EMPTY_NODE; max_depth as usize],
+            index: 0
+        }
+    }
+
+    pub fn get_leaf(&self) -> [u8;32] {
+        self.path[0]
+    }
+
+    // Record a leaf and its path up to the root from a valid proof
+    fn replace_and_recompute_path(&mut self, index: u32, leaf: [u8;32], proof: &[[u8;32]]) -> [u8;32] {
+        let mut node = leaf;
+        self.index = index;
+        for (i, sibling) in proof.iter().enumerate() {
+            self.path[i] = node;
+            hash_to_parent(&mut node, sibling, (index >> i) & 1 == 0);
+        }
+        self.root = node;
+        node
+    }
+
+    // Fast-forward a proof by swapping in the node where its path meets this change, or take the
+    // new leaf if the change was made to the same index
+    pub fn update_proof_or_leaf(&self, leaf_index: u32, proof: &mut [[u8;32]], leaf: &mut [u8;32]) {
+        let max_depth = self.path.len();
+        match leaf_index != self.index {
+            true => {
+                let common_path_len = ((leaf_index ^ self.index) << (32 - max_depth)).leading_zeros() as usize;
+                let critbit_index = (max_depth - 1) - common_path_len;
+                proof[critbit_index] = self.path[critbit_index];
+            },
+            false => *leaf = self.get_leaf()
+        }
+    }
+}
+
+impl ConcurrentMerkleTreeHeader {
+    pub fn new(max_depth: u32, max_buffer_size: u32, authority: [u8;32], creation_slot: u64) -> Self {
+        Self {
+            max_buffer_size,
+            max_depth,
+            authority,
+            creation_slot
+        }
+    }
+
+    // Serialize as an initialized V1 ConcurrentMerkleTree account header
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![1u8, 0u8];
+        bytes.extend_from_slice(&self.max_buffer_size.to_le_bytes());
+        bytes.extend_from_slice(&self.max_depth.to_le_bytes());
+        bytes.extend_from_slice(&self.authority);
+        bytes.extend_from_slice(&self.creation_slot.to_le_bytes());
+        bytes.extend_from_slice(&[0u8;6]);
+        bytes
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        // Account type must be ConcurrentMerkleTree and the header must be V1
+        if data.len() < CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1 || data[0] != 1 || data[1] != 0 {
+            return Err(MerkleError::InvalidTreeSize.into())
+        }
+        let mut authority = [0u8;32];
+        authority.copy_from_slice(&data[10..42]);
+        Ok(Self {
+            max_buffer_size: u32::from_le_bytes(data[2..6].try_into().unwrap()),
+            max_depth: u32::from_le_bytes(data[6..10].try_into().unwrap()),
+            authority,
+            creation_slot: u64::from_le_bytes(data[42..50].try_into().unwrap())
+        })
+    }
+}
+
+impl ConcurrentMerkleTree {
+    pub fn new(max_depth: u32, max_buffer_size: u32) -> Result<Self> {
+        // Change log bit math limits the depth to 30, and the buffer is indexed with a mask
+        if max_depth == 0 || max_depth > CONCURRENT_MERKLE_TREE_MAX_DEPTH || !max_buffer_size.is_power_of_two() {
+            return Err(MerkleError::InvalidTreeSize.into())
+        }
+        Ok(Self {
+            max_depth,
+            max_buffer_size,
+            sequence_number: 0,
+            active_index: 0,
+            buffer_size: 0,
+            change_logs: vec![ChangeLog::new(max_depth); max_buffer_size as usize],
+            rightmost_proof: ConcurrentMerkleTreePath {
+                proof: vec![EMPTY_NODE; max_depth as usize],
+                leaf: EMPTY_NODE,
+                index: 0
+            }
+        })
+    }
+
+    // Whether spl-account-compression will accept a tree with these parameters
+    pub fn is_supported_size(max_depth: u32, max_buffer_size: u32) -> bool {
+        CONCURRENT_MERKLE_TREE_SIZES.contains(&(max_depth, max_buffer_size))
+    }
+
+    // Size in bytes of the tree, excluding the account header and canopy
+    pub fn get_size(max_depth: u32, max_buffer_size: u32) -> usize {
+        let change_log_size = 32 + 32 * max_depth as usize + 8;
+        let path_size = 32 * max_depth as usize + 32 + 8;
+        24 + change_log_size * max_buffer_size as usize + path_size
+    }
+
+    // Size in bytes of a full spl-account-compression account with a canopy of the given depth
+    pub fn get_account_size(max_depth: u32, max_buffer_size: u32, canopy_depth: u32) -> usize {
+        let canopy_size = match canopy_depth {
+            0 => 0,
+            d => ((1 << (d + 1)) - 2) * 32
+        };
+        CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1 + Self::get_size(max_depth, max_buffer_size) + canopy_size
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        !(self.buffer_size == 0 && self.sequence_number == 0 && self.active_index == 0)
+    }
+
+    pub fn initialize(&mut self) -> Result<[u8;32]> {
+        if self.is_initialized() {
+            return Err(MerkleError::TreeAlreadyInitialized.into())
+        }
+        let empty = empty_nodes(self.max_depth);
+        let depth = self.max_depth as usize;
+        self.rightmost_proof.proof.copy_from_slice(&empty[..depth]);
+        self.change_logs[0].path.copy_from_slice(&empty[..depth]);
+        self.change_logs[0].root = empty[depth];
+        self.sequence_number = 0;
+        self.active_index = 0;
+        self.buffer_size = 1;
+        Ok(self.change_logs[0].root)
+    }
+
+    // Initialize a tree that already contains leaves from its root and the proof of its rightmost leaf
+    pub fn initialize_with_root(&mut self, root: [u8;32], rightmost_leaf: [u8;32], proof: &[[u8;32]], index: u32) -> Result<[u8;32]> {
+        check_leaf_index(index, self.max_depth)?;
+        if self.is_initialized() {
+            return Err(MerkleError::TreeAlreadyInitialized.into())
+        }
+        // Validate before writing so that a bad proof leaves the tree uninitialized
+        if proof.len() != self.max_depth as usize || root != recompute(rightmost_leaf, proof, index) {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        self.rightmost_proof = ConcurrentMerkleTreePath {
+            proof: proof.to_vec(),
+            leaf: rightmost_leaf,
+            index: index + 1
+        };
+        self.change_logs[0].root = root;
+        self.sequence_number = 1;
+        self.active_index = 0;
+        self.buffer_size = 1;
+        Ok(root)
+    }
+
+    pub fn prove_tree_is_empty(&self) -> Result<()> {
+        if !self.is_initialized() {
+            return Err(MerkleError::TreeNotInitialized.into())
+        }
+        if self.get_root() != empty_node(self.max_depth) {
+            return Err(MerkleError::TreeNonEmpty.into())
+        }
+        Ok(())
+    }
+
+    pub fn get_root(&self) -> [u8;32] {
+        self.get_change_log().root
+    }
+
+    pub fn get_change_log(&self) -> &ChangeLog {
+        &self.change_logs[self.active_index as usize]
+    }
+
+    pub fn get_seq(&self) -> u64 {
+        self.sequence_number
+    }
+
+    pub fn get_max_depth(&self) -> u32 {
+        self.max_depth
+    }
+
+    pub fn get_max_buffer_size(&self) -> u32 {
+        self.max_buffer_size
+    }
+
+    pub fn get_rightmost_proof(&self) -> &ConcurrentMerkleTreePath {
+        &self.rightmost_proof
+    }
+
+    // Verify that a leaf is in the tree against any root still held in the change log buffer
+    pub fn prove_leaf(&self, current_root: [u8;32], leaf: [u8;32], proof: &[[u8;32]], leaf_index: u32) -> Result<()> {
+        check_leaf_index(leaf_index, self.max_depth)?;
+        if !self.is_initialized() {
+            return Err(MerkleError::TreeNotInitialized.into())
+        }
+        if leaf_index > self.rightmost_proof.index {
+            return Err(MerkleError::LeafOutOfRange.into())
+        }
+        let mut proof = fill_in_proof(proof, self.max_depth);
+        if !self.check_valid_leaf(current_root, leaf, &mut proof, leaf_index, true)? {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        Ok(())
+    }
+
+    pub fn append(&mut self, leaf: [u8;32]) -> Result<[u8;32]> {
+        if !self.is_initialized() {
+            return Err(MerkleError::TreeNotInitialized.into())
+        }
+        if leaf == EMPTY_NODE {
+            return Err(MerkleError::CannotAppendEmptyNode.into())
+        }
+        if self.rightmost_proof.index as u64 >= 1 << self.max_depth {
+            return Err(MerkleError::TreeFull.into())
+        }
+        if self.rightmost_proof.index == 0 {
+            return self.initialize_tree_from_append(leaf);
+        }
+        let depth = self.max_depth as usize;
+        let index = self.rightmost_proof.index;
+        // The level at which the new leaf's path joins the path of the current rightmost leaf
+        let intersection = index.trailing_zeros() as usize;
+        let empty = empty_nodes(self.max_depth);
+        let mut change_list = vec![EMPTY_NODE; depth];
+        let mut intersection_node = self.rightmost_proof.leaf;
+        let mut node = leaf;
+        for (i, change) in change_list.iter_mut().enumerate() {
+            *change = node;
+            match i {
+                i if i < intersection => {
+                    // Compute proof to the appended node from empty nodes
+                    hash_to_parent(&mut intersection_node, &self.rightmost_proof.proof[i], ((index - 1) >> i) & 1 == 0);
+                    hash_to_parent(&mut node, &empty[i], true);
+                    self.rightmost_proof.proof[i] = empty[i];
+                },
+                i if i == intersection => {
+                    // Compute where the new node intersects the main tree
+                    hash_to_parent(&mut node, &intersection_node, false);
+                    self.rightmost_proof.proof[intersection] = intersection_node;
+                },
+                _ => {
+                    // Update the change list path up to the root
+                    hash_to_parent(&mut node, &self.rightmost_proof.proof[i], ((index - 1) >> i) & 1 == 0);
+                }
+            }
+        }
+        self.update_internal_counters();
+        self.change_logs[self.active_index as usize] = ChangeLog {
+            root: node,
+            path: change_list,
+            index
+        };
+        self.rightmost_proof.index += 1;
+        self.rightmost_proof.leaf = leaf;
+        Ok(node)
+    }
+
+    fn initialize_tree_from_append(&mut self, leaf: [u8;32]) -> Result<[u8;32]> {
+        let mut proof = self.rightmost_proof.proof.clone();
+        let old_root = recompute(EMPTY_NODE, &proof, 0);
+        if old_root != empty_node(self.max_depth) {
+            return Err(MerkleError::TreeAlreadyInitialized.into())
+        }
+        Ok(self.try_apply_proof(old_root, EMPTY_NODE, leaf, &mut proof, 0, false)?)
+    }
+
+    // Write a leaf into an empty slot, or append it if the slot has since been filled
+    pub fn fill_empty_or_append(&mut self, current_root: [u8;32], leaf: [u8;32], proof: &[[u8;32]], index: u32) -> Result<[u8;32]> {
+        check_leaf_index(index, self.max_depth)?;
+        if !self.is_initialized() {
+            return Err(MerkleError::TreeNotInitialized.into())
+        }
+        let mut proof = fill_in_proof(proof, self.max_depth);
+        match self.try_apply_proof(current_root, EMPTY_NODE, leaf, &mut proof, index, false) {
+            Err(MerkleError::LeafContentsModified) => self.append(leaf),
+            result => Ok(result?)
+        }
+    }
+
+    pub fn set_leaf(&mut self, current_root: [u8;32], previous_leaf: [u8;32], new_leaf: [u8;32], proof: &[[u8;32]], index: u32) -> Result<[u8;32]> {
+        check_leaf_index(index, self.max_depth)?;
+        if !self.is_initialized() {
+            return Err(MerkleError::TreeNotInitialized.into())
+        }
+        if index > self.rightmost_proof.index {
+            return Err(MerkleError::LeafOutOfRange.into())
+        }
+        let mut proof = fill_in_proof(proof, self.max_depth);
+        Ok(self.try_apply_proof(current_root, previous_leaf, new_leaf, &mut proof, index, true)?)
+    }
+
+    // Bring a proof made against an older root up to date with the current root. Fails if the
+    // root has fallen out of the change log buffer or if the leaf itself has since been modified.
+    pub fn fast_forward_proof(&self, current_root: [u8;32], leaf: [u8;32], proof: &mut [[u8;32]], leaf_index: u32) -> Result<()> {
+        check_leaf_index(leaf_index, self.max_depth)?;
+        if proof.len() != self.max_depth as usize {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        let changelog_index = self.find_root_in_changelog(current_root).ok_or(MerkleError::RootNotFound)?;
+        let mut updated_leaf = leaf;
+        if !self.fast_forward(&mut updated_leaf, proof, leaf_index, changelog_index, false) {
+            return Err(MerkleError::LeafContentsModified.into())
+        }
+        Ok(())
+    }
+
+    // Check that a proof verifies against the current root
+    pub fn check_valid_proof(&self, leaf: [u8;32], proof: &[[u8;32]], leaf_index: u32) -> bool {
+        if !self.is_initialized() || check_leaf_index(leaf_index, self.max_depth).is_err() {
+            return false
+        }
+        recompute(leaf, proof, leaf_index) == self.get_root()
+    }
+
+    // Apply every change log after `changelog_buffer_index` to the proof, returning whether the
+    // leaf was left unchanged
+    fn fast_forward(&self, leaf: &mut [u8;32], proof: &mut [[u8;32]], leaf_index: u32, changelog_buffer_index: u64, use_full_buffer: bool) -> bool {
+        let mask = self.max_buffer_size as u64 - 1;
+        let mut changelog_buffer_index = changelog_buffer_index;
+        let mut updated_leaf = *leaf;
+        loop {
+            // If use_full_buffer is false, stop once we reach the active index
+            if !use_full_buffer && changelog_buffer_index == self.active_index {
+                break;
+            }
+            changelog_buffer_index = (changelog_buffer_index + 1) & mask;
+            self.change_logs[changelog_buffer_index as usize].update_proof_or_leaf(leaf_index, proof, &mut updated_leaf);
+            // If use_full_buffer is true, make exactly one full pass of the buffer
+            if use_full_buffer && changelog_buffer_index == self.active_index {
+                break;
+            }
+        }
+        let proof_leaf_unchanged = updated_leaf == *leaf;
+        *leaf = updated_leaf;
+        proof_leaf_unchanged
+    }
+
+    fn find_root_in_changelog(&self, current_root: [u8;32]) -> Option<u64> {
+        let mask = self.max_buffer_size as u64 - 1;
+        for i in 0..self.buffer_size {
+            let j = self.active_index.wrapping_sub(i) & mask;
+            if self.change_logs[j as usize].root == current_root {
+                return Some(j);
+            }
+        }
+        None
+    }
+
+    fn check_valid_leaf(&self, current_root: [u8;32], leaf: [u8;32], proof: &mut [[u8;32]], leaf_index: u32, allow_inferred_proof: bool) -> core::result::Result<bool, MerkleError> {
+        let mask = self.max_buffer_size as u64 - 1;
+        let (changelog_index, use_full_buffer) = match self.find_root_in_changelog(current_root) {
+            Some(i) => (i, false),
+            // Without a matching root, replay the whole buffer over the proof
+            None => match allow_inferred_proof {
+                true => (self.active_index.wrapping_sub(self.buffer_size - 1) & mask, true),
+                false => return Err(MerkleError::RootNotFound)
+            }
+        };
+        let mut updatable_leaf = leaf;
+        if !self.fast_forward(&mut updatable_leaf, proof, leaf_index, changelog_index, use_full_buffer) {
+            return Err(MerkleError::LeafContentsModified)
+        }
+        Ok(self.check_valid_proof(updatable_leaf, proof, leaf_index))
+    }
+
+    // Errors are returned unconverted so that callers can match on them
+    fn try_apply_proof(&mut self, current_root: [u8;32], leaf: [u8;32], new_leaf: [u8;32], proof: &mut [[u8;32]], leaf_index: u32, allow_inferred_proof: bool) -> core::result::Result<[u8;32], MerkleError> {
+        if !self.check_valid_leaf(current_root, leaf, proof, leaf_index, allow_inferred_proof)? {
+            return Err(MerkleError::InvalidProof)
+        }
+        self.update_internal_counters();
+        Ok(self.update_buffers_from_proof(new_leaf, proof, leaf_index))
+    }
+
+    fn update_internal_counters(&mut self) {
+        let mask = self.max_buffer_size as u64 - 1;
+        self.active_index = (self.active_index + 1) & mask;
+        if self.buffer_size < self.max_buffer_size as u64 {
+            self.buffer_size += 1;
+        }
+        self.sequence_number = self.sequence_number.saturating_add(1);
+    }
+
+    fn update_buffers_from_proof(&mut self, leaf: [u8;32], proof: &[[u8;32]], index: u32) -> [u8;32] {
+        let change_log = &mut self.change_logs[self.active_index as usize];
+        let root = change_log.replace_and_recompute_path(index, leaf, proof);
+        // Update the rightmost path if possible
+        if (self.rightmost_proof.index as u64) < 1 << self.max_depth {
+            match index < self.rightmost_proof.index {
+                true => change_log.update_proof_or_leaf(self.rightmost_proof.index - 1, &mut self.rightmost_proof.proof, &mut self.rightmost_proof.leaf),
+                false => {
+                    self.rightmost_proof.proof.copy_from_slice(proof);
+                    self.rightmost_proof.index = index + 1;
+                    self.rightmost_proof.leaf = change_log.get_leaf();
+                }
+            }
+        }
+        root
+    }
+
+    // Serialize to the #[repr(C)] layout of ConcurrentMerkleTree<MAX_DEPTH, MAX_BUFFER_SIZE>
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::get_size(self.max_depth, self.max_buffer_size));
+        bytes.extend_from_slice(&self.sequence_number.to_le_bytes());
+        bytes.extend_from_slice(&self.active_index.to_le_bytes());
+        bytes.extend_from_slice(&self.buffer_size.to_le_bytes());
+        for change_log in self.change_logs.iter() {
+            bytes.extend_from_slice(&change_log.root);
+            bytes.extend_from_slice(&change_log.path.concat());
+            bytes.extend_from_slice(&change_log.index.to_le_bytes());
+            bytes.extend_from_slice(&[0u8;4]);
+        }
+        bytes.extend_from_slice(&self.rightmost_proof.proof.concat());
+        bytes.extend_from_slice(&self.rightmost_proof.leaf);
+        bytes.extend_from_slice(&self.rightmost_proof.index.to_le_bytes());
+        bytes.extend_from_slice(&[0u8;4]);
+        bytes
+    }
+
+    // Deserialize from the #[repr(C)] layout of ConcurrentMerkleTree<MAX_DEPTH, MAX_BUFFER_SIZE>
+    pub fn from_bytes(max_depth: u32, max_buffer_size: u32, data: &[u8]) -> Result<Self> {
+        let mut tree = Self::new(max_depth, max_buffer_size)?;
+        if data.len() < Self::get_size(max_depth, max_buffer_size) {
+            return Err(MerkleError::InvalidTreeSize.into())
+        }
+        let depth = max_depth as usize;
+        let read_u64 = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        let read_u32 = |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        let read_nodes = |offset: usize, count: usize| -> Vec<[u8;32]> {
+            data[offset..offset + 32 * count].chunks(32).map(|c| c.try_into().unwrap()).collect()
+        };
+        tree.sequence_number = read_u64(0);
+        tree.active_index = read_u64(8);
+        tree.buffer_size = read_u64(16);
+        if tree.active_index >= max_buffer_size as u64 || tree.buffer_size > max_buffer_size as u64 {
+            return Err(MerkleError::InvalidTreeSize.into())
+        }
+        let mut offset = 24;
+        for change_log in tree.change_logs.iter_mut() {
+            change_log.root = read_nodes(offset, 1)[0];
+            change_log.path = read_nodes(offset + 32, depth);
+            change_log.index = read_u32(offset + 32 + 32 * depth);
+            offset += 32 + 32 * depth + 8;
+        }
+        tree.rightmost_proof = ConcurrentMerkleTreePath {
+            proof: read_nodes(offset, depth),
+            leaf: read_nodes(offset + 32 * depth, 1)[0],
+            index: read_u32(offset + 32 * depth + 32)
+        };
+        Ok(tree)
+    }
+
+    // Read the header, tree and canopy from the data of a spl-account-compression tree account
+    pub fn from_account_data(data: &[u8]) -> Result<(ConcurrentMerkleTreeHeader, Self, Vec<[u8;32]>)> {
+        let header = ConcurrentMerkleTreeHeader::from_bytes(data)?;
+        let tree_data = &data[CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1..];
+        let tree = Self::from_bytes(header.max_depth, header.max_buffer_size, tree_data)?;
+        let canopy_data = &tree_data[Self::get_size(header.max_depth, header.max_buffer_size)..];
+        if canopy_data.len() % 32 != 0 {
+            return Err(MerkleError::CanopyLengthMismatch.into())
+        }
+        let canopy = canopy_data.chunks(32).map(|c| c.try_into().unwrap()).collect();
+        Ok((header, tree, canopy))
+    }
+
+    // Compute the root of a tree of the given depth holding `leaves`, padded with empty leaves
+    pub fn root_from_leaves(leaves: &[[u8;32]], max_depth: u32) -> Result<[u8;32]> {
+        Ok(Self::levels_from_leaves(leaves, max_depth)?.last().ok_or(MerkleError::TreeEmpty)?[0])
+    }
+
+    // Compute the full proof of a leaf in a tree of the given depth holding `leaves`. Off-chain
+    // indexers can use this to build proofs to submit against a compressed-account tree.
+    pub fn merkle_proof_from_leaves(leaves: &[[u8;32]], max_depth: u32, index: u32) -> Result<Vec<[u8;32]>> {
+        if index as usize >= leaves.len() {
+            return Err(MerkleError::LeafOutOfRange.into())
+        }
+        let levels = Self::levels_from_leaves(leaves, max_depth)?;
+        let empty = empty_nodes(max_depth);
+        let mut n = index as usize;
+        let mut proof = vec![];
+        for (level, nodes) in levels.iter().take(max_depth as usize).enumerate() {
+            proof.push(*nodes.get(n ^ 1).unwrap_or(&empty[level]));
+            n >>= 1;
+        }
+        Ok(proof)
+    }
+
+    fn levels_from_leaves(leaves: &[[u8;32]], max_depth: u32) -> Result<Vec<Vec<[u8;32]>>> {
+        if max_depth > CONCURRENT_MERKLE_TREE_MAX_DEPTH || leaves.len() as u64 > 1 << max_depth {
+            return Err(MerkleError::InvalidTreeSize.into())
+        }
+        let empty = empty_nodes(max_depth);
+        let mut levels = vec![leaves.to_vec()];
+        for level in 0..max_depth as usize {
+            let nodes = levels[level].chunks(2).map(|pair| match pair.len() {
+                2 => hashv(&pair[0], &pair[1]),
+                _ => hashv(&pair[0], &empty[level])
+            }).collect::<Vec<[u8;32]>>();
+            levels.push(match nodes.is_empty() {
+                true => vec![empty[level + 1]],
+                false => nodes
+            });
+        }
+        Ok(levels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spl_concurrent_merkle_tree::concurrent_merkle_tree::ConcurrentMerkleTree as SplConcurrentMerkleTree;
+
+    use super::{empty_node, fill_in_proof_from_canopy, update_canopy, ConcurrentMerkleTree, ConcurrentMerkleTreeHeader};
+
+    fn leaf(n: u32) -> [u8;32] {
+        crate::HashingAlgorithm::Keccak.hash(&n.to_le_bytes(), 32).try_into().unwrap()
+    }
+
+    #[test]
+    fn concurrent_merkle_tree_spl_parity_test() {
+        let mut spl_tree = Box::new(SplConcurrentMerkleTree::<5, 8>::new());
+        let mut tree = ConcurrentMerkleTree::new(5, 8).unwrap();
+        assert_eq!(spl_tree.initialize().unwrap(), tree.initialize().unwrap());
+        let mut leaves = vec![];
+        for n in 0..12u32 {
+            leaves.push(leaf(n));
+            assert_eq!(spl_tree.append(leaf(n)).unwrap(), tree.append(leaf(n)).unwrap());
+        }
+        // Replace a leaf against the latest root, then another against a stale root
+        let root = tree.get_root();
+        let proof = ConcurrentMerkleTree::merkle_proof_from_leaves(&leaves, 5, 3).unwrap();
+        assert_eq!(
+            spl_tree.set_leaf(root, leaves[3], leaf(100), &proof, 3).unwrap(),
+            tree.set_leaf(root, leaves[3], leaf(100), &proof, 3).unwrap()
+        );
+        let proof = ConcurrentMerkleTree::merkle_proof_from_leaves(&leaves, 5, 9).unwrap();
+        assert_eq!(
+            spl_tree.set_leaf(root, leaves[9], leaf(101), &proof, 9).unwrap(),
+            tree.set_leaf(root, leaves[9], leaf(101), &proof, 9).unwrap()
+        );
+        leaves[3] = leaf(100);
+        leaves[9] = leaf(101);
+        assert_eq!(ConcurrentMerkleTree::root_from_leaves(&leaves, 5).unwrap(), tree.get_root());
+        assert_eq!(bytemuck::bytes_of(spl_tree.as_ref()).to_vec(), tree.to_bytes());
+        assert_eq!(ConcurrentMerkleTree::get_size(5, 8), tree.to_bytes().len());
+        let restored = ConcurrentMerkleTree::from_bytes(5, 8, &tree.to_bytes()).unwrap();
+        assert_eq!(tree.to_bytes(), restored.to_bytes());
+    }
+
+    #[test]
+    fn concurrent_merkle_tree_initialize_with_root_test() {
+        let leaves: Vec<[u8;32]> = (0..5u32).map(leaf).collect();
+        let root = ConcurrentMerkleTree::root_from_leaves(&leaves, 3).unwrap();
+        let proof = ConcurrentMerkleTree::merkle_proof_from_leaves(&leaves, 3, 4).unwrap();
+        let mut tree = ConcurrentMerkleTree::new(3, 8).unwrap();
+        // A bad proof leaves the tree uninitialized
+        assert!(tree.initialize_with_root(root, leaves[3], &proof, 4).is_err());
+        assert!(!tree.is_initialized());
+        assert_eq!(root, tree.initialize_with_root(root, leaves[4], &proof, 4).unwrap());
+        assert!(tree.is_initialized());
+        assert_eq!(root, tree.get_root());
+        tree.append(leaf(5)).unwrap();
+        let leaves: Vec<[u8;32]> = (0..6u32).map(leaf).collect();
+        assert_eq!(ConcurrentMerkleTree::root_from_leaves(&leaves, 3).unwrap(), tree.get_root());
+    }
+
+    #[test]
+    fn concurrent_merkle_tree_fast_forward_test() {
+        let mut tree = ConcurrentMerkleTree::new(3, 8).unwrap();
+        tree.initialize().unwrap();
+        let mut leaves = vec![];
+        for n in 0..6u32 {
+            leaves.push(leaf(n));
+            tree.append(leaf(n)).unwrap();
+        }
+        let stale_root = tree.get_root();
+        let mut proof = ConcurrentMerkleTree::merkle_proof_from_leaves(&leaves, 3, 1).unwrap();
+        tree.set_leaf(stale_root, leaves[4], leaf(40), &ConcurrentMerkleTree::merkle_proof_from_leaves(&leaves, 3, 4).unwrap(), 4).unwrap();
+        tree.append(leaf(6)).unwrap();
+        assert!(!tree.check_valid_proof(leaves[1], &proof, 1));
+        tree.fast_forward_proof(stale_root, leaves[1], &mut proof, 1).unwrap();
+        assert!(tree.check_valid_proof(leaves[1], &proof, 1));
+        tree.prove_leaf(stale_root, leaves[1], &proof, 1).unwrap();
+        // A proof for a leaf that has since changed can't be fast-forwarded
+        let mut proof = ConcurrentMerkleTree::merkle_proof_from_leaves(&leaves, 3, 4).unwrap();
+        assert!(tree.fast_forward_proof(stale_root, leaves[4], &mut proof, 4).is_err());
+        assert!(tree.fast_forward_proof([1u8;32], leaves[1], &mut proof, 1).is_err());
+    }
+
+    #[test]
+    fn concurrent_merkle_tree_canopy_test() {
+        let mut tree = ConcurrentMerkleTree::new(5, 8).unwrap();
+        tree.initialize().unwrap();
+        let mut canopy = vec![[0u8;32]; 6];
+        let mut leaves = vec![];
+        for n in 0..20u32 {
+            leaves.push(leaf(n));
+            tree.append(leaf(n)).unwrap();
+            update_canopy(&mut canopy, 5, tree.get_change_log()).unwrap();
+        }
+        for index in [0u32, 7, 19, 25] {
+            let full_proof = ConcurrentMerkleTree::merkle_proof_from_leaves(&[leaves.clone(), vec![[0u8;32]; 12]].concat(), 5, index).unwrap();
+            let mut proof = full_proof[..3].to_vec();
+            fill_in_proof_from_canopy(&canopy, 5, index, &mut proof).unwrap();
+            assert_eq!(full_proof, proof);
+        }
+        // Indices outside of the tree are rejected rather than read past the end of the canopy
+        assert!(fill_in_proof_from_canopy(&canopy, 5, 32, &mut vec![[0u8;32]; 3]).is_err());
+        assert!(update_canopy(&mut [[0u8;32]; 5], 5, tree.get_change_log()).is_err());
+    }
+
+    #[test]
+    fn concurrent_merkle_tree_account_test() {
+        let mut tree = ConcurrentMerkleTree::new(14, 64).unwrap();
+        assert!(ConcurrentMerkleTree::is_supported_size(14, 64));
+        assert!(!ConcurrentMerkleTree::is_supported_size(14, 32));
+        assert_eq!(empty_node(14), tree.initialize().unwrap());
+        tree.prove_tree_is_empty().unwrap();
+        assert!(tree.initialize().is_err());
+        assert!(tree.append([0u8;32]).is_err());
+        tree.append(leaf(1)).unwrap();
+        assert!(tree.prove_tree_is_empty().is_err());
+
+        let header = ConcurrentMerkleTreeHeader::new(14, 64, [7u8;32], 1337);
+        let data = [header.to_bytes(), tree.to_bytes(), vec![0u8; 32 * 14]].concat();
+        assert_eq!(ConcurrentMerkleTree::get_account_size(14, 64, 3), data.len());
+        let (h, t, canopy) = ConcurrentMerkleTree::from_account_data(&data).unwrap();
+        assert_eq!(header, h);
+        assert_eq!(tree.get_root(), t.get_root());
+        assert_eq!(14, canopy.len());
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(not(target_arch = "wasm32"))]
+use anchor_lang::prelude::*;
+
+pub const DOMAIN_SEPARATION_LEAF_TAG: u8 = 0x00;
+pub const DOMAIN_SEPARATION_NODE_TAG: u8 = 0x01;
+
+// Whether leaf and internal node preimages are tagged before being hashed.
+//
+// Untagged hashing is the Bitcoin-compatible default. Because a branch is the hash of two
+// concatenated hashes, the preimage of any branch can be presented as a leaf of 2 * hash_size
+// bytes and proven against the same root. Truncated hashes make such preimages cheaper to find.
+//
+// Tagged hashing prefixes leaves with 0x00 and branches with 0x01, as in RFC 6962, so a leaf can
+// never hash to the same value as a branch.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub enum DomainSeparation {
+    #[default]
+    Untagged = 0,
+    Tagged = 1
+}
+
+impl From<DomainSeparation> for u8 {
+    fn from(value: DomainSeparation) -> Self {
+        match value {
+            DomainSeparation::Untagged => 0,
+            DomainSeparation::Tagged => 1,
+        }
+    }
+}
+
+impl From<u8> for DomainSeparation {
+    fn from(value: u8) -> DomainSeparation {
+        match value {
+            1 => DomainSeparation::Tagged,
+            _ => DomainSeparation::Untagged,
+        }
+    }
+}
+
+impl DomainSeparation {
+    // Preimage of a leaf hash
+    pub fn leaf(&self, leaf: &[u8]) -> Vec<u8> {
+        match self {
+            DomainSeparation::Untagged => leaf.to_vec(),
+            DomainSeparation::Tagged => [&[DOMAIN_SEPARATION_LEAF_TAG], leaf].concat()
+        }
+    }
+
+    // Preimage of a branch hash from its already ordered children
+    pub fn node(&self, left: &[u8], right: &[u8]) -> Vec<u8> {
+        match self {
+            DomainSeparation::Untagged => [left, right].concat(),
+            DomainSeparation::Tagged => [&[DOMAIN_SEPARATION_NODE_TAG], left, right].concat()
+        }
+    }
+}
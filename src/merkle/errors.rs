@@ -21,6 +21,56 @@ pub enum MerkleError {
     TreeEmpty,
     #[error("Invalid hash size")]
     InvalidHashSize,
+    #[error("Invalid proof")]
+    InvalidProof,
+    #[error("Merkle tree is full")]
+    TreeFull,
+    #[error("Merkle tree already initialized")]
+    TreeAlreadyInitialized,
+    #[error("Merkle tree not initialized")]
+    TreeNotInitialized,
+    #[error("Merkle tree is not empty")]
+    TreeNonEmpty,
+    #[error("Root not found in change log buffer")]
+    RootNotFound,
+    #[error("Leaf contents modified")]
+    LeafContentsModified,
+    #[error("Cannot append an empty node")]
+    CannotAppendEmptyNode,
+    #[error("Invalid tree size")]
+    InvalidTreeSize,
+    #[error("Canopy length mismatch")]
+    CanopyLengthMismatch,
+    #[error("Invalid standard merkle tree dump")]
+    InvalidTreeDump,
+    #[error("Invalid or unsupported ABI leaf encoding")]
+    InvalidLeafEncoding,
+    #[error("Merkle tree contains identical adjacent hashes")]
+    MutatedTree,
+    #[error("Merkle sum overflow")]
+    SumOverflow,
+    #[error("Value already exists in the tree")]
+    ValueAlreadyExists,
+    #[error("Invalid control block")]
+    InvalidControlBlock,
+    #[error("Invalid tweak")]
+    InvalidTweak,
+    #[error("Invalid block header")]
+    InvalidBlockHeader,
+    #[error("Insufficient proof of work")]
+    InsufficientProofOfWork,
+    #[error("Header chain broken")]
+    HeaderChainBroken,
+    #[error("Invalid block")]
+    InvalidBlock,
+    #[error("Invalid witness commitment")]
+    InvalidWitnessCommitment,
+    #[error("Input is not a valid BN254 field element")]
+    InvalidFieldElement,
+    #[error("Unbalanced tree can't be rebuilt as a MerkleTree")]
+    UnbalancedTree,
+    #[error("Hashing algorithm is not supported")]
+    UnsupportedHashingAlgorithm,
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -29,7 +79,7 @@ impl From<MerkleError> for anchor_lang::error::Error {
         let e = match value {
             MerkleError::LeafOutOfRange => AnchorError {
                 error_name: "LeafOutOfRange".to_string(),
-                error_code_number: ERROR_CODE_OFFSET + 1337 + 0,
+                error_code_number: ERROR_CODE_OFFSET + 1337,
                 error_msg: value.to_string(),
                 error_origin: None,
                 compared_values: None
@@ -69,6 +119,181 @@ impl From<MerkleError> for anchor_lang::error::Error {
                 error_origin: None,
                 compared_values: None
             },
+            MerkleError::InvalidProof => AnchorError {
+                error_name: "InvalidProof".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 6,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::TreeFull => AnchorError {
+                error_name: "TreeFull".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 7,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::TreeAlreadyInitialized => AnchorError {
+                error_name: "TreeAlreadyInitialized".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 8,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::TreeNotInitialized => AnchorError {
+                error_name: "TreeNotInitialized".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 9,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::TreeNonEmpty => AnchorError {
+                error_name: "TreeNonEmpty".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 10,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::RootNotFound => AnchorError {
+                error_name: "RootNotFound".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 11,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::LeafContentsModified => AnchorError {
+                error_name: "LeafContentsModified".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 12,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::CannotAppendEmptyNode => AnchorError {
+                error_name: "CannotAppendEmptyNode".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 13,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::InvalidTreeSize => AnchorError {
+                error_name: "InvalidTreeSize".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 14,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::CanopyLengthMismatch => AnchorError {
+                error_name: "CanopyLengthMismatch".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 15,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::InvalidTreeDump => AnchorError {
+                error_name: "InvalidTreeDump".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 16,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::InvalidLeafEncoding => AnchorError {
+                error_name: "InvalidLeafEncoding".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 17,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::MutatedTree => AnchorError {
+                error_name: "MutatedTree".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 18,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::SumOverflow => AnchorError {
+                error_name: "SumOverflow".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 19,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::ValueAlreadyExists => AnchorError {
+                error_name: "ValueAlreadyExists".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 20,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::InvalidControlBlock => AnchorError {
+                error_name: "InvalidControlBlock".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 21,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::InvalidTweak => AnchorError {
+                error_name: "InvalidTweak".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 22,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::InvalidBlockHeader => AnchorError {
+                error_name: "InvalidBlockHeader".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 23,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::InsufficientProofOfWork => AnchorError {
+                error_name: "InsufficientProofOfWork".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 24,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::HeaderChainBroken => AnchorError {
+                error_name: "HeaderChainBroken".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 25,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::InvalidBlock => AnchorError {
+                error_name: "InvalidBlock".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 26,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::InvalidWitnessCommitment => AnchorError {
+                error_name: "InvalidWitnessCommitment".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 27,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::InvalidFieldElement => AnchorError {
+                error_name: "InvalidFieldElement".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 28,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::UnbalancedTree => AnchorError {
+                error_name: "UnbalancedTree".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 29,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
+            MerkleError::UnsupportedHashingAlgorithm => AnchorError {
+                error_name: "UnsupportedHashingAlgorithm".to_string(),
+                error_code_number: ERROR_CODE_OFFSET + 1337 + 30,
+                error_msg: value.to_string(),
+                error_origin: None,
+                compared_values: None
+            },
         };
         Error::AnchorError(Box::new(e))
     }
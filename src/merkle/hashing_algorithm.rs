@@ -14,15 +14,47 @@ pub mod hashing_wasm {
         hasher.update(val);
         hasher.finalize().into()
     }
+
+    pub fn sha256v(vals: &[&[u8]]) -> [u8;32] {
+        let mut hasher = Sha256::new();
+        vals.iter().for_each(|val| hasher.update(val));
+        hasher.finalize().into()
+    }
+
+    pub fn keccak256v(vals: &[&[u8]]) -> [u8;32] {
+        let mut hasher = Keccak256::new();
+        vals.iter().for_each(|val| hasher.update(val));
+        hasher.finalize().into()
+    }
+
+    pub fn blake3(val: &[u8]) -> [u8;32] {
+        blake3::hash(val).into()
+    }
+
+    pub fn blake3v(vals: &[&[u8]]) -> [u8;32] {
+        let mut hasher = blake3::Hasher::new();
+        vals.iter().for_each(|val| { hasher.update(val); });
+        hasher.finalize().into()
+    }
+
+    // Poseidon over 1 to 12 big endian BN254 field elements, with the parameters of sol_poseidon
+    pub fn poseidon(elements: &[&[u8]]) -> [u8;32] {
+        use ark_bn254::Fr;
+        use light_poseidon::{Poseidon, PoseidonBytesHasher};
+        let mut hasher = Poseidon::<Fr>::new_circom(elements.len()).unwrap();
+        hasher.hash_bytes_be(elements).unwrap()
+    }
 }
 #[cfg(target_arch = "wasm32")]
-use hashing_wasm::{sha256, keccak256};
+use hashing_wasm::{sha256, keccak256, blake3, poseidon, sha256v, keccak256v, blake3v};
 
 #[cfg(not(target_arch = "wasm32"))]
 use anchor_lang::prelude::*;
 #[cfg(not(target_arch = "wasm32"))]
 mod hashing {
-    use solana_program::{hash, keccak};
+    #[cfg(any(not(target_os = "solana"), feature = "blake3-syscall"))]
+    use solana_program::blake3 as blake3_hash;
+    use solana_program::{hash, keccak, poseidon as poseidon_hash};
 
     pub fn sha256(val: &[u8]) -> [u8;32] {
         hash::hash(val).to_bytes()
@@ -31,17 +63,109 @@ mod hashing {
     pub fn keccak256(val: &[u8]) -> [u8;32] {
         keccak::hash(val).to_bytes()
     }
+
+    // Uses the blake3 syscall on Solana and the SIMD-accelerated blake3 crate elsewhere. The
+    // syscall is behind the blake3_syscall_enabled feature gate, which isn't active on mainnet-beta,
+    // and a program that links it can't be deployed where it isn't. On Solana it is only linked with
+    // the blake3-syscall feature. Without it, decoding Blake3 from bytes fails with
+    // UnsupportedHashingAlgorithm, and hashing with a variant built in code panics.
+    #[cfg(any(not(target_os = "solana"), feature = "blake3-syscall"))]
+    pub fn blake3(val: &[u8]) -> [u8;32] {
+        blake3_hash::hash(val).to_bytes()
+    }
+
+    #[cfg(all(target_os = "solana", not(feature = "blake3-syscall")))]
+    pub fn blake3(_val: &[u8]) -> [u8;32] {
+        panic!("Blake3 needs the blake3-syscall feature on Solana")
+    }
+
+    // Hash several slices in a single syscall on Solana
+    pub fn sha256v(vals: &[&[u8]]) -> [u8;32] {
+        hash::hashv(vals).to_bytes()
+    }
+
+    pub fn keccak256v(vals: &[&[u8]]) -> [u8;32] {
+        keccak::hashv(vals).to_bytes()
+    }
+
+    #[cfg(any(not(target_os = "solana"), feature = "blake3-syscall"))]
+    pub fn blake3v(vals: &[&[u8]]) -> [u8;32] {
+        blake3_hash::hashv(vals).to_bytes()
+    }
+
+    #[cfg(all(target_os = "solana", not(feature = "blake3-syscall")))]
+    pub fn blake3v(_vals: &[&[u8]]) -> [u8;32] {
+        panic!("Blake3 needs the blake3-syscall feature on Solana")
+    }
+
+    // Poseidon over 1 to 12 big endian BN254 field elements. Uses the sol_poseidon syscall on
+    // Solana and light-poseidon elsewhere.
+    pub fn poseidon(elements: &[&[u8]]) -> [u8;32] {
+        use poseidon_hash::{Endianness, Parameters};
+        poseidon_hash::hashv(Parameters::Bn254X5, Endianness::BigEndian, elements).unwrap().to_bytes()
+    }
 }
 #[cfg(not(target_arch = "wasm32"))]
-use hashing::{sha256, keccak256};
+use hashing::{sha256, keccak256, blake3, poseidon, sha256v, keccak256v, blake3v};
+
+// Modulus of the BN254 scalar field, big endian
+pub const BN254_MODULUS: [u8;32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01
+];
 
+// Maximum number of inputs of a single Poseidon permutation
+pub const POSEIDON_MAX_INPUTS: usize = 12;
+
+// Encode bytes as BN254 field elements. The first element is the length of the input in bytes,
+// followed by the input split into 32 byte big endian chunks, with a shorter last chunk left padded
+// with zeros. The length prefix keeps the encoding injective, so [1] and [0, 1] encode differently.
+// Chunks are not reduced modulo the field, so like sol_poseidon and light-poseidon, a chunk that
+// isn't below the modulus is rejected.
+pub fn to_field_elements(b: &[u8]) -> Result<Vec<[u8;32]>> {
+    let mut length = [0u8;32];
+    length[24..].copy_from_slice(&(b.len() as u64).to_be_bytes());
+    let mut elements = vec![length];
+    for chunk in b.chunks(32) {
+        let mut element = [0u8;32];
+        element[32 - chunk.len()..].copy_from_slice(chunk);
+        if element >= BN254_MODULUS {
+            return Err(MerkleError::InvalidFieldElement.into())
+        }
+        elements.push(element);
+    }
+    Ok(elements)
+}
+
+// Poseidon hash of bytes encoded as field elements. Inputs of more than 12 elements are absorbed
+// in turn, each hash taking the previous result followed by up to 11 new elements. Hashes are
+// field elements, so only leaf data can fail to encode, and hashing it panics.
+fn poseidonv(vals: &[&[u8]]) -> [u8;32] {
+    let elements = to_field_elements(&vals.concat()).expect("Poseidon input must encode as BN254 field elements");
+    let (first, rest) = elements.split_at(elements.len().min(POSEIDON_MAX_INPUTS));
+    let inputs: Vec<&[u8]> = first.iter().map(|e| e.as_slice()).collect();
+    let mut h = poseidon(&inputs);
+    for chunk in rest.chunks(POSEIDON_MAX_INPUTS - 1) {
+        let inputs: Vec<&[u8]> = [&h].into_iter().chain(chunk.iter()).map(|e| e.as_slice()).collect();
+        h = poseidon(&inputs);
+    }
+    h
+}
+
+use super::MerkleHasher;
+use crate::{MerkleError, Result};
+
+// Serialized as a single byte. Deserializing checks the algorithm is supported on this target.
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize))]
 pub enum HashingAlgorithm {
     Sha256 = 0,
     Sha256d = 1,
     Keccak = 2,
-    Keccakd = 3
+    Keccakd = 3,
+    Blake3 = 4,
+    Blake3d = 5,
+    Poseidon = 6
 }
 
 impl From<HashingAlgorithm> for u8 {
@@ -51,22 +175,54 @@ impl From<HashingAlgorithm> for u8 {
             HashingAlgorithm::Sha256d => 1,
             HashingAlgorithm::Keccak => 2,
             HashingAlgorithm::Keccakd => 3,
+            HashingAlgorithm::Blake3 => 4,
+            HashingAlgorithm::Blake3d => 5,
+            HashingAlgorithm::Poseidon => 6,
         }
     }
 }
 
-impl From<u8> for HashingAlgorithm {
-    fn from(value: u8) -> HashingAlgorithm {
-        match value {
+// Returns UnsupportedHashingAlgorithm for unknown values, and for Blake3 on Solana without the
+// blake3-syscall feature, so untrusted account data or instruction data can't select it
+impl TryFrom<u8> for HashingAlgorithm {
+    type Error = MerkleError;
+
+    fn try_from(value: u8) -> core::result::Result<HashingAlgorithm, MerkleError> {
+        let algorithm = match value {
+            0 => HashingAlgorithm::Sha256,
             1 => HashingAlgorithm::Sha256d,
             2 => HashingAlgorithm::Keccak,
             3 => HashingAlgorithm::Keccakd,
-            _ => HashingAlgorithm::Sha256,
+            4 => HashingAlgorithm::Blake3,
+            5 => HashingAlgorithm::Blake3d,
+            6 => HashingAlgorithm::Poseidon,
+            _ => return Err(MerkleError::UnsupportedHashingAlgorithm),
+        };
+        match algorithm.is_supported() {
+            true => Ok(algorithm),
+            false => Err(MerkleError::UnsupportedHashingAlgorithm)
         }
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+impl AnchorDeserialize for HashingAlgorithm {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let value = u8::deserialize_reader(reader)?;
+        HashingAlgorithm::try_from(value)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()))
+    }
+}
+
 impl HashingAlgorithm {
+    // Blake3 is only available on Solana with the blake3-syscall feature
+    pub fn is_supported(&self) -> bool {
+        match self {
+            HashingAlgorithm::Blake3 | HashingAlgorithm::Blake3d => cfg!(any(not(target_os = "solana"), feature = "blake3-syscall")),
+            _ => true
+        }
+    }
+
     pub fn hash(&self, b: &[u8], s: usize) -> Vec<u8> {
         let s = match s == 0 || s > 32 {
             true => 32,
@@ -75,7 +231,9 @@ impl HashingAlgorithm {
         match self {
             HashingAlgorithm::Sha256 => sha256(b)[..s].to_vec(),
             HashingAlgorithm::Keccak => keccak256(b)[..s].to_vec(),
-            HashingAlgorithm::Sha256d | HashingAlgorithm::Keccakd => self.double_hash(b, s)
+            HashingAlgorithm::Blake3 => blake3(b)[..s].to_vec(),
+            HashingAlgorithm::Poseidon => poseidonv(&[b])[..s].to_vec(),
+            HashingAlgorithm::Sha256d | HashingAlgorithm::Keccakd | HashingAlgorithm::Blake3d => self.double_hash(b, s)
         }
     }
 
@@ -87,6 +245,32 @@ impl HashingAlgorithm {
         match self {
             HashingAlgorithm::Sha256 | HashingAlgorithm::Sha256d => sha256(&sha256(b))[..s].to_vec(),
             HashingAlgorithm::Keccak | HashingAlgorithm::Keccakd => keccak256(&keccak256(b))[..s].to_vec(),
+            HashingAlgorithm::Blake3 | HashingAlgorithm::Blake3d => blake3(&blake3(b))[..s].to_vec(),
+            HashingAlgorithm::Poseidon => poseidonv(&[&poseidonv(&[b])])[..s].to_vec(),
+        }
+    }
+}
+
+// The built-in hashers. Double hashing variants hash every node twice.
+impl MerkleHasher for HashingAlgorithm {
+    fn output_size(&self) -> usize {
+        32
+    }
+
+    fn hashv(&self, vals: &[&[u8]]) -> Vec<u8> {
+        match self {
+            HashingAlgorithm::Sha256 => sha256v(vals).to_vec(),
+            HashingAlgorithm::Sha256d => sha256(&sha256v(vals)).to_vec(),
+            HashingAlgorithm::Keccak => keccak256v(vals).to_vec(),
+            HashingAlgorithm::Keccakd => keccak256(&keccak256v(vals)).to_vec(),
+            HashingAlgorithm::Blake3 => blake3v(vals).to_vec(),
+            HashingAlgorithm::Blake3d => blake3(&blake3v(vals)).to_vec(),
+            HashingAlgorithm::Poseidon => poseidonv(vals).to_vec()
         }
     }
+
+    // Leaves are hashed exactly twice, including for the double hashing variants
+    fn double_hash(&self, val: &[u8]) -> Vec<u8> {
+        HashingAlgorithm::double_hash(self, val, 32)
+    }
 }
@@ -0,0 +1,146 @@
+#[cfg(not(target_arch = "wasm32"))]
+use anchor_lang::prelude::*;
+use crate::{HashingAlgorithm, MerkleError, Result};
+
+// An append-only Merkle tree that only stores the right-edge "frontier" of the tree, one node per
+// level. It produces the same root as MerkleTree::merklize over the same leaves, including the
+// Bitcoin rule of pairing the last node of an odd level with itself.
+#[derive(Debug, Clone)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub struct IncrementalMerkleTree {
+    algorithm: HashingAlgorithm,
+    hash_size: u8,
+    count: u64,
+    frontier: Vec<Vec<u8>>
+}
+
+impl IncrementalMerkleTree {
+    pub fn new(algorithm: HashingAlgorithm, hash_size: u8) -> Self {
+        let mut hash_size = hash_size;
+        if hash_size == 0 || hash_size > 32 {
+            hash_size = 32
+        }
+        Self {
+            algorithm,
+            hash_size,
+            count: 0,
+            frontier: vec![]
+        }
+    }
+
+    // Double hash with defined hashing algorithm and truncate to defined length
+    fn double_hash(&self, m: &[u8]) -> Vec<u8> {
+        self.algorithm.double_hash(m, self.hash_size as usize)
+    }
+
+    // Branches are kept untruncated in the frontier so that a completed subtree can double as the
+    // untruncated root. Truncate both children before pairing them.
+    fn hash_pair(&self, left: &[u8], right: &[u8], size: usize) -> Vec<u8> {
+        let s = self.hash_size as usize;
+        self.algorithm.hash(&[&left[..s], &right[..s]].concat(), size)
+    }
+
+    // Hash and append a leaf
+    pub fn append(&mut self, leaf: &[u8]) {
+        // Double hash to prevent length extension attacks
+        self.append_hash_unchecked(self.double_hash(leaf))
+    }
+
+    // Append a hash with a length check. Use with unnormalized data
+    pub fn append_hash(&mut self, hash: Vec<u8>) -> Result<()> {
+        if hash.len() != self.hash_size as usize {
+            return Err(MerkleError::InvalidHashSize.into())
+        }
+        self.append_hash_unchecked(hash);
+        Ok(())
+    }
+
+    // Append a hash without a length check. Use with normalized data
+    pub fn append_hash_unchecked(&mut self, hash: Vec<u8>) {
+        let mut node = hash;
+        let mut level = 0;
+        // Each set bit of the leaf count is a complete left subtree waiting for its right sibling
+        while (self.count >> level) & 1 == 1 {
+            node = self.hash_pair(&self.frontier[level], &node, 32);
+            level += 1;
+        }
+        match self.frontier.get_mut(level) {
+            Some(f) => *f = node,
+            None => self.frontier.push(node)
+        }
+        self.count += 1;
+    }
+
+    pub fn get_merkle_root(&self) -> Result<Vec<u8>> {
+        let mut acc: Option<Vec<u8>> = None;
+        let mut level = 0;
+        let mut width = self.count;
+        match width {
+            0 => return Err(MerkleError::TreeEmpty.into()),
+            // A single leaf is its own root
+            1 => return Ok(self.frontier[0].clone()),
+            _ => {}
+        }
+        while width > 1 {
+            // Only the final pairing produces an untruncated hash
+            let size = match width <= 2 {
+                true => 32,
+                false => self.hash_size as usize
+            };
+            let has_left = (self.count >> level) & 1 == 1;
+            acc = match (has_left, acc) {
+                (true, Some(right)) => Some(self.hash_pair(&self.frontier[level], &right, size)),
+                (true, None) => Some(self.hash_pair(&self.frontier[level], &self.frontier[level], size)),
+                (false, Some(right)) => Some(self.hash_pair(&right, &right, size)),
+                (false, None) => None
+            };
+            width = width.div_ceil(2);
+            level += 1;
+        }
+        // If every level paired up evenly, the root is the last completed subtree
+        Ok(match acc {
+            Some(root) => root,
+            None => self.frontier[level].clone()
+        })
+    }
+
+    pub fn get_leaf_count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn get_frontier(&self) -> Vec<Vec<u8>> {
+        self.frontier.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{HashingAlgorithm, MerkleTree};
+
+    use super::IncrementalMerkleTree;
+
+    #[test]
+    fn incremental_merkle_tree_parity_test() {
+        for (algorithm, hash_size) in [(HashingAlgorithm::Sha256d, 32), (HashingAlgorithm::Keccak, 20), (HashingAlgorithm::Sha256, 16)] {
+            let mut merkle_tree = MerkleTree::new(algorithm.clone(), hash_size);
+            let mut incremental = IncrementalMerkleTree::new(algorithm.clone(), hash_size);
+            assert!(incremental.get_merkle_root().is_err());
+            for n in 0..70u32 {
+                merkle_tree.add_leaf(&n.to_le_bytes());
+                incremental.append(&n.to_le_bytes());
+                merkle_tree.merklize().unwrap();
+                assert_eq!(merkle_tree.get_merkle_root().unwrap(), incremental.get_merkle_root().unwrap());
+                assert_eq!(n as u64 + 1, incremental.get_leaf_count());
+            }
+            assert_eq!(7, incremental.get_frontier().len());
+        }
+    }
+
+    #[test]
+    fn incremental_merkle_tree_append_hash_test() {
+        let mut incremental = IncrementalMerkleTree::new(HashingAlgorithm::Sha256, 20);
+        assert!(incremental.append_hash(vec![0u8; 32]).is_err());
+        incremental.append_hash(vec![1u8; 20]).unwrap();
+        assert_eq!(vec![1u8; 20], incremental.get_merkle_root().unwrap());
+    }
+}
@@ -0,0 +1,445 @@
+use std::collections::BTreeMap;
+#[cfg(not(target_arch = "wasm32"))]
+use anchor_lang::prelude::*;
+use crate::{DomainSeparation, HashingAlgorithm, MerkleError, MerkleProof, PairingMode, Result};
+
+pub const INDEXED_MERKLE_TREE_MAX_DEPTH: u8 = 32;
+
+// A leaf of an indexed Merkle tree. Leaves form a linked list sorted by value, where each leaf
+// points at the leaf holding the next larger value. A next_value of zero marks the end of the list.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub struct IndexedLeaf {
+    pub value: [u8; 32],
+    pub next_index: u64,
+    pub next_value: [u8; 32]
+}
+
+// A fixed depth Merkle tree of IndexedLeaf, as used for nullifier sets by Aztec and Light Protocol.
+//
+// Values are 32 byte big endian integers and zero is reserved for the first leaf. A value is
+// proven absent by proving its "low leaf", the leaf with the largest value below it, whose next
+// value lies above it. This needs a proof of depth log2(capacity) rather than the 256 levels of a
+// sparse Merkle tree.
+//
+// Leaves are double hashed from value || next_index (little endian u64) || next_value, empty leaves
+// are zero, and branches are truncated to hash_size except for the untruncated root. Proofs are
+// plain MerkleProofs of depth hashes.
+#[derive(Debug, Clone)]
+pub struct IndexedMerkleTree {
+    algorithm: HashingAlgorithm,
+    hash_size: u8,
+    depth: u8,
+    leaves: Vec<IndexedLeaf>,
+    values: BTreeMap<[u8; 32], usize>,
+    empty_hashes: Vec<Vec<u8>>,
+    nodes: Vec<Vec<Vec<u8>>>,
+    root: Vec<u8>
+}
+
+// A leaf of the tree along with its proof, for membership and non-membership checks
+#[derive(Debug, Clone)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub struct IndexedMerkleProof {
+    leaf: IndexedLeaf,
+    proof: MerkleProof
+}
+
+// Everything needed to insert a value and recompute the root without the rest of the tree: the
+// low leaf and its proof against the old root, and a proof of the empty slot for the new leaf
+// against the root after the low leaf has been updated.
+#[derive(Debug, Clone)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub struct IndexedMerkleInsertionProof {
+    low_leaf: IndexedLeaf,
+    low_leaf_proof: MerkleProof,
+    new_leaf_proof: MerkleProof
+}
+
+impl IndexedLeaf {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [&self.value[..], &self.next_index.to_le_bytes(), &self.next_value].concat()
+    }
+
+    // Whether this leaf is the low leaf of a value: it is smaller than the value, and the next
+    // value is either larger or the end of the list
+    pub fn is_low_leaf_of(&self, value: &[u8; 32]) -> bool {
+        self.value < *value && (self.next_value > *value || self.next_value == [0u8; 32])
+    }
+}
+
+impl IndexedMerkleTree {
+    pub fn new(algorithm: HashingAlgorithm, hash_size: u8, depth: u8) -> Result<Self> {
+        let mut hash_size = hash_size;
+        if hash_size == 0 || hash_size > 32 {
+            hash_size = 32
+        }
+        if depth == 0 || depth > INDEXED_MERKLE_TREE_MAX_DEPTH {
+            return Err(MerkleError::InvalidTreeSize.into())
+        }
+        // Hashes of empty subtrees at each level, starting from an empty leaf
+        let mut empty_hashes = vec![vec![0u8; hash_size as usize]];
+        for h in 0..depth as usize {
+            let s = match h == depth as usize - 1 {
+                true => 32,
+                false => hash_size as usize
+            };
+            let e = &empty_hashes[h];
+            empty_hashes.push(algorithm.hash(&[&e[..hash_size as usize], &e[..hash_size as usize]].concat(), s));
+        }
+        let mut tree = Self {
+            algorithm,
+            hash_size,
+            depth,
+            leaves: vec![],
+            values: BTreeMap::new(),
+            root: empty_hashes[depth as usize].clone(),
+            empty_hashes,
+            nodes: vec![vec![]; depth as usize]
+        };
+        // The list starts with a zero leaf, which is the low leaf of every value until others exist
+        tree.push_leaf(IndexedLeaf::default());
+        Ok(tree)
+    }
+
+    // Double hash with defined hashing algorithm and truncate to defined length
+    fn double_hash(&self, m: &[u8]) -> Vec<u8> {
+        self.algorithm.double_hash(m, self.hash_size as usize)
+    }
+
+    fn node(&self, level: usize, i: usize) -> &[u8] {
+        match self.nodes[level].get(i) {
+            Some(h) => h,
+            None => &self.empty_hashes[level]
+        }
+    }
+
+    // Recompute the path from a leaf to the root
+    fn update_path(&mut self, i: usize) {
+        let s = self.hash_size as usize;
+        let mut n = i;
+        let mut h = self.double_hash(&self.leaves[i].to_bytes());
+        for level in 0..self.depth as usize {
+            match self.nodes[level].get_mut(n) {
+                Some(node) => *node = h.clone(),
+                None => self.nodes[level].push(h.clone())
+            }
+            let (left, right) = match n % 2 == 0 {
+                true => (h.as_slice(), self.node(level, n + 1)),
+                false => (self.node(level, n - 1), h.as_slice())
+            };
+            let size = match level == self.depth as usize - 1 {
+                true => 32,
+                false => s
+            };
+            h = self.algorithm.hash(&[&left[..s], &right[..s]].concat(), size);
+            n /= 2;
+        }
+        self.root = h;
+    }
+
+    fn push_leaf(&mut self, leaf: IndexedLeaf) {
+        self.values.insert(leaf.value, self.leaves.len());
+        self.leaves.push(leaf);
+        self.update_path(self.leaves.len() - 1);
+    }
+
+    pub fn get_merkle_root(&self) -> Vec<u8> {
+        self.root.clone()
+    }
+
+    pub fn get_depth(&self) -> u8 {
+        self.depth
+    }
+
+    pub fn get_leaf_count(&self) -> u64 {
+        self.leaves.len() as u64
+    }
+
+    pub fn get_leaf(&self, i: usize) -> Result<IndexedLeaf> {
+        match self.leaves.get(i) {
+            Some(leaf) => Ok(leaf.clone()),
+            None => Err(MerkleError::LeafOutOfRange.into())
+        }
+    }
+
+    pub fn contains(&self, value: &[u8; 32]) -> bool {
+        self.values.contains_key(value)
+    }
+
+    // Index of the leaf with the largest value below the given value
+    pub fn get_low_leaf_index(&self, value: &[u8; 32]) -> Result<usize> {
+        match self.values.range(..*value).next_back() {
+            Some((_, i)) => Ok(*i),
+            None => Err(MerkleError::LeafNotFound.into())
+        }
+    }
+
+    pub fn merkle_proof_index(&self, i: usize) -> Result<MerkleProof> {
+        let s = self.hash_size as usize;
+        if i >= 1 << self.depth {
+            return Err(MerkleError::LeafOutOfRange.into())
+        }
+        let mut hashes = vec![];
+        let mut n = i;
+        for level in 0..self.depth as usize {
+            hashes.extend_from_slice(&self.node(level, n ^ 1)[..s]);
+            n /= 2;
+        }
+        Ok(MerkleProof::new(
+            self.algorithm.clone(),
+            self.hash_size,
+            i as u32,
+            hashes
+        ))
+    }
+
+    // Prove a value is in the tree with the leaf holding it
+    pub fn membership_proof(&self, value: &[u8; 32]) -> Result<IndexedMerkleProof> {
+        let i = *self.values.get(value).ok_or(MerkleError::LeafNotFound)?;
+        Ok(IndexedMerkleProof {
+            leaf: self.leaves[i].clone(),
+            proof: self.merkle_proof_index(i)?
+        })
+    }
+
+    // Prove a value is not in the tree with its low leaf
+    pub fn non_membership_proof(&self, value: &[u8; 32]) -> Result<IndexedMerkleProof> {
+        if self.contains(value) {
+            return Err(MerkleError::ValueAlreadyExists.into())
+        }
+        let i = self.get_low_leaf_index(value)?;
+        Ok(IndexedMerkleProof {
+            leaf: self.leaves[i].clone(),
+            proof: self.merkle_proof_index(i)?
+        })
+    }
+
+    // Insert a value, returning a proof that lets a verifier holding only the old root compute
+    // the new one
+    pub fn insert(&mut self, value: [u8; 32]) -> Result<IndexedMerkleInsertionProof> {
+        if value == [0u8; 32] || self.contains(&value) {
+            return Err(MerkleError::ValueAlreadyExists.into())
+        }
+        let new_index = self.leaves.len();
+        if new_index >= 1 << self.depth {
+            return Err(MerkleError::TreeFull.into())
+        }
+        let low_index = self.get_low_leaf_index(&value)?;
+        let low_leaf = self.leaves[low_index].clone();
+        let low_leaf_proof = self.merkle_proof_index(low_index)?;
+        // Point the low leaf at the new value, and the new value at whatever the low leaf pointed to
+        self.leaves[low_index].next_index = new_index as u64;
+        self.leaves[low_index].next_value = value;
+        self.update_path(low_index);
+        let new_leaf_proof = self.merkle_proof_index(new_index)?;
+        self.push_leaf(IndexedLeaf {
+            value,
+            next_index: low_leaf.next_index,
+            next_value: low_leaf.next_value
+        });
+        Ok(IndexedMerkleInsertionProof {
+            low_leaf,
+            low_leaf_proof,
+            new_leaf_proof
+        })
+    }
+}
+
+impl IndexedMerkleProof {
+    pub fn new(leaf: IndexedLeaf, proof: MerkleProof) -> Self {
+        Self {
+            leaf,
+            proof
+        }
+    }
+
+    // Root of the tree holding this leaf
+    pub fn merklize(&self) -> Result<Vec<u8>> {
+        self.proof.merklize(&self.leaf.to_bytes())
+    }
+
+    // Check that a value is held by this leaf in the tree with the given root
+    pub fn verify_membership(&self, value: &[u8; 32], root: &[u8]) -> Result<()> {
+        if self.leaf.value != *value {
+            return Err(MerkleError::LeafNotFound.into())
+        }
+        match self.merklize()? == root {
+            true => Ok(()),
+            false => Err(MerkleError::InvalidProof.into())
+        }
+    }
+
+    // Check that a value is absent from the tree with the given root, as this leaf is its low leaf
+    pub fn verify_non_membership(&self, value: &[u8; 32], root: &[u8]) -> Result<()> {
+        if !self.leaf.is_low_leaf_of(value) {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        match self.merklize()? == root {
+            true => Ok(()),
+            false => Err(MerkleError::InvalidProof.into())
+        }
+    }
+
+    pub fn get_leaf(&self) -> IndexedLeaf {
+        self.leaf.clone()
+    }
+
+    pub fn get_proof(&self) -> MerkleProof {
+        self.proof.clone()
+    }
+}
+
+impl IndexedMerkleInsertionProof {
+    pub fn new(low_leaf: IndexedLeaf, low_leaf_proof: MerkleProof, new_leaf_proof: MerkleProof) -> Self {
+        Self {
+            low_leaf,
+            low_leaf_proof,
+            new_leaf_proof
+        }
+    }
+
+    // Verify the insertion of a value into a tree of the given depth holding leaf_count leaves
+    // against the old root, and return the new root. The new leaf must go into the next free slot
+    // at index leaf_count, and both proofs must hold depth hashes with the same hashing params.
+    // Empty slots are paired with each other, so rather than with merklize_strict, the depth and
+    // indices are checked here.
+    pub fn verify(&self, value: &[u8; 32], root: &[u8], depth: u8, leaf_count: u64) -> Result<Vec<u8>> {
+        if depth == 0 || depth > INDEXED_MERKLE_TREE_MAX_DEPTH {
+            return Err(MerkleError::InvalidTreeSize.into())
+        }
+        if leaf_count >= 1 << depth {
+            return Err(MerkleError::TreeFull.into())
+        }
+        let (low, new) = (&self.low_leaf_proof, &self.new_leaf_proof);
+        let proof_len = depth as usize * low.get_hash_size() as usize;
+        if low.get_algorithm() != new.get_algorithm()
+            || low.get_hash_size() != new.get_hash_size()
+            || [low, new].iter().any(|p| p.get_pairing_mode() != PairingMode::Indexed
+                || p.get_domain_separation() != DomainSeparation::Untagged
+                || p.get_pairing_hashes().len() != proof_len)
+            || low.get_index() as u64 >= leaf_count
+            || new.get_index() as u64 != leaf_count {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        // The value must be absent, with the low leaf proven against the old root
+        IndexedMerkleProof::new(self.low_leaf.clone(), self.low_leaf_proof.clone()).verify_non_membership(value, root)?;
+        let new_index = self.new_leaf_proof.get_index();
+        let updated_low_leaf = IndexedLeaf {
+            value: self.low_leaf.value,
+            next_index: new_index as u64,
+            next_value: *value
+        };
+        let intermediate_root = self.low_leaf_proof.merklize(&updated_low_leaf.to_bytes())?;
+        // The new leaf must go into an empty slot
+        let empty = vec![0u8; self.new_leaf_proof.get_hash_size() as usize];
+        if self.new_leaf_proof.merklize_hash(&empty)? != intermediate_root {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        let new_leaf = IndexedLeaf {
+            value: *value,
+            next_index: self.low_leaf.next_index,
+            next_value: self.low_leaf.next_value
+        };
+        self.new_leaf_proof.merklize(&new_leaf.to_bytes())
+    }
+
+    pub fn get_low_leaf(&self) -> IndexedLeaf {
+        self.low_leaf.clone()
+    }
+
+    pub fn get_low_leaf_proof(&self) -> MerkleProof {
+        self.low_leaf_proof.clone()
+    }
+
+    pub fn get_new_leaf_proof(&self) -> MerkleProof {
+        self.new_leaf_proof.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{HashingAlgorithm, MerkleProof};
+
+    use super::{IndexedMerkleInsertionProof, IndexedMerkleTree};
+
+    fn value(n: u64) -> [u8; 32] {
+        let mut v = [0u8; 32];
+        v[24..].copy_from_slice(&n.to_be_bytes());
+        v
+    }
+
+    #[test]
+    fn indexed_merkle_tree_test() {
+        let mut tree = IndexedMerkleTree::new(HashingAlgorithm::Sha256, 20, 4).unwrap();
+        for n in [30u64, 10, 20, 50, 40] {
+            let root = tree.get_merkle_root();
+            let leaf_count = tree.get_leaf_count();
+            let proof = tree.insert(value(n)).unwrap();
+            assert_eq!(tree.get_merkle_root(), proof.verify(&value(n), &root, 4, leaf_count).unwrap());
+        }
+        let root = tree.get_merkle_root();
+        // The list is sorted by value whatever the insertion order
+        assert_eq!(value(10), tree.get_leaf(0).unwrap().next_value);
+        assert_eq!(value(20), tree.get_leaf(2).unwrap().next_value);
+        assert_eq!([0u8; 32], tree.get_leaf(4).unwrap().next_value);
+        for n in [10u64, 20, 30, 40, 50] {
+            tree.membership_proof(&value(n)).unwrap().verify_membership(&value(n), &root).unwrap();
+        }
+        for n in [1u64, 15, 35, 45, 1000] {
+            let proof = tree.non_membership_proof(&value(n)).unwrap();
+            proof.verify_non_membership(&value(n), &root).unwrap();
+            // A low leaf only proves the absence of values in its own gap
+            assert!(proof.verify_non_membership(&value(n + 10), &root).is_err() || n == 1000);
+        }
+        assert!(tree.non_membership_proof(&value(30)).is_err());
+        assert!(tree.insert(value(30)).is_err());
+    }
+
+    #[test]
+    fn indexed_merkle_tree_full_test() {
+        let mut tree = IndexedMerkleTree::new(HashingAlgorithm::Keccak, 32, 2).unwrap();
+        for n in 1..4u64 {
+            tree.insert(value(n)).unwrap();
+        }
+        assert!(tree.insert(value(4)).is_err());
+        assert!(IndexedMerkleTree::new(HashingAlgorithm::Keccak, 32, 0).is_err());
+    }
+
+    #[test]
+    fn indexed_merkle_insertion_proof_replay_test() {
+        let mut tree = IndexedMerkleTree::new(HashingAlgorithm::Sha256, 32, 8).unwrap();
+        let old_root = tree.get_merkle_root();
+        let proof = tree.insert(value(7)).unwrap();
+        // The same proof cannot be used against the new root, nor for a different value
+        assert!(proof.verify(&value(7), &tree.get_merkle_root(), 8, 1).is_err());
+        assert!(proof.verify(&value(8), &old_root, 8, 1).is_err());
+        assert_eq!(tree.get_merkle_root(), proof.verify(&value(7), &old_root, 8, 1).unwrap());
+    }
+
+    #[test]
+    fn indexed_merkle_insertion_proof_index_test() {
+        let mut tree = IndexedMerkleTree::new(HashingAlgorithm::Sha256, 32, 8).unwrap();
+        tree.insert(value(3)).unwrap();
+        let old_root = tree.get_merkle_root();
+        let proof = tree.insert(value(7)).unwrap();
+        assert_eq!(tree.get_merkle_root(), proof.verify(&value(7), &old_root, 8, 2).unwrap());
+        // The new leaf must go into the next free slot of a tree of the given depth
+        assert!(proof.verify(&value(7), &old_root, 8, 1).is_err());
+        assert!(proof.verify(&value(7), &old_root, 8, 3).is_err());
+        assert!(proof.verify(&value(7), &old_root, 7, 2).is_err());
+        assert!(proof.verify(&value(7), &old_root, 1, 2).is_err());
+        // Index bits above the depth of the tree would alias the new leaf's slot
+        let new_leaf_proof = proof.get_new_leaf_proof();
+        let aliased = MerkleProof::new(HashingAlgorithm::Sha256, 32, 2 + 256, new_leaf_proof.get_pairing_hashes());
+        let aliased = IndexedMerkleInsertionProof::new(proof.get_low_leaf(), proof.get_low_leaf_proof(), aliased);
+        assert!(aliased.verify(&value(7), &old_root, 8, 2).is_err());
+        // Both proofs must use the same hashing params
+        let truncated = MerkleProof::new(HashingAlgorithm::Sha256, 20, 2, new_leaf_proof.get_pairing_hashes()[..160].to_vec());
+        let truncated = IndexedMerkleInsertionProof::new(proof.get_low_leaf(), proof.get_low_leaf_proof(), truncated);
+        assert!(truncated.verify(&value(7), &old_root, 8, 2).is_err());
+        let keccak = MerkleProof::new(HashingAlgorithm::Keccak, 32, 2, new_leaf_proof.get_pairing_hashes());
+        let keccak = IndexedMerkleInsertionProof::new(proof.get_low_leaf(), proof.get_low_leaf_proof(), keccak);
+        assert!(keccak.verify(&value(7), &old_root, 8, 2).is_err());
+    }
+}
@@ -0,0 +1,312 @@
+#[cfg(not(target_os = "solana"))]
+use anyhow::Result;
+#[cfg(target_os = "solana")]
+use anchor_lang::Result;
+#[cfg(not(target_arch = "wasm32"))]
+use anchor_lang::prelude::*;
+use crate::{DomainSeparation, HashingAlgorithm, MerkleError, MerkleHasher, MerkleProof, MerkleTree, PairingMode, DOMAIN_SEPARATION_LEAF_TAG};
+
+// Leaf and tree formats of the widely deployed merkle-distributor programs.
+//
+// Jito: leaves are sha256(0x00 || sha256(claimant || amount_unlocked || amount_locked)) and
+// branches sha256(0x01 || min(left, right) || max(left, right)). The last node of an odd level is
+// paired with itself, as in solana-merkle-tree.
+//
+// Saber: leaves are keccak(index || claimant || amount) and branches
+// keccak(min(left, right) || max(left, right)). Leaves are sorted and deduplicated, and the last
+// node of an odd level is carried up unhashed, as in the Uniswap tree Saber's SDK builds, so Saber
+// proofs skip those levels.
+//
+// Integers are little endian. Both programs verify with sorted pairs, so proofs hold no indices.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub enum MerkleDistributorFormat {
+    Jito,
+    Saber
+}
+
+impl MerkleDistributorFormat {
+    fn algorithm(&self) -> HashingAlgorithm {
+        match self {
+            MerkleDistributorFormat::Jito => HashingAlgorithm::Sha256,
+            MerkleDistributorFormat::Saber => HashingAlgorithm::Keccak
+        }
+    }
+
+    fn domain_separation(&self) -> DomainSeparation {
+        match self {
+            MerkleDistributorFormat::Jito => DomainSeparation::Tagged,
+            MerkleDistributorFormat::Saber => DomainSeparation::Untagged
+        }
+    }
+
+    // Verify a leaf hash against a root as the distributor program does
+    pub fn verify(&self, proof: &[[u8;32]], root: &[u8;32], leaf: &[u8;32]) -> Result<()> {
+        let proof = MerkleProof::new(self.algorithm(), 32, 0, proof.concat())
+            .with_pairing_mode(PairingMode::Sorted)
+            .with_domain_separation(self.domain_separation());
+        if proof.merklize_hash(leaf)? != root {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        Ok(())
+    }
+}
+
+// A claim of an airdrop. Jito leaves ignore the index, and Saber leaves only hold unlocked amounts.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub struct MerkleDistributorClaim {
+    pub index: u64,
+    pub claimant: [u8;32],
+    pub amount_unlocked: u64,
+    pub amount_locked: u64
+}
+
+impl MerkleDistributorClaim {
+    pub fn new(index: u64, claimant: [u8;32], amount_unlocked: u64, amount_locked: u64) -> Self {
+        Self {
+            index,
+            claimant,
+            amount_unlocked,
+            amount_locked
+        }
+    }
+
+    pub fn leaf_hash(&self, format: &MerkleDistributorFormat) -> Result<[u8;32]> {
+        let hash = match format {
+            MerkleDistributorFormat::Jito => {
+                let node = HashingAlgorithm::Sha256.hashv(&[&self.claimant, &self.amount_unlocked.to_le_bytes(), &self.amount_locked.to_le_bytes()]);
+                HashingAlgorithm::Sha256.hashv(&[&[DOMAIN_SEPARATION_LEAF_TAG], &node])
+            },
+            MerkleDistributorFormat::Saber => {
+                if self.amount_locked != 0 {
+                    return Err(MerkleError::InvalidLeafEncoding.into())
+                }
+                HashingAlgorithm::Keccak.hashv(&[&self.index.to_le_bytes(), &self.claimant, &self.amount_unlocked.to_le_bytes()])
+            }
+        };
+        Ok(hash.try_into().unwrap())
+    }
+}
+
+#[derive(Debug, Clone)]
+enum MerkleDistributorNodes {
+    Jito(MerkleTree),
+    // Levels of the tree from the sorted leaves up to the root
+    Saber(Vec<Vec<[u8;32]>>)
+}
+
+// A tree of airdrop claims whose root and proofs match the given distributor program
+#[derive(Debug, Clone)]
+pub struct MerkleDistributorTree {
+    format: MerkleDistributorFormat,
+    claims: Vec<MerkleDistributorClaim>,
+    // Position of each claim's leaf in the tree
+    positions: Vec<usize>,
+    nodes: MerkleDistributorNodes
+}
+
+impl MerkleDistributorTree {
+    pub fn new(format: MerkleDistributorFormat, claims: Vec<MerkleDistributorClaim>) -> Result<Self> {
+        if claims.is_empty() {
+            return Err(MerkleError::TreeEmpty.into())
+        }
+        let leaves = claims.iter().map(|claim| claim.leaf_hash(&format)).collect::<Result<Vec<[u8;32]>>>()?;
+        let (positions, nodes) = match format {
+            MerkleDistributorFormat::Jito => {
+                let mut tree = MerkleTree::new(HashingAlgorithm::Sha256, 32)
+                    .with_pairing_mode(PairingMode::Sorted)
+                    .with_domain_separation(DomainSeparation::Tagged);
+                tree.add_hashes(leaves.iter().map(|leaf| leaf.to_vec()).collect())?;
+                // Jito's tree, like solana-merkle-tree, accepts repeated claims
+                tree.merklize()?;
+                ((0..claims.len()).collect(), MerkleDistributorNodes::Jito(tree))
+            },
+            MerkleDistributorFormat::Saber => {
+                // Saber's SDK drops repeated leaves, so a repeated claim shares a single leaf
+                let mut sorted = leaves.clone();
+                sorted.sort();
+                sorted.dedup();
+                let positions = leaves.iter().map(|leaf| sorted.binary_search(leaf).unwrap()).collect();
+                let mut levels = vec![sorted];
+                while levels[levels.len() - 1].len() > 1 {
+                    let next = levels[levels.len() - 1].chunks(2).map(|pair| match pair {
+                        [left, right] => {
+                            let (left, right) = PairingMode::Sorted.ordered(left, right);
+                            HashingAlgorithm::Keccak.hashv(&[left, right]).try_into().unwrap()
+                        },
+                        _ => pair[0]
+                    }).collect();
+                    levels.push(next);
+                }
+                (positions, MerkleDistributorNodes::Saber(levels))
+            }
+        };
+        Ok(Self {
+            format,
+            claims,
+            positions,
+            nodes
+        })
+    }
+
+    pub fn get_format(&self) -> MerkleDistributorFormat {
+        self.format.clone()
+    }
+
+    pub fn get_merkle_root(&self) -> Result<[u8;32]> {
+        match &self.nodes {
+            MerkleDistributorNodes::Jito(tree) => Ok(tree.get_merkle_root()?.try_into().unwrap()),
+            MerkleDistributorNodes::Saber(levels) => Ok(levels[levels.len() - 1][0])
+        }
+    }
+
+    pub fn get_claim(&self, i: usize) -> Result<MerkleDistributorClaim> {
+        self.claims.get(i).cloned().ok_or(MerkleError::LeafOutOfRange.into())
+    }
+
+    pub fn get_leaf_hash(&self, i: usize) -> Result<[u8;32]> {
+        self.get_claim(i)?.leaf_hash(&self.format)
+    }
+
+    // Proof of the claim at index i, as the list of nodes the distributor's claim instruction takes
+    pub fn get_proof(&self, i: usize) -> Result<Vec<[u8;32]>> {
+        let position = *self.positions.get(i).ok_or(MerkleError::LeafOutOfRange)?;
+        match &self.nodes {
+            MerkleDistributorNodes::Jito(tree) => {
+                let hashes = tree.merkle_proof_index(position)?.get_pairing_hashes();
+                Ok(hashes.chunks(32).map(|h| h.try_into().unwrap()).collect())
+            },
+            MerkleDistributorNodes::Saber(levels) => {
+                let mut n = position;
+                let mut proof = vec![];
+                for level in levels.iter() {
+                    // A node carried up unhashed has no sibling
+                    if let Some(sibling) = level.get(n ^ 1) {
+                        proof.push(*sibling);
+                    }
+                    n /= 2;
+                }
+                Ok(proof)
+            }
+        }
+    }
+
+    // Proof of the claim at index i that can be merklized from its leaf hash
+    pub fn merkle_proof_index(&self, i: usize) -> Result<MerkleProof> {
+        Ok(MerkleProof::new(self.format.algorithm(), 32, i as u32, self.get_proof(i)?.concat())
+            .with_pairing_mode(PairingMode::Sorted)
+            .with_domain_separation(self.format.domain_separation()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{HashingAlgorithm, MerkleHasher};
+
+    use hex_literal::hex;
+
+    use super::{MerkleDistributorClaim, MerkleDistributorFormat, MerkleDistributorTree};
+
+    fn claims(n: u64) -> Vec<MerkleDistributorClaim> {
+        (0..n).map(|i| MerkleDistributorClaim::new(i, [i as u8 + 1; 32], (i + 1) * 1000, 0)).collect()
+    }
+
+    // The claim instructions' verification, for comparison
+    fn verify(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32], hash: impl Fn(&[u8], &[u8]) -> [u8; 32]) -> bool {
+        let computed = proof.iter().fold(leaf, |computed, element| match computed <= *element {
+            true => hash(&computed, element),
+            false => hash(element, &computed)
+        });
+        computed == root
+    }
+
+    fn jito_hash(left: &[u8], right: &[u8]) -> [u8; 32] {
+        HashingAlgorithm::Sha256.hashv(&[&[1], left, right]).try_into().unwrap()
+    }
+
+    fn saber_hash(left: &[u8], right: &[u8]) -> [u8; 32] {
+        HashingAlgorithm::Keccak.hashv(&[left, right]).try_into().unwrap()
+    }
+
+    #[test]
+    fn merkle_distributor_jito_test() {
+        for n in 1..12 {
+            let tree = MerkleDistributorTree::new(MerkleDistributorFormat::Jito, claims(n)).unwrap();
+            let root = tree.get_merkle_root().unwrap();
+            for i in 0..n as usize {
+                let leaf = tree.get_leaf_hash(i).unwrap();
+                let proof = tree.get_proof(i).unwrap();
+                assert!(verify(&proof, root, leaf, jito_hash));
+                MerkleDistributorFormat::Jito.verify(&proof, &root, &leaf).unwrap();
+                assert_eq!(root.to_vec(), tree.merkle_proof_index(i).unwrap().merklize_hash(&leaf).unwrap());
+            }
+        }
+        // Locked amounts are part of Jito leaves
+        let claim = MerkleDistributorClaim::new(0, [1u8; 32], 10, 20);
+        let node = HashingAlgorithm::Sha256.hashv(&[&[1u8; 32], &10u64.to_le_bytes(), &20u64.to_le_bytes()]);
+        assert_eq!(HashingAlgorithm::Sha256.hashv(&[&[0], &node]), claim.leaf_hash(&MerkleDistributorFormat::Jito).unwrap().to_vec());
+    }
+
+    #[test]
+    fn merkle_distributor_saber_test() {
+        // Three sorted leaves a < b < c give root H(H(a, b), c), with c carried up unhashed
+        let tree = MerkleDistributorTree::new(MerkleDistributorFormat::Saber, claims(3)).unwrap();
+        let mut leaves: Vec<[u8; 32]> = (0..3).map(|i| tree.get_leaf_hash(i).unwrap()).collect();
+        assert_eq!(HashingAlgorithm::Keccak.hashv(&[&0u64.to_le_bytes(), &[1u8; 32], &1000u64.to_le_bytes()]), leaves[0].to_vec());
+        leaves.sort();
+        let ab = saber_hash(&leaves[0], &leaves[1]);
+        let root = match ab <= leaves[2] {
+            true => saber_hash(&ab, &leaves[2]),
+            false => saber_hash(&leaves[2], &ab)
+        };
+        assert_eq!(root, tree.get_merkle_root().unwrap());
+        for n in 1..12 {
+            let tree = MerkleDistributorTree::new(MerkleDistributorFormat::Saber, claims(n)).unwrap();
+            let root = tree.get_merkle_root().unwrap();
+            for i in 0..n as usize {
+                let leaf = tree.get_leaf_hash(i).unwrap();
+                let proof = tree.get_proof(i).unwrap();
+                assert!(verify(&proof, root, leaf, saber_hash));
+                MerkleDistributorFormat::Saber.verify(&proof, &root, &leaf).unwrap();
+                // A claim with a different amount does not verify
+                let mut claim = tree.get_claim(i).unwrap();
+                claim.amount_unlocked += 1;
+                assert!(MerkleDistributorFormat::Saber.verify(&proof, &root, &claim.leaf_hash(&MerkleDistributorFormat::Saber).unwrap()).is_err());
+            }
+        }
+        // Saber leaves cannot hold locked amounts
+        assert!(MerkleDistributorTree::new(MerkleDistributorFormat::Saber, vec![MerkleDistributorClaim::new(0, [1u8; 32], 10, 20)]).is_err());
+        // Repeated claims share a leaf, as Saber's SDK drops duplicates
+        let tree = MerkleDistributorTree::new(MerkleDistributorFormat::Saber, [claims(5), claims(2)].concat()).unwrap();
+        let root = tree.get_merkle_root().unwrap();
+        assert_eq!(MerkleDistributorTree::new(MerkleDistributorFormat::Saber, claims(5)).unwrap().get_merkle_root().unwrap(), root);
+        assert_eq!(tree.get_proof(1).unwrap(), tree.get_proof(6).unwrap());
+        MerkleDistributorFormat::Saber.verify(&tree.get_proof(6).unwrap(), &root, &tree.get_leaf_hash(6).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn merkle_distributor_jito_repeated_claims_test() {
+        let tree = MerkleDistributorTree::new(MerkleDistributorFormat::Jito, [claims(2), claims(2)].concat()).unwrap();
+        let root = tree.get_merkle_root().unwrap();
+        for i in 0..4 {
+            let leaf = tree.get_leaf_hash(i).unwrap();
+            MerkleDistributorFormat::Jito.verify(&tree.get_proof(i).unwrap(), &root, &leaf).unwrap();
+        }
+    }
+
+    #[test]
+    fn merkle_distributor_golden_test() {
+        // Golden roots from ports of Jito's merkle-tree crate and Saber's BalanceTree
+        let golden = [
+            (1, hex!("25312e1a1e732cf30980b79c75ff5f415ee06383dfedb13c97231ecd75fa815d"), hex!("519d20f195abcfafc05dbe1fa29b30a0d126402cd890893aa770cbc1d698340e")),
+            (2, hex!("8e53d70a3f0aa34cd21472f196af6012bc662ee657d6042c0b48fb8e5c32d95c"), hex!("58687142fb9e949c65ca9744fe4e5fe24a963b837b796daffa5fcd9f72171cde")),
+            (5, hex!("343fb6b3ce5777d350443c089dc040c94a675b72863eac6b2285b647fb4e2df5"), hex!("a7060c32e2f613f390094d994f6560972f00f5ee33d5985dff68470053bb09d3")),
+            (11, hex!("81333ca1cf699210f90131c36b59f539a18996231de167b7f6312d6c6066a763"), hex!("2336d75fc854d3165e1eec74c9711a1bd98ad1531ec8951676939423d504469d"))
+        ];
+        for (n, jito, saber) in golden {
+            assert_eq!(jito, MerkleDistributorTree::new(MerkleDistributorFormat::Jito, claims(n)).unwrap().get_merkle_root().unwrap());
+            assert_eq!(saber, MerkleDistributorTree::new(MerkleDistributorFormat::Saber, claims(n)).unwrap().get_merkle_root().unwrap());
+        }
+    }
+}
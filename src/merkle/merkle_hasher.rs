@@ -0,0 +1,47 @@
+use std::fmt::Debug;
+
+// A hash function that trees and proofs can be built on. HashingAlgorithm provides the built-in
+// implementations, which use syscalls on target_os = "solana". Implement this trait to use any
+// other hash function without changes to the crate.
+pub trait MerkleHasher: Debug + Clone + Send + Sync {
+    // Size of an untruncated hash in bytes. Must be between 1 and 255.
+    fn output_size(&self) -> usize;
+
+    // Hash the concatenation of several byte slices
+    fn hashv(&self, vals: &[&[u8]]) -> Vec<u8>;
+
+    // Hash a byte slice
+    fn hash(&self, val: &[u8]) -> Vec<u8> {
+        self.hashv(&[val])
+    }
+
+    // Hash a byte slice twice. Leaves are double hashed to prevent length extension attacks.
+    fn double_hash(&self, val: &[u8]) -> Vec<u8> {
+        self.hash(&self.hash(val))
+    }
+
+    // Hash and truncate to size bytes. A size of 0 leaves the hash untruncated.
+    fn hash_truncated(&self, val: &[u8], size: usize) -> Vec<u8> {
+        truncate(self.hash(val), size)
+    }
+
+    // Double hash and truncate to size bytes. A size of 0 leaves the hash untruncated.
+    fn double_hash_truncated(&self, val: &[u8], size: usize) -> Vec<u8> {
+        truncate(self.double_hash(val), size)
+    }
+
+    // Clamp a hash size to the output size, treating 0 as the output size
+    fn normalize_hash_size(&self, hash_size: u8) -> u8 {
+        match hash_size == 0 || hash_size as usize > self.output_size() {
+            true => self.output_size() as u8,
+            false => hash_size
+        }
+    }
+}
+
+fn truncate(mut hash: Vec<u8>, size: usize) -> Vec<u8> {
+    if size > 0 && size < hash.len() {
+        hash.truncate(size);
+    }
+    hash
+}
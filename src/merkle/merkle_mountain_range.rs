@@ -0,0 +1,312 @@
+#[cfg(not(target_arch = "wasm32"))]
+use anchor_lang::prelude::*;
+use crate::{HashingAlgorithm, MerkleError, Result};
+
+// An append-only Merkle Mountain Range. Leaves are grouped into perfect binary trees ("peaks"),
+// one for each set bit of the leaf count, and the peaks are bagged from right to left into a root.
+// Nodes never change once written, so proofs can be produced against any historical size.
+//
+// Branches are stored untruncated and truncated to hash_size when hashed into their parent, so
+// that a single peak or the final bagging hash can serve as an untruncated root.
+#[derive(Debug, Clone)]
+pub struct MerkleMountainRange {
+    algorithm: HashingAlgorithm,
+    hash_size: u8,
+    nodes: Vec<Vec<Vec<u8>>>
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub struct MerkleMountainRangeProof {
+    algorithm: HashingAlgorithm,
+    hash_size: u8,
+    leaf_count: u64,
+    index: u64,
+    hashes: Vec<u8>
+}
+
+// Heights of the peaks of a range with `leaf_count` leaves, from left to right
+fn peak_heights(leaf_count: u64) -> Vec<u32> {
+    (0..64).rev().filter(|h| (leaf_count >> h) & 1 == 1).collect()
+}
+
+// Find the peak holding a leaf, returning its position among the peaks, its height and the index
+// of the leaf within it
+fn locate_peak(leaf_count: u64, index: u64) -> Option<(usize, u32, u64)> {
+    let mut start = 0u64;
+    for (i, height) in peak_heights(leaf_count).into_iter().enumerate() {
+        if index < start + (1 << height) {
+            return Some((i, height, index - start));
+        }
+        start += 1 << height;
+    }
+    None
+}
+
+fn hash_pair(algorithm: &HashingAlgorithm, hash_size: u8, left: &[u8], right: &[u8]) -> Vec<u8> {
+    let s = hash_size as usize;
+    algorithm.hash(&[&left[..s], &right[..s]].concat(), 32)
+}
+
+// Bag peaks from right to left. A single peak is its own root.
+fn bag_peaks(algorithm: &HashingAlgorithm, hash_size: u8, peaks: &[Vec<u8>]) -> Vec<u8> {
+    let mut peaks = peaks.iter().rev();
+    let mut root = peaks.next().cloned().unwrap_or_default();
+    for peak in peaks {
+        root = hash_pair(algorithm, hash_size, peak, &root);
+    }
+    root
+}
+
+impl MerkleMountainRange {
+    pub fn new(algorithm: HashingAlgorithm, hash_size: u8) -> Self {
+        let mut hash_size = hash_size;
+        if hash_size == 0 || hash_size > 32 {
+            hash_size = 32
+        }
+        Self {
+            algorithm,
+            hash_size,
+            nodes: vec![vec![]]
+        }
+    }
+
+    // Double hash with defined hashing algorithm and truncate to defined length
+    fn double_hash(&self, m: &[u8]) -> Vec<u8> {
+        self.algorithm.double_hash(m, self.hash_size as usize)
+    }
+
+    // Hash and append a leaf
+    pub fn append(&mut self, leaf: &[u8]) {
+        // Double hash to prevent length extension attacks
+        self.append_hash_unchecked(self.double_hash(leaf))
+    }
+
+    // Append a hash with a length check. Use with unnormalized data
+    pub fn append_hash(&mut self, hash: Vec<u8>) -> Result<()> {
+        if hash.len() != self.hash_size as usize {
+            return Err(MerkleError::InvalidHashSize.into())
+        }
+        self.append_hash_unchecked(hash);
+        Ok(())
+    }
+
+    // Append a hash without a length check. Use with normalized data
+    pub fn append_hash_unchecked(&mut self, hash: Vec<u8>) {
+        self.nodes[0].push(hash);
+        let mut level = 0;
+        // Merge completed pairs upwards until we reach a level with an unpaired node
+        while self.nodes[level].len() % 2 == 0 {
+            let len = self.nodes[level].len();
+            let parent = hash_pair(&self.algorithm, self.hash_size, &self.nodes[level][len - 2], &self.nodes[level][len - 1]);
+            if self.nodes.len() == level + 1 {
+                self.nodes.push(vec![]);
+            }
+            self.nodes[level + 1].push(parent);
+            level += 1;
+        }
+    }
+
+    pub fn get_leaf_count(&self) -> u64 {
+        self.nodes[0].len() as u64
+    }
+
+    pub fn get_leaf_hash(&self, i: usize) -> Result<Vec<u8>> {
+        match self.nodes[0].get(i) {
+            Some(h) => Ok(h.clone()),
+            None => Err(MerkleError::LeafOutOfRange.into())
+        }
+    }
+
+    fn within_size(&self, leaf_count: u64) -> Result<()> {
+        match leaf_count {
+            0 => Err(MerkleError::TreeEmpty.into()),
+            n if n > self.get_leaf_count() => Err(MerkleError::LeafOutOfRange.into()),
+            _ => Ok(())
+        }
+    }
+
+    // Peaks of the range as it was when it held `leaf_count` leaves, from left to right
+    pub fn get_peaks_at(&self, leaf_count: u64) -> Result<Vec<Vec<u8>>> {
+        self.within_size(leaf_count)?;
+        let mut start = 0u64;
+        let mut peaks = vec![];
+        for height in peak_heights(leaf_count) {
+            peaks.push(self.nodes[height as usize][(start >> height) as usize].clone());
+            start += 1 << height;
+        }
+        Ok(peaks)
+    }
+
+    pub fn get_merkle_root(&self) -> Result<Vec<u8>> {
+        self.get_merkle_root_at(self.get_leaf_count())
+    }
+
+    pub fn get_merkle_root_at(&self, leaf_count: u64) -> Result<Vec<u8>> {
+        let peaks = self.get_peaks_at(leaf_count)?;
+        Ok(bag_peaks(&self.algorithm, self.hash_size, &peaks))
+    }
+
+    pub fn merkle_proof_index(&self, i: u64) -> Result<MerkleMountainRangeProof> {
+        self.merkle_proof_index_at(i, self.get_leaf_count())
+    }
+
+    // Prove a leaf against the root of the range as it was when it held `leaf_count` leaves
+    pub fn merkle_proof_index_at(&self, i: u64, leaf_count: u64) -> Result<MerkleMountainRangeProof> {
+        self.within_size(leaf_count)?;
+        let s = self.hash_size as usize;
+        let (peak, height, _) = locate_peak(leaf_count, i).ok_or(MerkleError::LeafOutOfRange)?;
+        let mut hashes: Vec<Vec<u8>> = vec![];
+        // Pairing hashes from the leaf up to its peak
+        let mut n = i as usize;
+        for level in 0..height as usize {
+            hashes.push(self.nodes[level][n ^ 1][..s].to_vec());
+            n >>= 1;
+        }
+        // Followed by every other peak, from left to right
+        for (j, p) in self.get_peaks_at(leaf_count)?.into_iter().enumerate() {
+            if j != peak {
+                hashes.push(p[..s].to_vec());
+            }
+        }
+        Ok(MerkleMountainRangeProof::new(
+            self.algorithm.clone(),
+            self.hash_size,
+            leaf_count,
+            i,
+            hashes.concat()
+        ))
+    }
+}
+
+impl MerkleMountainRangeProof {
+    pub fn new(algorithm: HashingAlgorithm, hash_size: u8, leaf_count: u64, index: u64, hashes: Vec<u8>) -> Self {
+        let mut hash_size = hash_size;
+        if hash_size == 0 || hash_size > 32 {
+            hash_size = 32
+        }
+        Self {
+            algorithm,
+            hash_size,
+            leaf_count,
+            index,
+            hashes
+        }
+    }
+
+    // Double hash with defined hashing algorithm and truncate to defined length
+    pub fn double_hash(&self, m: &[u8]) -> Vec<u8> {
+        self.algorithm.double_hash(m, self.hash_size as usize)
+    }
+
+    // Merklize from a leaf
+    pub fn merklize(&self, leaf: &[u8]) -> Result<Vec<u8>> {
+        self.merklize_hash_unchecked(&self.double_hash(leaf))
+    }
+
+    // Merklize from a leaf hash
+    pub fn merklize_hash(&self, hash: &[u8]) -> Result<Vec<u8>> {
+        if hash.len() != self.hash_size as usize {
+            return Err(MerkleError::InvalidHashSize.into())
+        }
+        self.merklize_hash_unchecked(hash)
+    }
+
+    // Merklize from a hash. NOTE: There are no length checks being performed on the hash here.
+    fn merklize_hash_unchecked(&self, hash: &[u8]) -> Result<Vec<u8>> {
+        let size = self.hash_size as usize;
+        let (peak, height, local_index) = locate_peak(self.leaf_count, self.index).ok_or(MerkleError::LeafOutOfRange)?;
+        let peak_count = peak_heights(self.leaf_count).len();
+        // The proof must hold exactly the pairing hashes of the peak and every other peak
+        if self.hashes.len() != (height as usize + peak_count - 1) * size {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        let mut hashes = self.hashes.chunks(size);
+        let mut h = hash.to_vec();
+        for level in 0..height {
+            let sibling = hashes.next().ok_or(MerkleError::InvalidProof)?;
+            h = match (local_index >> level) % 2 == 0 {
+                true => hash_pair(&self.algorithm, self.hash_size, &h, sibling),
+                false => hash_pair(&self.algorithm, self.hash_size, sibling, &h)
+            };
+        }
+        let mut peaks: Vec<Vec<u8>> = hashes.map(|p| p.to_vec()).collect();
+        peaks.insert(peak, h);
+        Ok(bag_peaks(&self.algorithm, self.hash_size, &peaks))
+    }
+
+    pub fn get_leaf_count(&self) -> u64 {
+        self.leaf_count
+    }
+
+    pub fn get_index(&self) -> u64 {
+        self.index
+    }
+
+    pub fn get_pairing_hashes(&self) -> Vec<u8> {
+        self.hashes.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::HashingAlgorithm;
+
+    use super::{MerkleMountainRange, MerkleMountainRangeProof};
+
+    #[test]
+    fn merkle_mountain_range_proof_test() {
+        for (algorithm, hash_size) in [(HashingAlgorithm::Sha256, 32), (HashingAlgorithm::Keccakd, 20)] {
+            let mut mmr = MerkleMountainRange::new(algorithm, hash_size);
+            for n in 0..37u32 {
+                mmr.append(&n.to_le_bytes());
+                let root = mmr.get_merkle_root().unwrap();
+                assert_eq!(match n { 0 => hash_size as usize, _ => 32 }, root.len());
+                for i in 0..=n as u64 {
+                    let proof = mmr.merkle_proof_index(i).unwrap();
+                    assert_eq!(root, proof.merklize(&(i as u32).to_le_bytes()).unwrap());
+                    assert_ne!(root, proof.merklize(&(i as u32 + 1).to_le_bytes()).unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn merkle_mountain_range_historical_proof_test() {
+        let mut mmr = MerkleMountainRange::new(HashingAlgorithm::Sha256, 16);
+        let mut roots = vec![];
+        for n in 0..50u32 {
+            mmr.append(&n.to_le_bytes());
+            roots.push(mmr.get_merkle_root().unwrap());
+        }
+        for (size, root) in roots.iter().enumerate() {
+            let leaf_count = size as u64 + 1;
+            assert_eq!(*root, mmr.get_merkle_root_at(leaf_count).unwrap());
+            for i in 0..leaf_count {
+                let proof = mmr.merkle_proof_index_at(i, leaf_count).unwrap();
+                assert_eq!(*root, proof.merklize_hash(&mmr.get_leaf_hash(i as usize).unwrap()).unwrap());
+            }
+        }
+        assert!(mmr.get_merkle_root_at(0).is_err());
+        assert!(mmr.get_merkle_root_at(51).is_err());
+        assert!(mmr.merkle_proof_index_at(20, 20).is_err());
+    }
+
+    #[test]
+    fn merkle_mountain_range_invalid_proof_test() {
+        let mut mmr = MerkleMountainRange::new(HashingAlgorithm::Sha256, 32);
+        for n in 0..11u32 {
+            mmr.append(&n.to_le_bytes());
+        }
+        let proof = mmr.merkle_proof_index(5).unwrap();
+        let truncated = MerkleMountainRangeProof::new(
+            HashingAlgorithm::Sha256,
+            32,
+            proof.get_leaf_count(),
+            proof.get_index(),
+            proof.get_pairing_hashes()[32..].to_vec()
+        );
+        assert!(truncated.merklize(&5u32.to_le_bytes()).is_err());
+        assert!(proof.merklize_hash(&[0u8; 20]).is_err());
+    }
+}
@@ -1,47 +1,65 @@
 #[cfg(not(target_arch = "wasm32"))]
 use anchor_lang::prelude::*;
 use crate::{MerkleError, Result};
-use super::HashingAlgorithm;
+use super::{DomainSeparation, HashingAlgorithm, MerkleHasher, PairingMode};
 
 #[derive(Debug, Clone)]
 #[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
-pub struct MerkleProof {
-    algorithm: HashingAlgorithm,
+pub struct MerkleProof<H: MerkleHasher = HashingAlgorithm> {
+    algorithm: H,
     hash_size: u8,
     index: u32,
-    hashes: Vec<u8>
+    hashes: Vec<u8>,
+    pairing_mode: PairingMode,
+    domain_separation: DomainSeparation
 }
 
-impl MerkleProof {
-    pub fn new(algorithm: HashingAlgorithm, hash_size: u8, index: u32, hashes: Vec<u8>) -> Self {
-        let mut hash_size = hash_size;
-        if hash_size == 0 || hash_size > 32 {
-            hash_size = 32
-        }
+impl<H: MerkleHasher> MerkleProof<H> {
+    pub fn new(algorithm: H, hash_size: u8, index: u32, hashes: Vec<u8>) -> Self {
+        let hash_size = algorithm.normalize_hash_size(hash_size);
         Self {
             algorithm,
             index,
             hash_size,
-            hashes
+            hashes,
+            pairing_mode: PairingMode::Indexed,
+            domain_separation: DomainSeparation::Untagged
         }
     }
 
+    // Set how child hashes are ordered when pairing them. Sorted proofs ignore the index.
+    pub fn with_pairing_mode(mut self, pairing_mode: PairingMode) -> Self {
+        self.pairing_mode = pairing_mode;
+        self
+    }
+
+    // Set whether leaves and branches are tagged before hashing. Must match the tree.
+    pub fn with_domain_separation(mut self, domain_separation: DomainSeparation) -> Self {
+        self.domain_separation = domain_separation;
+        self
+    }
+
     // Hash with defined hashing algorithm and truncate to defined length
     pub fn hash(&self, m: &[u8]) -> Vec<u8> {
-        self.algorithm.hash(m, self.hash_size as usize)
+        self.algorithm.hash_truncated(m, self.hash_size as usize)
     }
 
     // Double hash with defined hashing algorithm and truncate to defined length
     pub fn double_hash(&self, m: &[u8]) -> Vec<u8> {
-        self.algorithm.double_hash(m, self.hash_size as usize)
+        self.algorithm.double_hash_truncated(m, self.hash_size as usize)
+    }
+
+    // Double hash a leaf, tagging it first if domain separation is enabled
+    pub fn leaf_hash(&self, leaf: &[u8]) -> Vec<u8> {
+        self.double_hash(&self.domain_separation.leaf(leaf))
     }
 
     // Merklize from a leaf
     pub fn merklize(&self, leaf: &[u8]) -> Result<Vec<u8>> {
         // If our pairing hashes are empty, return the untruncated hash
-        match self.hashes.len() == 0 {
-            true => Ok(self.algorithm.double_hash(leaf, 0)),
-            false => self.merklize_hash_unchecked(&self.double_hash(leaf))
+        match self.hashes.is_empty() {
+            true => Ok(self.algorithm.double_hash_truncated(&self.domain_separation.leaf(leaf), 0)),
+            false => self.merklize_hash_unchecked(&self.leaf_hash(leaf), None)
         }
     }
 
@@ -49,61 +67,331 @@ impl MerkleProof {
     pub fn merklize_hash(&self, hash: &[u8]) -> Result<Vec<u8>> {
         // If pairing hashes are empty and our hash is 32 bytes long, return early
         if hash.len() != self.hash_size as usize {
-            match self.hashes.is_empty() && hash.len() == 32 {
+            match self.hashes.is_empty() && hash.len() == self.algorithm.output_size() {
                 true => return Ok(hash.to_vec()),
                 false => return Err(MerkleError::InvalidHashSize.into())
             }
         }
-        self.merklize_hash_unchecked(hash)
+        self.merklize_hash_unchecked(hash, None)
     }
 
-    // Merklize from a hash. NOTE: There are no length checks being performed here.
-    fn merklize_hash_unchecked(&self, hash: &[u8]) -> Result<Vec<u8>> {
+    // Merklize from a leaf in a tree of leaf_count leaves, rejecting proofs through an identical
+    // adjacent pair (CVE-2012-2459). Only the last node of an odd level may be paired with itself.
+    pub fn merklize_strict(&self, leaf: &[u8], leaf_count: u32) -> Result<Vec<u8>> {
+        match self.hashes.is_empty() && leaf_count == 1 && self.index == 0 {
+            true => self.merklize(leaf),
+            false => self.merklize_hash_strict(&self.leaf_hash(leaf), leaf_count)
+        }
+    }
+
+    // Merklize from a leaf hash in a tree of leaf_count leaves, rejecting proofs through an
+    // identical adjacent pair
+    pub fn merklize_hash_strict(&self, hash: &[u8], leaf_count: u32) -> Result<Vec<u8>> {
+        if hash.len() != self.hash_size as usize {
+            return Err(MerkleError::InvalidHashSize.into())
+        }
+        if self.index >= leaf_count {
+            return Err(MerkleError::LeafOutOfRange.into())
+        }
+        self.merklize_hash_unchecked(hash, Some(leaf_count))
+    }
+
+    // Merklize from a hash, checking each level against the leaf count if we know it. NOTE: There
+    // are no length checks being performed here.
+    fn merklize_hash_unchecked(&self, hash: &[u8], leaf_count: Option<u32>) -> Result<Vec<u8>> {
         let size = self.hash_size as usize;
         // If the pairing hashes are not a valid length, return an invalid size error
         if self.hashes.len() % size != 0 {
             return Err(MerkleError::InvalidHashSize.into());
         }
         // If there are no pairing hashes, simply return the hashed data
-        if self.hashes.len() == 0 {
-            return Ok(hash.to_vec())
+        if self.hashes.is_empty() {
+            return match leaf_count {
+                None | Some(1) => Ok(hash.to_vec()),
+                _ => Err(MerkleError::InvalidProof.into())
+            }
         }
         let hash_count = self.hashes.len() / size;
         let mut index = self.index;
+        let mut width = leaf_count;
         let mut h = hash.to_vec();
-        let mut m = vec![0u8;size*2];
         for i in 0..hash_count {
-            match index%2 == 0 {
-                true => {
-                    m[..size].copy_from_slice(&h);
-                    m[size..].copy_from_slice(&self.hashes[i*size..size*(i+1)]);
-                },
-                false => {
-                    m[..size].copy_from_slice(&self.hashes[i*size..size*(i+1)]);
-                    m[size..].copy_from_slice(&h);
+            let sibling = &self.hashes[i*size..size*(i+1)];
+            if let Some(w) = width {
+                // The proof must hold exactly one pairing hash per level of the tree, and a node
+                // may only equal its sibling if it is the last node of an odd level
+                let self_paired = index%2 == 0 && index == w-1;
+                match (w == 1, self_paired, sibling == h.as_slice()) {
+                    (true, _, _) | (false, true, false) => return Err(MerkleError::InvalidProof.into()),
+                    (false, false, true) => return Err(MerkleError::MutatedTree.into()),
+                    _ => width = Some(w.div_ceil(2))
                 }
             }
+            let (left, right) = match index%2 == 0 {
+                true => self.pairing_mode.ordered(&h, sibling),
+                false => self.pairing_mode.ordered(sibling, &h)
+            };
+            let m = self.domain_separation.node(left, right);
             h = match i == hash_count-1 {
-                true => self.algorithm.hash(&m, 32),
+                true => self.algorithm.hash_truncated(&m, 0),
                 false => self.hash(&m)
             };
-            index = index/2;
+            index /= 2;
+        }
+        if width.is_some_and(|w| w != 1) {
+            return Err(MerkleError::InvalidProof.into())
         }
         Ok(h)
     }
 
+    // Verify old_leaf against the root of a tree of leaf_count leaves and return the root with
+    // new_leaf in its place. Only the root needs to be stored to allow updates, as the path to the
+    // leaf is unchanged. As in merklize_strict, proofs through an identical adjacent pair are
+    // rejected.
+    pub fn update_root(&self, root: &[u8], old_leaf: &[u8], new_leaf: &[u8], leaf_count: u32) -> Result<Vec<u8>> {
+        match self.hashes.is_empty() && leaf_count == 1 && self.index == 0 {
+            true => {
+                if self.merklize(old_leaf)? != root {
+                    return Err(MerkleError::InvalidProof.into())
+                }
+                self.merklize(new_leaf)
+            },
+            false => self.update_root_hash(root, &self.leaf_hash(old_leaf), &self.leaf_hash(new_leaf), leaf_count)
+        }
+    }
+
+    // Verify old_hash against the root of a tree of leaf_count leaves and return the root with
+    // new_hash in its place
+    pub fn update_root_hash(&self, root: &[u8], old_hash: &[u8], new_hash: &[u8], leaf_count: u32) -> Result<Vec<u8>> {
+        if old_hash.len() != new_hash.len() {
+            return Err(MerkleError::InvalidHashSize.into())
+        }
+        // The root of a single leaf tree may be its untruncated leaf hash
+        let single_leaf = self.hashes.is_empty() && leaf_count == 1 && self.index == 0;
+        if single_leaf && old_hash.len() == self.algorithm.output_size() {
+            if old_hash != root {
+                return Err(MerkleError::InvalidProof.into())
+            }
+            return Ok(new_hash.to_vec())
+        }
+        if self.merklize_hash_strict(old_hash, leaf_count)? != root {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        Ok(self.update_root_unchecked(new_hash, leaf_count))
+    }
+
+    // Merklize new_hash along the path of a verified proof. The last node of an odd level is
+    // paired with itself, so its sibling changes along with it.
+    fn update_root_unchecked(&self, new_hash: &[u8], leaf_count: u32) -> Vec<u8> {
+        let size = self.hash_size as usize;
+        let hash_count = self.hashes.len() / size;
+        let mut index = self.index;
+        let mut width = leaf_count;
+        let mut h = new_hash.to_vec();
+        for (i, sibling) in self.hashes.chunks(size).enumerate() {
+            let sibling = match index%2 == 0 && index == width-1 {
+                true => h.clone(),
+                false => sibling.to_vec()
+            };
+            h = self.hash_pair(index, &h, &sibling, i == hash_count-1);
+            index /= 2;
+            width = width.div_ceil(2);
+        }
+        h
+    }
+
+    // Hash a node of the given index with its sibling. Only the root is left untruncated.
+    fn hash_pair(&self, index: u32, h: &[u8], sibling: &[u8], is_root: bool) -> Vec<u8> {
+        let (left, right) = match index%2 == 0 {
+            true => self.pairing_mode.ordered(h, sibling),
+            false => self.pairing_mode.ordered(sibling, h)
+        };
+        let m = self.domain_separation.node(left, right);
+        match is_root {
+            true => self.algorithm.hash_truncated(&m, 0),
+            false => self.hash(&m)
+        }
+    }
+
+    // From a proof of the last leaf of a tree, verify it against root and return the root of the
+    // tree with new_leaf appended
+    pub fn append_root(&self, root: &[u8], last_leaf: &[u8], new_leaf: &[u8]) -> Result<Vec<u8>> {
+        self.append_root_hash(root, &self.leaf_hash(last_leaf), &self.leaf_hash(new_leaf))
+    }
+
+    // From a proof of the last leaf hash of a tree, verify it against root and return the root of
+    // the tree with new_hash appended. The proof is checked against a tree of index + 1 leaves, so
+    // it can't be for any leaf but the last.
+    pub fn append_root_hash(&self, root: &[u8], last_hash: &[u8], new_hash: &[u8]) -> Result<Vec<u8>> {
+        let size = self.hash_size as usize;
+        if new_hash.len() != size {
+            return Err(MerkleError::InvalidHashSize.into())
+        }
+        let leaf_count = self.index.checked_add(1).ok_or(MerkleError::TreeFull)?;
+        if self.merklize_hash_strict(last_hash, leaf_count)? != root {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        // The right edge of the tree before the append, truncated, from the leaves up
+        let mut edge = vec![last_hash.to_vec()];
+        let mut index = self.index;
+        for sibling in self.hashes.chunks(size) {
+            edge.push(self.hash_pair(index, edge.last().unwrap(), sibling, false));
+            index /= 2;
+        }
+        // Walk up from the new leaf. Its left sibling is either the old right edge, or the sibling
+        // the old edge was paired with. The last node of an odd level is paired with itself.
+        let new_index = leaf_count;
+        let height = u32::BITS - new_index.leading_zeros();
+        let mut h = new_hash.to_vec();
+        for (level, old) in edge.iter().enumerate().take(height as usize) {
+            let (n, o) = (new_index >> level, self.index >> level);
+            let sibling = match (n%2 == 0, n == o) {
+                (true, _) => h.clone(),
+                (false, true) => self.hashes[level*size..(level+1)*size].to_vec(),
+                (false, false) => old.clone()
+            };
+            h = self.hash_pair(n, &h, &sibling, level == height as usize - 1);
+        }
+        Ok(h)
+    }
+
+    pub fn get_algorithm(&self) -> H {
+        self.algorithm.clone()
+    }
+
+    pub fn get_hash_size(&self) -> u8 {
+        self.hash_size
+    }
+
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+
     pub fn get_pairing_hashes(&self) -> Vec<u8> {
         self.hashes.clone()
     }
+
+    pub fn get_pairing_mode(&self) -> PairingMode {
+        self.pairing_mode.clone()
+    }
+
+    pub fn get_domain_separation(&self) -> DomainSeparation {
+        self.domain_separation.clone()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::merkle::HashingAlgorithm;
 
+    use crate::{DomainSeparation, MerkleTree, PairingMode};
     use super::MerkleProof;
     use hex_literal::hex;
 
+    // Hashing params that tests compare against MerkleTree with
+    const CONFIGS: [(HashingAlgorithm, u8, PairingMode, DomainSeparation); 3] = [
+        (HashingAlgorithm::Sha256d, 32, PairingMode::Indexed, DomainSeparation::Untagged),
+        (HashingAlgorithm::Keccak, 20, PairingMode::Sorted, DomainSeparation::Untagged),
+        (HashingAlgorithm::Sha256, 16, PairingMode::Indexed, DomainSeparation::Tagged)
+    ];
+
+    // A merklized MerkleTree of the given leaves
+    fn tree(leaves: &[Vec<u8>], algorithm: HashingAlgorithm, hash_size: u8, pairing_mode: PairingMode, domain_separation: DomainSeparation) -> MerkleTree {
+        let mut merkle_tree = MerkleTree::new(algorithm, hash_size)
+            .with_pairing_mode(pairing_mode)
+            .with_domain_separation(domain_separation);
+        leaves.iter().for_each(|leaf| merkle_tree.add_leaf(leaf));
+        merkle_tree.merklize().unwrap();
+        merkle_tree
+    }
+
+    #[test]
+    fn update_root_test() {
+        for (algorithm, hash_size, pairing_mode, domain_separation) in CONFIGS {
+            let mut leaves: Vec<Vec<u8>> = (0..11u8).map(|n| vec![n]).collect();
+            let mut merkle_tree = tree(&leaves, algorithm.clone(), hash_size, pairing_mode.clone(), domain_separation.clone());
+            for i in [0, 7, 10, 3] {
+                let root = merkle_tree.get_merkle_root().unwrap();
+                let proof = merkle_tree.merkle_proof_index(i).unwrap();
+                let new_leaf = vec![i as u8 + 100];
+                assert!(proof.update_root(&root, &new_leaf, &new_leaf, 11).is_err());
+                // The proof doesn't verify in a tree of another height
+                assert!(proof.update_root(&root, &leaves[i], &new_leaf, 6).is_err());
+                let new_root = proof.update_root(&root, &leaves[i], &new_leaf, 11).unwrap();
+                leaves[i] = new_leaf;
+                merkle_tree = tree(&leaves, algorithm.clone(), hash_size, pairing_mode.clone(), domain_separation.clone());
+                assert_eq!(merkle_tree.get_merkle_root().unwrap(), new_root);
+                let hash = merkle_tree.get_leaf_hash(i).unwrap();
+                let proof = merkle_tree.merkle_proof_index(i).unwrap();
+                assert_eq!(new_root, proof.update_root_hash(&new_root, &hash, &hash, 11).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn update_root_identical_leaves_test() {
+        for (algorithm, hash_size, pairing_mode, domain_separation) in CONFIGS {
+            // The last leaf of an odd level is paired with itself, even when it equals its neighbour
+            let mut leaves: Vec<Vec<u8>> = [5u8, 7, 7].iter().map(|n| vec![*n]).collect();
+            let merkle_tree = tree(&leaves, algorithm.clone(), hash_size, pairing_mode.clone(), domain_separation.clone());
+            let proof = merkle_tree.merkle_proof_index(2).unwrap();
+            let new_root = proof.update_root(&merkle_tree.get_merkle_root().unwrap(), &[7u8], &[8u8], 3).unwrap();
+            leaves[2] = vec![8];
+            assert_eq!(tree(&leaves, algorithm.clone(), hash_size, pairing_mode.clone(), domain_separation.clone()).get_merkle_root().unwrap(), new_root);
+
+            // Identical neighbours aren't mistaken for a self-paired node, and are rejected like
+            // any mutated tree rather than both being rewritten
+            let mut merkle_tree = MerkleTree::new(algorithm, hash_size)
+                .with_pairing_mode(pairing_mode)
+                .with_domain_separation(domain_separation);
+            [100u8, 100, 7, 9].iter().for_each(|n| merkle_tree.add_leaf(&[*n]));
+            merkle_tree.merklize().unwrap();
+            let root = merkle_tree.get_merkle_root().unwrap();
+            for i in 0..2 {
+                let proof = merkle_tree.merkle_proof_index(i).unwrap();
+                assert!(proof.update_root(&root, &[100u8], &[8u8], 4).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn append_root_test() {
+        for (algorithm, hash_size, pairing_mode, domain_separation) in CONFIGS {
+            let leaves: Vec<Vec<u8>> = (0..18u8).map(|n| vec![n]).collect();
+            for n in 1..leaves.len() {
+                let merkle_tree = tree(&leaves[..n], algorithm.clone(), hash_size, pairing_mode.clone(), domain_separation.clone());
+                let root = merkle_tree.get_merkle_root().unwrap();
+                let proof = merkle_tree.merkle_proof_index(n - 1).unwrap();
+                let appended = tree(&leaves[..n + 1], algorithm.clone(), hash_size, pairing_mode.clone(), domain_separation.clone());
+                assert_eq!(appended.get_merkle_root().unwrap(), proof.append_root(&root, &leaves[n - 1], &leaves[n]).unwrap());
+                assert!(proof.append_root(&root, &leaves[n], &leaves[n]).is_err());
+                // Only a proof of the last leaf can append
+                if n > 2 {
+                    let proof = merkle_tree.merkle_proof_index(n - 3).unwrap();
+                    assert!(proof.append_root(&root, &leaves[n - 3], &leaves[n]).is_err());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn merkle_proof_layout_test() {
+        // Modes are serialized after the pairing hashes, as they are in a MultiProof
+        let proof = MerkleProof::new(HashingAlgorithm::Keccak, 20, 5, vec![7u8; 40])
+            .with_pairing_mode(PairingMode::Sorted)
+            .with_domain_separation(DomainSeparation::Tagged);
+        let bytes = anchor_lang::AnchorSerialize::try_to_vec(&proof).unwrap();
+        assert_eq!([&[2u8, 20, 5, 0, 0, 0, 40, 0, 0, 0][..], &[7u8; 40], &[1, 1]].concat(), bytes);
+        let proof: MerkleProof = anchor_lang::AnchorDeserialize::try_from_slice(&bytes).unwrap();
+        assert_eq!(PairingMode::Sorted, proof.get_pairing_mode());
+        assert_eq!(DomainSeparation::Tagged, proof.get_domain_separation());
+        assert_eq!(5, proof.get_index());
+        // Unknown algorithms are rejected rather than read as Sha256
+        let mut bytes = bytes;
+        bytes[0] = 7;
+        assert!(<MerkleProof as anchor_lang::AnchorDeserialize>::try_from_slice(&bytes).is_err());
+    }
+
     #[test]
     fn test_spv_proof() {
         let spv = MerkleProof::new(
@@ -129,7 +417,7 @@ mod tests {
         );
         assert_eq!(
             hex!("e43a1de4dd9c526274b8ea9e4bf01fe8928649f8e5b94abc4e05d83b8abeb924").to_vec(), 
-            spv.merklize(&hex!("01000000017125b04467dc2e3e766a0dae2b7a2f74211c7aa7bf796d47fbf44c259be23462661100006b483045022100f1e5fdfd36837a2e84225e157d25f4d341cad49bfdc909e0332e5e5a58e849a102203b5c59d2f5cf4c6f84b2bc189a03ed802d48784f335b712f73e80f807d4cdd714121037d53430715b2bc8463847e79d7e259c11a7d81bf7d6166e003e1b103b65731ffffffffff0123020000000000001976a9140ec56960e83cd3c03c8882e0fd34d462a34c653888ac00000000").to_vec()).unwrap()
+            spv.merklize(&hex!("01000000017125b04467dc2e3e766a0dae2b7a2f74211c7aa7bf796d47fbf44c259be23462661100006b483045022100f1e5fdfd36837a2e84225e157d25f4d341cad49bfdc909e0332e5e5a58e849a102203b5c59d2f5cf4c6f84b2bc189a03ed802d48784f335b712f73e80f807d4cdd714121037d53430715b2bc8463847e79d7e259c11a7d81bf7d6166e003e1b103b65731ffffffffff0123020000000000001976a9140ec56960e83cd3c03c8882e0fd34d462a34c653888ac00000000")).unwrap()
         );
     }
 }
\ No newline at end of file
@@ -0,0 +1,368 @@
+#[cfg(not(target_arch = "wasm32"))]
+use anchor_lang::prelude::*;
+use std::fmt::Debug;
+use crate::{HashingAlgorithm, MerkleError, Result};
+
+// A Merkle tree in which every node commits to both a hash and the sum of the balances beneath it,
+// for proofs of liabilities such as proof-of-reserves snapshots.
+//
+// Leaves are hashed as double_hash(leaf || balance) and branches as
+// hash(left_hash || left_sum || right_hash || right_sum), with sums encoded as little endian
+// integers of the tree's MerkleSum type, u64 by default.
+// Committing to both child sums separately stops a prover from shifting balance between siblings.
+// The last node of an odd level is paired with an empty node of zero hash and zero sum rather than
+// with itself, so that no balance is counted twice.
+//
+// Balances and sums are unsigned, so no node can carry a negative balance, and every addition is
+// checked on both the prover and verifier side so that sums cannot wrap around.
+#[derive(Debug, Clone)]
+pub struct MerkleSumTree<S: MerkleSum = u64> {
+    algorithm: HashingAlgorithm,
+    hash_size: u8,
+    root: Vec<u8>,
+    total: S,
+    nodes: Vec<Vec<(Vec<u8>, S)>>
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub struct MerkleSumProof<S: MerkleSum = u64> {
+    algorithm: HashingAlgorithm,
+    hash_size: u8,
+    index: u32,
+    hashes: Vec<u8>,
+    sums: Vec<S>
+}
+
+// An unsigned balance type that sums can be committed to, encoded little endian
+pub trait MerkleSum: Debug + Clone + Copy + Default + PartialEq + Send + Sync {
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn to_le_bytes(self) -> Vec<u8>;
+}
+
+impl MerkleSum for u64 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        u64::checked_add(self, other)
+    }
+
+    fn to_le_bytes(self) -> Vec<u8> {
+        u64::to_le_bytes(self).to_vec()
+    }
+}
+
+impl MerkleSum for u128 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        u128::checked_add(self, other)
+    }
+
+    fn to_le_bytes(self) -> Vec<u8> {
+        u128::to_le_bytes(self).to_vec()
+    }
+}
+
+// Hash two children and add their sums, checking for overflow
+fn hash_pair<S: MerkleSum>(algorithm: &HashingAlgorithm, left: (&[u8], S), right: (&[u8], S), s: usize) -> Result<(Vec<u8>, S)> {
+    let sum = left.1.checked_add(right.1).ok_or(MerkleError::SumOverflow)?;
+    let hash = algorithm.hash(&[left.0, &left.1.to_le_bytes(), right.0, &right.1.to_le_bytes()].concat(), s);
+    Ok((hash, sum))
+}
+
+impl MerkleSumTree {
+    // A tree of u64 balances
+    pub fn new(algorithm: HashingAlgorithm, hash_size: u8) -> Self {
+        Self::new_with_sum(algorithm, hash_size)
+    }
+}
+
+impl<S: MerkleSum> MerkleSumTree<S> {
+    // A tree of balances of any MerkleSum type, such as u128
+    pub fn new_with_sum(algorithm: HashingAlgorithm, hash_size: u8) -> Self {
+        let mut hash_size = hash_size;
+        if hash_size == 0 || hash_size > 32 {
+            hash_size = 32
+        }
+        Self {
+            algorithm,
+            hash_size,
+            root: vec![],
+            total: S::default(),
+            nodes: vec![vec![]]
+        }
+    }
+
+    // Double hash a leaf and its balance with defined hashing algorithm and truncate to defined length
+    fn double_hash(&self, leaf: &[u8], balance: S) -> Vec<u8> {
+        self.algorithm.double_hash(&[leaf, &balance.to_le_bytes()].concat(), self.hash_size as usize)
+    }
+
+    // Hash and append a leaf with its balance
+    pub fn add_leaf(&mut self, leaf: &[u8], balance: S) {
+        self.add_hash_unchecked(self.double_hash(leaf, balance), balance)
+    }
+
+    // Append a hash with a length check. Use with unnormalized data
+    pub fn add_hash(&mut self, hash: Vec<u8>, balance: S) -> Result<()> {
+        if hash.len() != self.hash_size as usize {
+            return Err(MerkleError::InvalidHashSize.into())
+        }
+        self.add_hash_unchecked(hash, balance);
+        Ok(())
+    }
+
+    // Append a hash without a length check. Use with normalized data
+    pub fn add_hash_unchecked(&mut self, hash: Vec<u8>, balance: S) {
+        self.nodes[0].push((hash, balance));
+    }
+
+    pub fn merklize(&mut self) -> Result<()> {
+        self.nodes.truncate(1);
+        self.root = vec![];
+        let empty = vec![0u8; self.hash_size as usize];
+        match self.nodes[0].len() {
+            0 => return Err(MerkleError::TreeEmpty.into()),
+            // A single leaf is its own root
+            1 => {
+                (self.root, self.total) = self.nodes[0][0].clone();
+                return Ok(())
+            },
+            _ => {}
+        }
+        loop {
+            let level = self.nodes.last().ok_or(MerkleError::BranchOutOfRange)?;
+            // Only the final pairing produces an untruncated hash
+            let s = match level.len() <= 2 {
+                true => 32,
+                false => self.hash_size as usize
+            };
+            let next = level.chunks(2).map(|pair| {
+                let (right, right_sum) = pair.get(1).map(|(h, sum)| (h.as_slice(), *sum)).unwrap_or((&empty, S::default()));
+                hash_pair(&self.algorithm, (&pair[0].0, pair[0].1), (right, right_sum), s)
+            }).collect::<Result<Vec<(Vec<u8>, S)>>>()?;
+            if next.len() == 1 {
+                (self.root, self.total) = next[0].clone();
+                return Ok(())
+            }
+            self.nodes.push(next);
+        }
+    }
+
+    fn merklized(&self) -> Result<()> {
+        if self.root.is_empty() {
+            return Err(MerkleError::TreeNotMerklized.into())
+        }
+        Ok(())
+    }
+
+    pub fn get_merkle_root(&self) -> Result<Vec<u8>> {
+        self.merklized()?;
+        Ok(self.root.clone())
+    }
+
+    // Sum of every balance in the tree
+    pub fn get_total(&self) -> Result<S> {
+        self.merklized()?;
+        Ok(self.total)
+    }
+
+    pub fn get_leaf_hash(&self, i: usize) -> Result<Vec<u8>> {
+        match self.nodes[0].get(i) {
+            Some((h, _)) => Ok(h.clone()),
+            None => Err(MerkleError::LeafOutOfRange.into())
+        }
+    }
+
+    pub fn merkle_proof_index(&self, i: usize) -> Result<MerkleSumProof<S>> {
+        self.merklized()?;
+        if i >= self.nodes[0].len() {
+            return Err(MerkleError::LeafOutOfRange.into())
+        }
+        let mut hashes: Vec<Vec<u8>> = vec![];
+        let mut sums = vec![];
+        let mut n = i;
+        for level in self.nodes.iter() {
+            match level.get(n ^ 1) {
+                Some((h, sum)) => {
+                    hashes.push(h.clone());
+                    sums.push(*sum);
+                },
+                None => {
+                    hashes.push(vec![0u8; self.hash_size as usize]);
+                    sums.push(S::default());
+                }
+            }
+            n /= 2;
+        }
+        // A single leaf tree needs no pairing hashes
+        if self.nodes[0].len() == 1 {
+            hashes.clear();
+            sums.clear();
+        }
+        Ok(MerkleSumProof::new(
+            self.algorithm.clone(),
+            self.hash_size,
+            i as u32,
+            hashes.concat(),
+            sums
+        ))
+    }
+}
+
+impl<S: MerkleSum> MerkleSumProof<S> {
+    pub fn new(algorithm: HashingAlgorithm, hash_size: u8, index: u32, hashes: Vec<u8>, sums: Vec<S>) -> Self {
+        let mut hash_size = hash_size;
+        if hash_size == 0 || hash_size > 32 {
+            hash_size = 32
+        }
+        Self {
+            algorithm,
+            hash_size,
+            index,
+            hashes,
+            sums
+        }
+    }
+
+    // Merklize from a leaf and its balance, returning the root and the total it commits to
+    pub fn merklize(&self, leaf: &[u8], balance: S) -> Result<(Vec<u8>, S)> {
+        let hash = self.algorithm.double_hash(&[leaf, &balance.to_le_bytes()].concat(), self.hash_size as usize);
+        self.merklize_hash_unchecked(&hash, balance)
+    }
+
+    // Merklize from a leaf hash and its balance
+    pub fn merklize_hash(&self, hash: &[u8], balance: S) -> Result<(Vec<u8>, S)> {
+        if hash.len() != self.hash_size as usize {
+            return Err(MerkleError::InvalidHashSize.into())
+        }
+        self.merklize_hash_unchecked(hash, balance)
+    }
+
+    // Merklize from a hash. NOTE: There are no length checks being performed on the hash here.
+    fn merklize_hash_unchecked(&self, hash: &[u8], balance: S) -> Result<(Vec<u8>, S)> {
+        let size = self.hash_size as usize;
+        // Every pairing hash must come with its sum
+        if self.hashes.len() % size != 0 || self.hashes.len() / size != self.sums.len() {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        let count = self.sums.len();
+        let mut index = self.index;
+        let mut node = (hash.to_vec(), balance);
+        for (i, (sibling, sum)) in self.hashes.chunks(size).zip(self.sums.iter()).enumerate() {
+            let s = match i == count - 1 {
+                true => 32,
+                false => size
+            };
+            node = match index % 2 == 0 {
+                true => hash_pair(&self.algorithm, (&node.0, node.1), (sibling, *sum), s)?,
+                false => hash_pair(&self.algorithm, (sibling, *sum), (&node.0, node.1), s)?
+            };
+            index /= 2;
+        }
+        Ok(node)
+    }
+
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+
+    pub fn get_pairing_hashes(&self) -> Vec<u8> {
+        self.hashes.clone()
+    }
+
+    pub fn get_pairing_sums(&self) -> Vec<S> {
+        self.sums.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::HashingAlgorithm;
+
+    use super::{MerkleSumProof, MerkleSumTree};
+
+    #[test]
+    fn merkle_sum_tree_test() {
+        for (algorithm, hash_size) in [(HashingAlgorithm::Sha256, 32), (HashingAlgorithm::Keccak, 16)] {
+            for n in 1..20u64 {
+                let mut tree = MerkleSumTree::new(algorithm.clone(), hash_size);
+                for i in 0..n {
+                    tree.add_leaf(&i.to_le_bytes(), i * 100);
+                }
+                tree.merklize().unwrap();
+                let root = tree.get_merkle_root().unwrap();
+                assert_eq!((0..n).map(|i| i * 100).sum::<u64>(), tree.get_total().unwrap());
+                for i in 0..n {
+                    let proof = tree.merkle_proof_index(i as usize).unwrap();
+                    assert_eq!((root.clone(), tree.get_total().unwrap()), proof.merklize(&i.to_le_bytes(), i * 100).unwrap());
+                    // Understating a balance changes both the root and the total
+                    let (r, total) = proof.merklize(&i.to_le_bytes(), i * 100 + 1).unwrap();
+                    assert_ne!(root, r);
+                    assert_ne!(tree.get_total().unwrap(), total);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn merkle_sum_proof_tampered_sums_test() {
+        let mut tree = MerkleSumTree::new(HashingAlgorithm::Sha256, 32);
+        for i in 0..8u64 {
+            tree.add_leaf(&i.to_le_bytes(), 1000);
+        }
+        tree.merklize().unwrap();
+        let root = tree.get_merkle_root().unwrap();
+        let proof = tree.merkle_proof_index(3).unwrap();
+        // Moving balance from one sibling to another must not verify
+        let mut sums = proof.get_pairing_sums();
+        sums[0] -= 500;
+        sums[1] += 500;
+        let tampered = MerkleSumProof::new(HashingAlgorithm::Sha256, 32, 3, proof.get_pairing_hashes(), sums.clone());
+        let (r, total) = tampered.merklize(&3u64.to_le_bytes(), 1000).unwrap();
+        assert_eq!(8000, total);
+        assert_ne!(root, r);
+        // Every pairing hash needs a sum
+        let truncated = MerkleSumProof::new(HashingAlgorithm::Sha256, 32, 3, proof.get_pairing_hashes(), sums[1..].to_vec());
+        assert!(truncated.merklize(&3u64.to_le_bytes(), 1000).is_err());
+    }
+
+    #[test]
+    fn merkle_sum_tree_u128_test() {
+        // Balances and totals beyond a u64
+        let mut tree: MerkleSumTree<u128> = MerkleSumTree::new_with_sum(HashingAlgorithm::Keccak, 20);
+        for i in 0..5u128 {
+            tree.add_leaf(&i.to_le_bytes(), u64::MAX as u128 + i);
+        }
+        tree.merklize().unwrap();
+        let root = tree.get_merkle_root().unwrap();
+        let total = 5 * u64::MAX as u128 + 10;
+        assert_eq!(total, tree.get_total().unwrap());
+        for i in 0..5u128 {
+            let proof = tree.merkle_proof_index(i as usize).unwrap();
+            assert_eq!((root.clone(), total), proof.merklize(&i.to_le_bytes(), u64::MAX as u128 + i).unwrap());
+            assert_ne!(root, proof.merklize(&i.to_le_bytes(), i).unwrap().0);
+        }
+        // The same balances commit to different roots as u64s and u128s
+        let mut narrow = MerkleSumTree::new(HashingAlgorithm::Keccak, 20);
+        let mut wide: MerkleSumTree<u128> = MerkleSumTree::new_with_sum(HashingAlgorithm::Keccak, 20);
+        for i in 0..3u64 {
+            narrow.add_leaf(&i.to_le_bytes(), i);
+            wide.add_leaf(&i.to_le_bytes(), i as u128);
+        }
+        narrow.merklize().unwrap();
+        wide.merklize().unwrap();
+        assert_ne!(narrow.get_merkle_root().unwrap(), wide.get_merkle_root().unwrap());
+        let mut overflow: MerkleSumTree<u128> = MerkleSumTree::new_with_sum(HashingAlgorithm::Sha256, 32);
+        overflow.add_leaf(b"a", u128::MAX);
+        overflow.add_leaf(b"b", 1);
+        assert!(overflow.merklize().is_err());
+    }
+
+    #[test]
+    fn merkle_sum_tree_overflow_test() {
+        let mut tree = MerkleSumTree::new(HashingAlgorithm::Sha256, 32);
+        tree.add_leaf(b"a", u64::MAX);
+        tree.add_leaf(b"b", 1);
+        assert!(tree.merklize().is_err());
+        assert!(tree.get_merkle_root().is_err());
+    }
+}
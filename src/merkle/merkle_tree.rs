@@ -2,35 +2,41 @@
 use rayon::{prelude::*, iter::{IntoParallelIterator,ParallelIterator}};
 #[cfg(not(target_os = "solana"))]
 use anyhow::Result;
-use crate::{HashingAlgorithm, MerkleError};
+use crate::{DomainSeparation, HashingAlgorithm, MerkleError, MerkleHasher, PairingMode};
 #[cfg(target_os = "solana")]
 use anchor_lang::Result;
-use super::MerkleProof;
+use super::{MerkleProof, MultiProof};
 
+// A Bitcoin-compatible Merkle tree, generic over its hasher. Defaults to HashingAlgorithm.
 #[derive(Debug, Clone)]
-pub struct MerkleTree {
-    algorithm: HashingAlgorithm,
+pub struct MerkleTree<H: MerkleHasher = HashingAlgorithm> {
+    algorithm: H,
     hash_size: u8,
     root: Vec<u8>,
-    hashes: Vec<Vec<Vec<u8>>>
+    hashes: Vec<Vec<Vec<u8>>>,
+    // Number of leaves the root and branches were last built from
+    merklized_count: usize,
+    pairing_mode: PairingMode,
+    domain_separation: DomainSeparation
 }
 
 // For non-Solana targets, use Rayon to hash/merklize in parallel
 #[cfg(not(target_os = "solana"))]
-impl MerkleTree {
-    fn merklize_unchecked(h: &Vec<Vec<u8>>, a: HashingAlgorithm, s: usize) -> Vec<Vec<u8>> {
+impl<H: MerkleHasher> MerkleTree<H> {
+    fn merklize_unchecked(h: &[Vec<u8>], a: &H, p: &PairingMode, d: &DomainSeparation, s: usize) -> Vec<Vec<u8>> {
         h.par_chunks(2).into_par_iter().map(|h| {
             if h.len() > 1 {
-                a.hash(&vec![h[0].clone(),h[1].clone()].concat(), s)
+                let (l, r) = p.ordered(&h[0], &h[1]);
+                a.hash_truncated(&d.node(l, r), s)
             } else {
-                a.hash(&vec![h[0].clone(),h[0].clone()].concat(), s)
+                a.hash_truncated(&d.node(&h[0], &h[0]), s)
             }
         }).collect()
     }
 
     pub fn add_leaves(&mut self, leaves: &Vec<Vec<u8>>) -> Result<()> {
         let hashes: Vec<Vec<u8>> = leaves.into_par_iter().map(|leaf| {
-            self.double_hash(leaf)
+            self.leaf_hash(leaf)
         }).collect();
         self.add_hashes_unchecked(hashes)
     }
@@ -38,39 +44,53 @@ impl MerkleTree {
 
 // For Solana targets, merklize in serial
 #[cfg(target_os = "solana")]
-impl MerkleTree {
-    fn merklize_unchecked(h: &Vec<Vec<u8>>, a: HashingAlgorithm, s: usize) -> Vec<Vec<u8>> {
+impl<H: MerkleHasher> MerkleTree<H> {
+    fn merklize_unchecked(h: &[Vec<u8>], a: &H, p: &PairingMode, d: &DomainSeparation, s: usize) -> Vec<Vec<u8>> {
         h.chunks(2).into_iter().map(|h| {
             if h.len() > 1 {
-                a.hash(&vec![h[0].clone(),h[1].clone()].concat(), s)
+                let (l, r) = p.ordered(&h[0], &h[1]);
+                a.hash_truncated(&d.node(l, r), s)
             } else {
-                a.hash(&vec![h[0].clone(),h[0].clone()].concat(), s)
+                a.hash_truncated(&d.node(&h[0], &h[0]), s)
             }
         }).collect()
     }
 
     pub fn add_leaves(&mut self, leaves: &Vec<Vec<u8>>) -> Result<()> {
         let hashes: Vec<Vec<u8>> = leaves.into_iter().map(|leaf| {
-            self.double_hash(leaf)
+            self.leaf_hash(leaf)
         }).collect();
         self.add_hashes_unchecked(hashes)
     }
 }
 
-impl MerkleTree {
+impl<H: MerkleHasher> MerkleTree<H> {
     // Initialize a new tree with configurable size and hashing params
-    pub fn new(algorithm: HashingAlgorithm, hash_size: u8) -> Self {
-        let mut hash_size = hash_size;
-        if hash_size == 0 || hash_size > 32 {
-            hash_size = 32
-        }
+    pub fn new(algorithm: H, hash_size: u8) -> Self {
+        let hash_size = algorithm.normalize_hash_size(hash_size);
         Self {
             algorithm,
             root: vec![],
             hash_size,
-            hashes: vec![vec![]]
+            hashes: vec![vec![]],
+            merklized_count: 0,
+            pairing_mode: PairingMode::Indexed,
+            domain_separation: DomainSeparation::Untagged
         }
     }
+
+    // Set how child hashes are ordered when pairing them
+    pub fn with_pairing_mode(mut self, pairing_mode: PairingMode) -> Self {
+        self.pairing_mode = pairing_mode;
+        self
+    }
+
+    // Set whether leaves and branches are tagged before hashing. Hashes added with add_hash must
+    // then be computed from tagged leaves.
+    pub fn with_domain_separation(mut self, domain_separation: DomainSeparation) -> Self {
+        self.domain_separation = domain_separation;
+        self
+    }
     
     // Append multiple hashes with a length check. Use with unnormalized data
     pub fn add_hashes(&mut self, hashes: Vec<Vec<u8>>) -> Result<()> {
@@ -91,14 +111,19 @@ impl MerkleTree {
 
     // Double hash with defined hashing algorithm and truncate to defined length
     fn double_hash(&self, m: &[u8]) -> Vec<u8> {
-        self.algorithm.double_hash(m, self.hash_size as usize)
+        self.algorithm.double_hash_truncated(m, self.hash_size as usize)
+    }
+
+    // Double hash a leaf, tagging it first if domain separation is enabled
+    fn leaf_hash(&self, leaf: &[u8]) -> Vec<u8> {
+        self.double_hash(&self.domain_separation.leaf(leaf))
     }
 
     // Hash and append a leaf
     pub fn add_leaf(&mut self, leaf: &[u8]) {
         // Double hash to prevent length extension attacks
         // No need for length check
-        self.add_hash_unchecked(self.double_hash(leaf))
+        self.add_hash_unchecked(self.leaf_hash(leaf))
     }
 
     // Append a hash with a length check. Use with unnormalized data
@@ -115,6 +140,8 @@ impl MerkleTree {
         self.hashes[0].push(hash);
     }
 
+    // Merklize the tree. Identical adjacent pairs are accepted, so trees with equal leaves can still
+    // be merklized and proven. Use merklize_strict to reject them.
     pub fn merklize(&mut self) -> Result<()> {
         let len = self.hashes[0].len();
         match len {
@@ -122,28 +149,138 @@ impl MerkleTree {
             1 => {
                 self.reset();
                 self.root = self.hashes[0][0].clone();
+                self.merklized_count = 1;
                 Ok(())
             }, 
             _ => {
                 self.reset();
                 let mut count = self.hashes[0].len();
                 while count > 2 {
-                    let h: Vec<Vec<u8>> = Self::merklize_unchecked(self.hashes.last().ok_or(MerkleError::BranchOutOfRange)?, self.algorithm.clone(), self.hash_size as usize);
+                    let h: Vec<Vec<u8>> = Self::merklize_unchecked(self.hashes.last().ok_or(MerkleError::BranchOutOfRange)?, &self.algorithm, &self.pairing_mode, &self.domain_separation, self.hash_size as usize);
                     count = h.len();
                     self.hashes.push(h);
                 }
-                self.root = Self::merklize_unchecked(self.hashes.last().ok_or(MerkleError::BranchOutOfRange)?, self.algorithm.clone(), 32 as usize)[0].clone();
+                self.root = Self::merklize_unchecked(self.hashes.last().ok_or(MerkleError::BranchOutOfRange)?, &self.algorithm, &self.pairing_mode, &self.domain_separation, 0)[0].clone();
+                self.merklized_count = len;
                 Ok(())
             }
         }
     }
 
+    // Merklize the tree, returning MutatedTree if any level contains an identical adjacent pair, as
+    // two different leaf lists can then share a root (CVE-2012-2459). A mutated tree is left
+    // unmerklized, so it never serves a root or proofs.
+    pub fn merklize_strict(&mut self) -> Result<()> {
+        self.merklize()?;
+        if let Err(e) = self.validate() {
+            self.reset();
+            self.root = vec![];
+            self.merklized_count = 0;
+            return Err(e)
+        }
+        Ok(())
+    }
+
+    // Check that no level of the tree pairs two identical hashes. The last node of an odd level
+    // being paired with itself is expected and allowed.
+    pub fn validate(&self) -> Result<()> {
+        for level in self.hashes.iter() {
+            if level.chunks_exact(2).any(|pair| pair[0] == pair[1]) {
+                return Err(MerkleError::MutatedTree.into())
+            }
+        }
+        Ok(())
+    }
+
+    // Replace a leaf, recomputing only its path to the root if the tree is merklized
+    pub fn set_leaf(&mut self, i: usize, leaf: &[u8]) -> Result<()> {
+        self.set_hashes(vec![(i, self.leaf_hash(leaf))])
+    }
+
+    // Replace a leaf hash, recomputing only its path to the root if the tree is merklized
+    pub fn set_hash(&mut self, i: usize, hash: Vec<u8>) -> Result<()> {
+        self.set_hashes(vec![(i, hash)])
+    }
+
+    // Replace several leaves at once. Paths shared by the updated leaves are only hashed once.
+    pub fn set_leaves(&mut self, leaves: &[(usize, Vec<u8>)]) -> Result<()> {
+        self.set_hashes(leaves.iter().map(|(i, leaf)| (*i, self.leaf_hash(leaf))).collect())
+    }
+
+    // Replace several leaf hashes at once, recomputing only the nodes above them. Returns
+    // MutatedTree if an updated node would equal its sibling, and leaves the tree unchanged, so a
+    // failed update never leaves a mutated tree serving proofs. A tree with leaves added since it
+    // was last merklized only has its leaves replaced, and is checked when merklized again.
+    pub fn set_hashes(&mut self, hashes: Vec<(usize, Vec<u8>)>) -> Result<()> {
+        for (i, hash) in hashes.iter() {
+            self.within_range(*i)?;
+            if hash.len() != self.hash_size as usize {
+                return Err(MerkleError::InvalidHashSize.into())
+            }
+        }
+        let current = self.is_current();
+        let mut dirty: Vec<usize> = hashes.iter().map(|(i, _)| *i).collect();
+        // Nodes replaced so far, restored if the update turns out to mutate the tree
+        let mut replaced = vec![];
+        for (i, hash) in hashes {
+            replaced.push((0, i, std::mem::replace(&mut self.hashes[0][i], hash)));
+        }
+        if !current {
+            return Ok(())
+        }
+        dirty.sort();
+        dirty.dedup();
+        let mut mutated = self.has_identical_sibling(0, &dirty);
+        for level in 1..self.hashes.len() {
+            if mutated {
+                break
+            }
+            dirty = dirty.iter().map(|i| i / 2).collect();
+            dirty.dedup();
+            for i in dirty.iter() {
+                let hash = self.hash_children(level - 1, *i, self.hash_size as usize);
+                replaced.push((level, *i, std::mem::replace(&mut self.hashes[level][*i], hash)));
+            }
+            mutated |= self.has_identical_sibling(level, &dirty);
+        }
+        if mutated {
+            for (level, i, hash) in replaced.into_iter().rev() {
+                self.hashes[level][i] = hash;
+            }
+            return Err(MerkleError::MutatedTree.into())
+        }
+        self.root = match self.hashes[0].len() {
+            1 => self.hashes[0][0].clone(),
+            _ => self.hash_children(self.hashes.len() - 1, 0, 0)
+        };
+        Ok(())
+    }
+
+    // Whether the root and branches were built from the current leaves
+    fn is_current(&self) -> bool {
+        !self.root.is_empty() && self.merklized_count == self.hashes[0].len()
+    }
+
+    // Hash node i of the level above from its children, pairing the last node of an odd level
+    // with itself
+    fn hash_children(&self, level: usize, i: usize, size: usize) -> Vec<u8> {
+        let left = &self.hashes[level][2*i];
+        let right = self.hashes[level].get(2*i+1).unwrap_or(left);
+        let (l, r) = self.pairing_mode.ordered(left, right);
+        self.algorithm.hash_truncated(&self.domain_separation.node(l, r), size)
+    }
+
+    fn has_identical_sibling(&self, level: usize, indices: &[usize]) -> bool {
+        let nodes = &self.hashes[level];
+        indices.iter().any(|i| nodes.get(i ^ 1).is_some_and(|sibling| *sibling == nodes[*i]))
+    }
+
     pub fn reset(&mut self) {
         self.hashes.truncate(1);
     }
 
     fn merklized(&self) -> Result<()> {
-        if self.root.eq(&[0u8;32]) {
+        if self.root.is_empty() || self.root.eq(&[0u8;32]) {
             return Err(MerkleError::TreeNotMerklized.into())
         }
         Ok(())
@@ -151,7 +288,7 @@ impl MerkleTree {
 
     fn within_range(&self, index: usize) -> Result<()> {
         let len = self.hashes[0].len();
-        if index > len {
+        if index >= len {
             return Err(MerkleError::LeafOutOfRange.into())
         }
         Ok(())
@@ -184,19 +321,19 @@ impl MerkleTree {
         Ok(self.hashes[0][i].clone())
     }
 
-    pub fn merkle_proof_hash(&self, hash: Vec<u8>) -> Result<MerkleProof> {
+    pub fn merkle_proof_hash(&self, hash: Vec<u8>) -> Result<MerkleProof<H>> {
         self.merklized()?;
         let i = self.get_hash_index(hash)?;
         self.merkle_proof_index_unchecked(i)
     }
 
-    pub fn merkle_proof_index(&self, i: usize) -> Result<MerkleProof> {
+    pub fn merkle_proof_index(&self, i: usize) -> Result<MerkleProof<H>> {
         self.merklized()?;
         self.within_range(i)?;
         self.merkle_proof_index_unchecked(i)
     }
 
-    fn merkle_proof_index_unchecked(&self, i: usize) -> Result<MerkleProof> {
+    fn merkle_proof_index_unchecked(&self, i: usize) -> Result<MerkleProof<H>> {
         let len = self.hashes[0].len();
         match len {
             // We can't have zero leaves in a Merkle tree
@@ -207,7 +344,7 @@ impl MerkleTree {
                 self.hash_size,
                 i as u32,
                 vec![],
-            )),
+            ).with_pairing_mode(self.pairing_mode.clone()).with_domain_separation(self.domain_separation.clone())),
             _ => {
                 let mut hashes: Vec<Vec<u8>> = vec![];
                 let mut n = i;
@@ -231,16 +368,56 @@ impl MerkleTree {
                     self.hash_size,
                     i as u32,
                     hashes.concat()
-                ))
+                ).with_pairing_mode(self.pairing_mode.clone()).with_domain_separation(self.domain_separation.clone()))
             }
         }
     }
+
+    // Prove several leaves at once. Siblings that are themselves proven or derived from proven
+    // leaves are left out of the proof.
+    pub fn multiproof(&self, indices: &[u32]) -> Result<MultiProof<H>> {
+        self.merklized()?;
+        let len = self.hashes[0].len();
+        let mut known: Vec<usize> = indices.iter().map(|i| *i as usize).collect();
+        known.sort();
+        known.dedup();
+        match known.last() {
+            None => return Err(MerkleError::LeafNotFound.into()),
+            Some(i) if *i >= len => return Err(MerkleError::LeafOutOfRange.into()),
+            _ => {}
+        }
+        let indices: Vec<u32> = known.iter().map(|i| *i as u32).collect();
+        let mut hashes: Vec<Vec<u8>> = vec![];
+        for level in self.hashes.iter().filter(|level| level.len() > 1) {
+            let mut next = vec![];
+            let mut known_iter = known.iter().peekable();
+            while let Some(n) = known_iter.next() {
+                let sibling = n ^ 1;
+                // The last node of an odd level is paired with itself
+                if sibling < level.len() {
+                    match known_iter.peek() {
+                        Some(m) if **m == sibling => { known_iter.next(); },
+                        _ => hashes.push(level[sibling].clone())
+                    }
+                }
+                next.push(n / 2);
+            }
+            known = next;
+        }
+        Ok(MultiProof::new(
+            self.algorithm.clone(),
+            self.hash_size,
+            len as u32,
+            indices,
+            hashes.concat()
+        ).with_pairing_mode(self.pairing_mode.clone()).with_domain_separation(self.domain_separation.clone()))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use hex_literal::hex;
-    use crate::{merkle, HashingAlgorithm, MerkleProof};
+    use crate::{DomainSeparation, HashingAlgorithm, MerkleHasher, MerkleProof, PairingMode};
 
     use super::MerkleTree;
 
@@ -370,4 +547,292 @@ mod tests {
         println!("{:?}", hex::encode(proof_root))
         
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn merkle_tree_sorted_pairing_test() {
+        // OpenZeppelin's MerkleProof.processProof, which hashes each pair in sorted order
+        fn process_proof(leaf: &[u8], proof: &[u8]) -> Vec<u8> {
+            proof.chunks(32).fold(leaf.to_vec(), |h, p| match h.as_slice() < p {
+                true => HashingAlgorithm::Keccak.hash(&[h.as_slice(), p].concat(), 32),
+                false => HashingAlgorithm::Keccak.hash(&[p, h.as_slice()].concat(), 32)
+            })
+        }
+
+        for count in 1..12u32 {
+            let mut merkle_tree = MerkleTree::new(HashingAlgorithm::Keccak, 32).with_pairing_mode(PairingMode::Sorted);
+            for n in 0..count {
+                merkle_tree.add_leaf(&n.to_le_bytes());
+            }
+            merkle_tree.merklize().unwrap();
+            let root = merkle_tree.get_merkle_root().unwrap();
+            for n in 0..count as usize {
+                let proof = merkle_tree.merkle_proof_index(n).unwrap();
+                let leaf_hash = merkle_tree.get_leaf_hash(n).unwrap();
+                assert_eq!(root, process_proof(&leaf_hash, &proof.get_pairing_hashes()));
+                // Sorted proofs don't need to know the index of the leaf
+                let indexless = MerkleProof::new(HashingAlgorithm::Keccak, 32, 0, proof.get_pairing_hashes()).with_pairing_mode(PairingMode::Sorted);
+                assert_eq!(root, indexless.merklize_hash(&leaf_hash).unwrap());
+            }
+        }
+
+        let mut indexed = MerkleTree::new(HashingAlgorithm::Keccak, 32);
+        let mut sorted = MerkleTree::new(HashingAlgorithm::Keccak, 32).with_pairing_mode(PairingMode::Sorted);
+        indexed.add_hashes(vec![[2u8; 32].to_vec(), [1u8; 32].to_vec()]).unwrap();
+        sorted.add_hashes(vec![[2u8; 32].to_vec(), [1u8; 32].to_vec()]).unwrap();
+        indexed.merklize().unwrap();
+        sorted.merklize().unwrap();
+        assert_eq!(HashingAlgorithm::Keccak.hash(&[[1u8; 32], [2u8; 32]].concat(), 32), sorted.root);
+        assert_ne!(indexed.root, sorted.root);
+    }
+
+    #[test]
+    fn merkle_tree_multiproof_test() {
+        for (algorithm, hash_size, pairing_mode) in [
+            (HashingAlgorithm::Sha256d, 32, PairingMode::Indexed),
+            (HashingAlgorithm::Keccak, 20, PairingMode::Sorted)
+        ] {
+            for n in 1..20u32 {
+                let mut merkle_tree = MerkleTree::new(algorithm.clone(), hash_size).with_pairing_mode(pairing_mode.clone());
+                let leaves: Vec<Vec<u8>> = (0..n).map(|i| i.to_le_bytes().to_vec()).collect();
+                merkle_tree.add_leaves(&leaves).unwrap();
+                merkle_tree.merklize().unwrap();
+                let root = merkle_tree.get_merkle_root().unwrap();
+                for indices in [vec![0], vec![n - 1, 0], (0..n).collect(), (0..n).step_by(3).collect()] {
+                    let proof = merkle_tree.multiproof(&indices).unwrap();
+                    let proven: Vec<Vec<u8>> = proof.get_indices().iter().map(|i| leaves[*i as usize].clone()).collect();
+                    assert_eq!(root, proof.merklize(&proven).unwrap());
+                    if n > 1 {
+                        let mut tampered = proven.clone();
+                        tampered[0] = n.to_le_bytes().to_vec();
+                        assert_ne!(root, proof.merklize(&tampered).unwrap());
+                    }
+                }
+                // Proving every leaf needs no pairing hashes at all
+                assert!(merkle_tree.multiproof(&(0..n).collect::<Vec<u32>>()).unwrap().get_pairing_hashes().is_empty());
+                assert!(merkle_tree.multiproof(&[n]).is_err());
+                assert!(merkle_tree.multiproof(&[]).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn merkle_tree_domain_separation_test() {
+        let leaves: Vec<Vec<u8>> = (0..4u32).map(|i| i.to_le_bytes().to_vec()).collect();
+        for domain_separation in [DomainSeparation::Untagged, DomainSeparation::Tagged] {
+            let mut merkle_tree = MerkleTree::new(HashingAlgorithm::Sha256d, 32).with_domain_separation(domain_separation.clone());
+            merkle_tree.add_leaves(&leaves).unwrap();
+            merkle_tree.merklize().unwrap();
+            let root = merkle_tree.get_merkle_root().unwrap();
+            let proof = merkle_tree.merkle_proof_index(0).unwrap();
+            assert_eq!(domain_separation, proof.get_domain_separation());
+            assert_eq!(root, proof.merklize(&leaves[0]).unwrap());
+            // Present the preimage of the first branch as a leaf one level up
+            let branch = [merkle_tree.get_leaf_hash(0).unwrap(), merkle_tree.get_leaf_hash(1).unwrap()].concat();
+            let forged = MerkleProof::new(HashingAlgorithm::Sha256d, 32, 0, proof.get_pairing_hashes()[32..].to_vec())
+                .with_domain_separation(domain_separation.clone());
+            match domain_separation {
+                DomainSeparation::Untagged => assert_eq!(root, forged.merklize(&branch).unwrap()),
+                DomainSeparation::Tagged => {
+                    assert_ne!(root, forged.merklize(&branch).unwrap());
+                    // Verifying a tagged proof without tags must fail too
+                    let untagged = MerkleProof::new(HashingAlgorithm::Sha256d, 32, 0, proof.get_pairing_hashes());
+                    assert_ne!(root, untagged.merklize(&leaves[0]).unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn merkle_tree_mutation_test() {
+        let leaves: Vec<Vec<u8>> = (0..3u32).map(|i| i.to_le_bytes().to_vec()).collect();
+        let mut merkle_tree = MerkleTree::new(HashingAlgorithm::Sha256d, 32);
+        merkle_tree.add_leaves(&leaves).unwrap();
+        merkle_tree.merklize().unwrap();
+        let root = merkle_tree.get_merkle_root().unwrap();
+        // Duplicating the odd last leaf produces the same root
+        let mut mutated = MerkleTree::new(HashingAlgorithm::Sha256d, 32);
+        mutated.add_leaves(&[leaves.clone(), vec![leaves[2].clone()]].concat()).unwrap();
+        assert!(mutated.merklize_strict().is_err());
+        // A tree that fails strict merklization serves no root or proofs
+        assert!(mutated.get_merkle_root().is_err());
+        assert!(mutated.merkle_proof_index(0).is_err());
+        // Merklized leniently, it shares the root of the original leaves
+        mutated.merklize().unwrap();
+        assert!(mutated.validate().is_err());
+        assert_eq!(root, mutated.get_merkle_root().unwrap());
+        // Verifiers that know the leaf count reject proofs into the duplicated tail
+        for i in 2..4 {
+            let proof = mutated.merkle_proof_index(i).unwrap();
+            assert_eq!(root, proof.merklize(&leaves[2]).unwrap());
+            assert!(proof.merklize_strict(&leaves[2], 4).is_err());
+        }
+        assert!(mutated.merkle_proof_index(3).unwrap().merklize_strict(&leaves[2], 3).is_err());
+        for (i, leaf) in leaves.iter().enumerate() {
+            let proof = merkle_tree.merkle_proof_index(i).unwrap();
+            assert_eq!(root, proof.merklize_strict(leaf, 3).unwrap());
+            assert!(proof.merklize_strict(leaf, 5).is_err());
+        }
+        // Multiproofs always know the leaf count, so strict verification rejects the duplicated tail
+        let multiproof = mutated.multiproof(&[3]).unwrap();
+        assert_eq!(root, multiproof.merklize(&[leaves[2].clone()]).unwrap());
+        assert!(multiproof.merklize_strict(&[leaves[2].clone()]).is_err());
+        let multiproof = merkle_tree.multiproof(&[0, 2]).unwrap();
+        assert_eq!(root, multiproof.merklize_strict(&[leaves[0].clone(), leaves[2].clone()]).unwrap());
+        // Equal neighbouring leaves verify leniently
+        let mut equal = MerkleTree::new(HashingAlgorithm::Sha256d, 32);
+        equal.add_leaves(&vec![leaves[0].clone(); 4]).unwrap();
+        assert!(equal.merklize_strict().is_err());
+        equal.merklize().unwrap();
+        let multiproof = equal.multiproof(&[0, 3]).unwrap();
+        assert_eq!(equal.get_merkle_root().unwrap(), multiproof.merklize(&[leaves[0].clone(), leaves[0].clone()]).unwrap());
+        assert!(multiproof.merklize_strict(&[leaves[0].clone(), leaves[0].clone()]).is_err());
+    }
+
+    // A 64 byte hasher, to check nothing assumes 32 byte hashes
+    #[derive(Debug, Clone)]
+    struct Sha256Keccak;
+
+    impl MerkleHasher for Sha256Keccak {
+        fn output_size(&self) -> usize {
+            64
+        }
+
+        fn hashv(&self, vals: &[&[u8]]) -> Vec<u8> {
+            [HashingAlgorithm::Sha256.hashv(vals), HashingAlgorithm::Keccak.hashv(vals)].concat()
+        }
+    }
+
+    #[test]
+    fn merkle_tree_custom_hasher_test() {
+        for hash_size in [0, 40] {
+            let mut merkle_tree = MerkleTree::new(Sha256Keccak, hash_size);
+            let leaves: Vec<Vec<u8>> = (0..5u32).map(|i| i.to_le_bytes().to_vec()).collect();
+            merkle_tree.add_leaves(&leaves).unwrap();
+            merkle_tree.merklize().unwrap();
+            let root = merkle_tree.get_merkle_root().unwrap();
+            assert_eq!(64, root.len());
+            assert_eq!(match hash_size { 0 => 64, n => n as usize }, merkle_tree.get_leaf_hash(0).unwrap().len());
+            for (i, leaf) in leaves.iter().enumerate() {
+                assert_eq!(root, merkle_tree.merkle_proof_index(i).unwrap().merklize(leaf).unwrap());
+            }
+            assert_eq!(root, merkle_tree.multiproof(&[1, 3]).unwrap().merklize(&[leaves[1].clone(), leaves[3].clone()]).unwrap());
+        }
+        // The built-in hashers hash several slices the same as their concatenation
+        assert_eq!(HashingAlgorithm::Keccakd.hash(b"ab", 32), HashingAlgorithm::Keccakd.hashv(&[b"a", b"b"]));
+    }
+
+    #[test]
+    fn merkle_tree_blake3_test() {
+        assert_eq!(hex!("af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262").to_vec(), HashingAlgorithm::Blake3.hash(b"", 32));
+        assert_eq!(HashingAlgorithm::Blake3.hash(&HashingAlgorithm::Blake3.hash(b"ab", 32), 32), HashingAlgorithm::Blake3d.hashv(&[b"a", b"b"]));
+        for (algorithm, hash_size) in [(HashingAlgorithm::Blake3, 32), (HashingAlgorithm::Blake3d, 20)] {
+            let mut merkle_tree = MerkleTree::new(algorithm.clone(), hash_size);
+            let leaves: Vec<Vec<u8>> = (0..7u32).map(|i| i.to_le_bytes().to_vec()).collect();
+            merkle_tree.add_leaves(&leaves).unwrap();
+            merkle_tree.merklize().unwrap();
+            let root = merkle_tree.get_merkle_root().unwrap();
+            for (i, leaf) in leaves.iter().enumerate() {
+                assert_eq!(root, merkle_tree.merkle_proof_index(i).unwrap().merklize(leaf).unwrap());
+            }
+            assert_eq!(algorithm, HashingAlgorithm::try_from(u8::from(algorithm.clone())).unwrap());
+        }
+    }
+
+    #[test]
+    fn merkle_tree_poseidon_test() {
+        use solana_program::poseidon::{hashv, Endianness, Parameters};
+        // Input is prefixed with its length and hashed as field elements, matching the sol_poseidon syscall
+        let length = |n: u8| {
+            let mut l = [0u8; 32];
+            l[31] = n;
+            l
+        };
+        let poseidon = |elements: &[&[u8]]| hashv(Parameters::Bn254X5, Endianness::BigEndian, elements).unwrap().to_bytes().to_vec();
+        assert_eq!(poseidon(&[&length(32), &[1u8; 32]]), HashingAlgorithm::Poseidon.hashv(&[&[1u8; 32]]));
+        assert_eq!(poseidon(&[&length(64), &[1u8; 32], &[2u8; 32]]), HashingAlgorithm::Poseidon.hashv(&[&[1u8; 32], &[2u8; 32]]));
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        assert_eq!(poseidon(&[&length(1), &one]), HashingAlgorithm::Poseidon.hash(&[1], 32));
+        // The encoding is injective, so leading zeros and domain separation tags aren't lost
+        assert_eq!(vec![length(1), one], crate::to_field_elements(&[1]).unwrap());
+        assert_eq!(vec![length(2), one], crate::to_field_elements(&[0, 1]).unwrap());
+        assert_ne!(HashingAlgorithm::Poseidon.hash(&[1], 32), HashingAlgorithm::Poseidon.hash(&[0, 1], 32));
+        // Chunks that aren't below the modulus are rejected rather than reduced
+        let mut below = crate::BN254_MODULUS;
+        below[31] -= 1;
+        assert!(crate::to_field_elements(&below).is_ok());
+        assert!(crate::to_field_elements(&crate::BN254_MODULUS).is_err());
+        assert!(crate::to_field_elements(&[[1u8; 32], [0xff; 32]].concat()).is_err());
+        // Inputs of more than 12 elements are absorbed in turn
+        let long: Vec<u8> = (0..20 * 32).map(|i| (i % 32) as u8).collect();
+        assert_eq!(32, HashingAlgorithm::Poseidon.hash(&long, 32).len());
+        let mut merkle_tree = MerkleTree::new(HashingAlgorithm::Poseidon, 32);
+        let leaves: Vec<Vec<u8>> = (0..5u64).map(|i| i.to_be_bytes().to_vec()).collect();
+        merkle_tree.add_leaves(&leaves).unwrap();
+        merkle_tree.merklize().unwrap();
+        let root = merkle_tree.get_merkle_root().unwrap();
+        // Leaves are double hashed like with every other algorithm
+        let l = merkle_tree.get_leaf_hash(0).unwrap();
+        assert_eq!(HashingAlgorithm::Poseidon.double_hash(&leaves[0], 32), l);
+        for (i, leaf) in leaves.iter().enumerate() {
+            assert_eq!(root, merkle_tree.merkle_proof_index(i).unwrap().merklize(leaf).unwrap());
+        }
+    }
+
+    #[test]
+    fn merkle_tree_set_leaf_test() {
+        let build = |leaves: &[Vec<u8>]| {
+            let mut merkle_tree = MerkleTree::new(HashingAlgorithm::Keccak, 20).with_pairing_mode(PairingMode::Sorted);
+            leaves.iter().for_each(|leaf| merkle_tree.add_leaf(leaf));
+            merkle_tree.merklize().unwrap();
+            merkle_tree
+        };
+        for len in [1, 2, 3, 37] {
+            let mut leaves: Vec<Vec<u8>> = (0..len).map(|n: u32| n.to_le_bytes().to_vec()).collect();
+            let mut merkle_tree = build(&leaves);
+            // The last leaf of an odd level is paired with itself
+            for i in [0, len as usize - 1, len as usize / 2] {
+                leaves[i] = vec![0xff, i as u8];
+                merkle_tree.set_leaf(i, &leaves[i]).unwrap();
+                let rebuilt = build(&leaves);
+                assert_eq!(rebuilt.get_merkle_root().unwrap(), merkle_tree.get_merkle_root().unwrap());
+                for j in 0..leaves.len() {
+                    assert_eq!(rebuilt.merkle_proof_index(j).unwrap().get_pairing_hashes(), merkle_tree.merkle_proof_index(j).unwrap().get_pairing_hashes());
+                }
+            }
+            assert!(merkle_tree.set_leaf(len as usize, &[0]).is_err());
+            assert!(merkle_tree.set_hash(0, vec![0u8; 32]).is_err());
+        }
+
+        let mut leaves: Vec<Vec<u8>> = (0..100u32).map(|n| n.to_le_bytes().to_vec()).collect();
+        let mut merkle_tree = build(&leaves);
+        let updates: Vec<(usize, Vec<u8>)> = [3, 4, 50, 99, 3].iter().map(|i| (*i, vec![0xee, *i as u8])).collect();
+        updates.iter().for_each(|(i, leaf)| leaves[*i] = leaf.clone());
+        merkle_tree.set_leaves(&updates).unwrap();
+        assert_eq!(build(&leaves).get_merkle_root().unwrap(), merkle_tree.get_merkle_root().unwrap());
+
+        // An update that makes an identical adjacent pair is rejected and leaves the tree as it was
+        let root = merkle_tree.get_merkle_root().unwrap();
+        let proofs: Vec<Vec<u8>> = (0..100).map(|i| merkle_tree.merkle_proof_index(i).unwrap().get_pairing_hashes()).collect();
+        let leaf = leaves[0].clone();
+        assert!(merkle_tree.set_leaf(1, &leaf).is_err());
+        assert!(merkle_tree.set_leaves(&[(98, vec![0xaa]), (1, leaf)]).is_err());
+        assert_eq!(root, merkle_tree.get_merkle_root().unwrap());
+        for (i, proof) in proofs.iter().enumerate() {
+            assert_eq!(*proof, merkle_tree.merkle_proof_index(i).unwrap().get_pairing_hashes());
+        }
+        merkle_tree.validate().unwrap();
+        // Identical branches are caught too: leaves 0-1 and 2-3 hash to the same parent
+        assert!(merkle_tree.set_leaves(&[(2, leaves[0].clone()), (3, leaves[1].clone())]).is_err());
+        assert_eq!(root, merkle_tree.get_merkle_root().unwrap());
+        merkle_tree.validate().unwrap();
+
+        // Leaves added since the last merklize need a full rebuild
+        leaves.push(vec![1]);
+        merkle_tree.add_leaf(&leaves[100]);
+        leaves[0] = vec![2];
+        merkle_tree.set_leaf(0, &leaves[0]).unwrap();
+        merkle_tree.merklize().unwrap();
+        assert_eq!(build(&leaves).get_merkle_root().unwrap(), merkle_tree.get_merkle_root().unwrap());
+    }
+}
@@ -0,0 +1,427 @@
+use crate::{DomainSeparation, HashingAlgorithm, MerkleError, MerkleHasher, MerkleProof, PairingMode, Result};
+
+// A MerkleTree laid out in a fixed-size byte buffer such as account data, so that programs can
+// append and replace leaves without deserializing the whole tree. It produces the same roots and
+// proofs as MerkleTree over the same leaves.
+//
+// Layout:
+//   0       is_initialized (u8)
+//   1       algorithm (u8)
+//   2       hash_size (u8)
+//   3       depth (u8)
+//   4       pairing_mode (u8)
+//   5       domain_separation (u8)
+//   6..8    padding
+//   8..16   leaf count (u64, little endian)
+//   16..48  root, untruncated, or the leaf hash followed by zeroes while there is a single leaf
+//   48..    nodes, level by level from the leaves up. Level l holds 2^(depth - l) nodes of
+//           hash_size bytes.
+
+pub const MERKLE_TREE_ACCOUNT_HEADER_SIZE: usize = 48;
+// Keeps node offsets within a u32 on wasm
+pub const MERKLE_TREE_ACCOUNT_MAX_DEPTH: u8 = 24;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MerkleTreeAccountHeader {
+    algorithm: HashingAlgorithm,
+    hash_size: u8,
+    depth: u8,
+    pairing_mode: PairingMode,
+    domain_separation: DomainSeparation
+}
+
+impl MerkleTreeAccountHeader {
+    // Returns InvalidTreeSize for a depth above MERKLE_TREE_ACCOUNT_MAX_DEPTH, and
+    // UnsupportedHashingAlgorithm for an algorithm that isn't available on this target
+    pub fn new(algorithm: HashingAlgorithm, hash_size: u8, depth: u8) -> Result<Self> {
+        if depth > MERKLE_TREE_ACCOUNT_MAX_DEPTH {
+            return Err(MerkleError::InvalidTreeSize.into())
+        }
+        if !algorithm.is_supported() {
+            return Err(MerkleError::UnsupportedHashingAlgorithm.into())
+        }
+        let hash_size = algorithm.normalize_hash_size(hash_size);
+        Ok(Self {
+            algorithm,
+            hash_size,
+            depth,
+            pairing_mode: PairingMode::Indexed,
+            domain_separation: DomainSeparation::Untagged
+        })
+    }
+
+    pub fn with_pairing_mode(mut self, pairing_mode: PairingMode) -> Self {
+        self.pairing_mode = pairing_mode;
+        self
+    }
+
+    pub fn with_domain_separation(mut self, domain_separation: DomainSeparation) -> Self {
+        self.domain_separation = domain_separation;
+        self
+    }
+
+    pub fn get_algorithm(&self) -> HashingAlgorithm {
+        self.algorithm.clone()
+    }
+
+    pub fn get_hash_size(&self) -> u8 {
+        self.hash_size
+    }
+
+    pub fn get_depth(&self) -> u8 {
+        self.depth
+    }
+
+    pub fn get_pairing_mode(&self) -> PairingMode {
+        self.pairing_mode.clone()
+    }
+
+    pub fn get_domain_separation(&self) -> DomainSeparation {
+        self.domain_separation.clone()
+    }
+
+    // Size in bytes of an account holding up to 2^depth leaves, including this header
+    pub fn get_account_size(&self) -> usize {
+        MERKLE_TREE_ACCOUNT_HEADER_SIZE + ((1usize << (self.depth + 1)) - 1) * self.hash_size as usize
+    }
+
+    pub fn get_capacity(&self) -> u64 {
+        1 << self.depth
+    }
+
+    fn to_bytes(&self) -> [u8;8] {
+        [1, self.algorithm.clone().into(), self.hash_size, self.depth, self.pairing_mode.clone().into(), self.domain_separation.clone().into(), 0, 0]
+    }
+
+    fn from_bytes(data: &[u8]) -> Result<Self> {
+        if data.len() < MERKLE_TREE_ACCOUNT_HEADER_SIZE {
+            return Err(MerkleError::InvalidTreeSize.into())
+        }
+        if data[0] != 1 {
+            return Err(MerkleError::TreeNotInitialized.into())
+        }
+        Ok(Self::new(HashingAlgorithm::try_from(data[1])?, data[2], data[3])?
+            .with_pairing_mode(PairingMode::from(data[4]))
+            .with_domain_separation(DomainSeparation::from(data[5])))
+    }
+}
+
+pub struct MerkleTreeAccount<'a> {
+    header: MerkleTreeAccountHeader,
+    data: &'a mut [u8]
+}
+
+impl<'a> MerkleTreeAccount<'a> {
+    // Write an empty tree into zeroed account data
+    pub fn initialize(data: &'a mut [u8], header: MerkleTreeAccountHeader) -> Result<Self> {
+        if data.len() < header.get_account_size() {
+            return Err(MerkleError::InvalidTreeSize.into())
+        }
+        if data[0] != 0 {
+            return Err(MerkleError::TreeAlreadyInitialized.into())
+        }
+        data[..8].copy_from_slice(&header.to_bytes());
+        Ok(Self { header, data })
+    }
+
+    // Load a tree previously written with initialize
+    pub fn from_account_data(data: &'a mut [u8]) -> Result<Self> {
+        let header = MerkleTreeAccountHeader::from_bytes(data)?;
+        if data.len() < header.get_account_size() {
+            return Err(MerkleError::InvalidTreeSize.into())
+        }
+        Ok(Self { header, data })
+    }
+
+    pub fn get_header(&self) -> &MerkleTreeAccountHeader {
+        &self.header
+    }
+
+    pub fn get_leaf_count(&self) -> u64 {
+        u64::from_le_bytes(self.data[8..16].try_into().unwrap())
+    }
+
+    fn set_leaf_count(&mut self, count: u64) {
+        self.data[8..16].copy_from_slice(&count.to_le_bytes());
+    }
+
+    pub fn get_merkle_root(&self) -> Result<Vec<u8>> {
+        match self.get_leaf_count() {
+            0 => Err(MerkleError::TreeEmpty.into()),
+            1 => Ok(self.data[16..16 + self.header.hash_size as usize].to_vec()),
+            _ => Ok(self.data[16..16 + self.header.algorithm.output_size()].to_vec())
+        }
+    }
+
+    // Offset of node i of a level in the account data
+    fn node_offset(&self, level: u8, i: u64) -> usize {
+        let depth = self.header.depth;
+        let level_start = (1usize << (depth + 1)) - (1usize << (depth + 1 - level));
+        MERKLE_TREE_ACCOUNT_HEADER_SIZE + (level_start + i as usize) * self.header.hash_size as usize
+    }
+
+    fn node(&self, level: u8, i: u64) -> &[u8] {
+        let offset = self.node_offset(level, i);
+        &self.data[offset..offset + self.header.hash_size as usize]
+    }
+
+    fn set_node(&mut self, level: u8, i: u64, hash: &[u8]) {
+        let offset = self.node_offset(level, i);
+        let size = self.header.hash_size as usize;
+        self.data[offset..offset + size].copy_from_slice(&hash[..size]);
+    }
+
+    pub fn get_leaf_hash(&self, i: u64) -> Result<Vec<u8>> {
+        if i >= self.get_leaf_count() {
+            return Err(MerkleError::LeafOutOfRange.into())
+        }
+        Ok(self.node(0, i).to_vec())
+    }
+
+    // Double hash a leaf, tagging it first if domain separation is enabled
+    pub fn leaf_hash(&self, leaf: &[u8]) -> Vec<u8> {
+        self.header.algorithm.double_hash_truncated(&self.header.domain_separation.leaf(leaf), self.header.hash_size as usize)
+    }
+
+    // Height of a tree of count leaves, as MerkleTree builds it
+    fn height(count: u64) -> u8 {
+        (u64::BITS - (count - 1).leading_zeros()) as u8
+    }
+
+    // Recompute the ancestors of leaf i and the root. As in MerkleTree, the last node of an odd
+    // level is paired with itself and only the root is left untruncated.
+    fn update_path(&mut self, i: u64) {
+        let count = self.get_leaf_count();
+        let height = Self::height(count);
+        let mut index = i;
+        for level in 0..height {
+            let width = count.div_ceil(1 << level);
+            let (left, right) = match index % 2 == 0 {
+                true => (index, match index + 1 < width {
+                    true => index + 1,
+                    false => index
+                }),
+                false => (index - 1, index)
+            };
+            let (l, r) = self.header.pairing_mode.ordered(self.node(level, left), self.node(level, right));
+            let m = self.header.domain_separation.node(l, r);
+            let size = match level + 1 == height {
+                true => 0,
+                false => self.header.hash_size as usize
+            };
+            let parent = self.header.algorithm.hash_truncated(&m, size);
+            index /= 2;
+            self.set_node(level + 1, index, &parent);
+            if level + 1 == height {
+                self.data[16..16 + parent.len()].copy_from_slice(&parent);
+            }
+        }
+        if height == 0 {
+            let size = self.header.hash_size as usize;
+            let offset = self.node_offset(0, 0);
+            self.data.copy_within(offset..offset + size, 16);
+            self.data[16 + size..48].fill(0);
+        }
+    }
+
+    // Hash and append a leaf
+    pub fn append(&mut self, leaf: &[u8]) -> Result<()> {
+        self.append_hash(&self.leaf_hash(leaf))
+    }
+
+    // Append a leaf hash of hash_size bytes
+    pub fn append_hash(&mut self, hash: &[u8]) -> Result<()> {
+        if hash.len() != self.header.hash_size as usize {
+            return Err(MerkleError::InvalidHashSize.into())
+        }
+        let count = self.get_leaf_count();
+        if count >= self.header.get_capacity() {
+            return Err(MerkleError::TreeFull.into())
+        }
+        self.set_node(0, count, hash);
+        self.set_leaf_count(count + 1);
+        self.update_path(count);
+        Ok(())
+    }
+
+    // Replace a leaf, given its current value and its MerkleProof against the current root
+    pub fn replace_leaf(&mut self, proof: &MerkleProof, old_leaf: &[u8], new_leaf: &[u8]) -> Result<()> {
+        self.replace_hash(proof, &self.leaf_hash(old_leaf), &self.leaf_hash(new_leaf))
+    }
+
+    // Replace a leaf hash, given its current value and its MerkleProof against the current root.
+    // The proof must match the tree's hashing params and index the leaf it proves, so a sorted
+    // proof can't be replayed against another index. It is checked against the stored nodes
+    // rather than with merklize_hash_strict, so that leaves append accepts, like identical
+    // neighbours, can also be replaced.
+    pub fn replace_hash(&mut self, proof: &MerkleProof, old_hash: &[u8], new_hash: &[u8]) -> Result<()> {
+        if new_hash.len() != self.header.hash_size as usize {
+            return Err(MerkleError::InvalidHashSize.into())
+        }
+        if proof.get_algorithm() != self.header.algorithm
+            || proof.get_hash_size() != self.header.hash_size
+            || proof.get_pairing_mode() != self.header.pairing_mode
+            || proof.get_domain_separation() != self.header.domain_separation {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        let index = proof.get_index() as u64;
+        if self.get_leaf_hash(index)? != old_hash
+            || proof.get_pairing_hashes() != self.merkle_proof_index(index)?.get_pairing_hashes() {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        self.set_node(0, index, new_hash);
+        self.update_path(index);
+        Ok(())
+    }
+
+    // The MerkleProof of leaf i, as MerkleTree::merkle_proof_index would produce it
+    pub fn merkle_proof_index(&self, i: u64) -> Result<MerkleProof> {
+        let count = self.get_leaf_count();
+        if i >= count {
+            return Err(MerkleError::LeafOutOfRange.into())
+        }
+        let mut hashes = vec![];
+        let mut index = i;
+        for level in 0..Self::height(count) {
+            let width = count.div_ceil(1 << level);
+            let sibling = match index % 2 == 0 {
+                true => u64::min(index + 1, width - 1),
+                false => index - 1
+            };
+            hashes.extend_from_slice(self.node(level, sibling));
+            index /= 2;
+        }
+        Ok(MerkleProof::new(self.header.algorithm.clone(), self.header.hash_size, i as u32, hashes)
+            .with_pairing_mode(self.header.pairing_mode.clone())
+            .with_domain_separation(self.header.domain_separation.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DomainSeparation, HashingAlgorithm, MerkleTree, PairingMode};
+
+    use super::{MerkleTreeAccount, MerkleTreeAccountHeader, MERKLE_TREE_ACCOUNT_HEADER_SIZE, MERKLE_TREE_ACCOUNT_MAX_DEPTH};
+
+    // Hashing params that tests compare against MerkleTree with
+    const CONFIGS: [(HashingAlgorithm, u8, PairingMode, DomainSeparation); 3] = [
+        (HashingAlgorithm::Sha256d, 32, PairingMode::Indexed, DomainSeparation::Untagged),
+        (HashingAlgorithm::Keccak, 20, PairingMode::Sorted, DomainSeparation::Untagged),
+        (HashingAlgorithm::Sha256, 16, PairingMode::Indexed, DomainSeparation::Tagged)
+    ];
+
+    // A merklized MerkleTree of the given leaves
+    fn tree(leaves: &[Vec<u8>], algorithm: HashingAlgorithm, hash_size: u8, pairing_mode: PairingMode, domain_separation: DomainSeparation) -> MerkleTree {
+        let mut merkle_tree = MerkleTree::new(algorithm, hash_size)
+            .with_pairing_mode(pairing_mode)
+            .with_domain_separation(domain_separation);
+        leaves.iter().for_each(|leaf| merkle_tree.add_leaf(leaf));
+        merkle_tree.merklize().unwrap();
+        merkle_tree
+    }
+
+    #[test]
+    fn merkle_tree_account_append_test() {
+        for (algorithm, hash_size, pairing_mode, domain_separation) in CONFIGS {
+            let header = MerkleTreeAccountHeader::new(algorithm.clone(), hash_size, 4).unwrap()
+                .with_pairing_mode(pairing_mode.clone())
+                .with_domain_separation(domain_separation.clone());
+            assert_eq!(MERKLE_TREE_ACCOUNT_HEADER_SIZE + 31 * hash_size as usize, header.get_account_size());
+            let mut data = vec![0u8; header.get_account_size()];
+            let mut account = MerkleTreeAccount::initialize(&mut data, header.clone()).unwrap();
+            assert!(account.get_merkle_root().is_err());
+            let mut merkle_tree = MerkleTree::new(algorithm, hash_size)
+                .with_pairing_mode(pairing_mode)
+                .with_domain_separation(domain_separation);
+            for n in 0..16u32 {
+                account.append(&n.to_le_bytes()).unwrap();
+                merkle_tree.add_leaf(&n.to_le_bytes());
+                merkle_tree.merklize().unwrap();
+                assert_eq!(merkle_tree.get_merkle_root().unwrap(), account.get_merkle_root().unwrap());
+                for i in 0..=n as usize {
+                    assert_eq!(merkle_tree.merkle_proof_index(i).unwrap().get_pairing_hashes(), account.merkle_proof_index(i as u64).unwrap().get_pairing_hashes());
+                }
+            }
+            assert!(account.append(&[0u8]).is_err());
+            // The tree survives a reload from its account data
+            let root = account.get_merkle_root().unwrap();
+            let account = MerkleTreeAccount::from_account_data(&mut data).unwrap();
+            assert_eq!(&header, account.get_header());
+            assert_eq!(root, account.get_merkle_root().unwrap());
+            assert_eq!(16, account.get_leaf_count());
+        }
+    }
+
+    #[test]
+    fn merkle_tree_account_replace_test() {
+        let header = MerkleTreeAccountHeader::new(HashingAlgorithm::Keccak, 20, 3).unwrap();
+        let mut data = vec![0u8; header.get_account_size()];
+        let mut account = MerkleTreeAccount::initialize(&mut data, header.clone()).unwrap();
+        let mut leaves: Vec<Vec<u8>> = (0..6u8).map(|n| vec![n]).collect();
+        for leaf in leaves.iter() {
+            account.append(leaf).unwrap();
+        }
+        for i in [5, 0, 3] {
+            let proof = account.merkle_proof_index(i as u64).unwrap();
+            let neighbour_proof = account.merkle_proof_index(i as u64 ^ 1).unwrap();
+            let new_leaf = vec![i as u8 + 100];
+            // The current leaf must be supplied
+            assert!(account.replace_leaf(&proof, &new_leaf, &new_leaf).is_err());
+            account.replace_leaf(&proof, &leaves[i], &new_leaf).unwrap();
+            leaves[i] = new_leaf;
+            let merkle_tree = tree(&leaves, HashingAlgorithm::Keccak, 20, PairingMode::Indexed, DomainSeparation::Untagged);
+            assert_eq!(merkle_tree.get_merkle_root().unwrap(), account.get_merkle_root().unwrap());
+            // A proof taken before the update no longer verifies against the new root
+            assert!(account.replace_leaf(&neighbour_proof, &leaves[i ^ 1], &[0u8]).is_err());
+        }
+        // A single leaf tree's root is its leaf hash
+        let mut data = vec![0u8; header.get_account_size()];
+        let mut account = MerkleTreeAccount::initialize(&mut data, header).unwrap();
+        account.append(&[1u8]).unwrap();
+        let proof = account.merkle_proof_index(0).unwrap();
+        account.replace_leaf(&proof, &[1u8], &[2u8]).unwrap();
+        assert_eq!(account.leaf_hash(&[2u8]), account.get_merkle_root().unwrap());
+        assert!(MerkleTreeAccount::initialize(&mut data, MerkleTreeAccountHeader::new(HashingAlgorithm::Keccak, 20, 3).unwrap()).is_err());
+    }
+
+    #[test]
+    fn merkle_tree_account_identical_leaves_test() {
+        // Identical neighbours can be appended, and replaced again
+        let header = MerkleTreeAccountHeader::new(HashingAlgorithm::Keccak, 20, 2).unwrap();
+        let mut data = vec![0u8; header.get_account_size()];
+        let mut account = MerkleTreeAccount::initialize(&mut data, header).unwrap();
+        let mut leaves: Vec<Vec<u8>> = [100u8, 100, 7, 9].iter().map(|n| vec![*n]).collect();
+        for leaf in leaves.iter() {
+            account.append(leaf).unwrap();
+        }
+        let neighbour_proof = account.merkle_proof_index(0).unwrap();
+        let proof = account.merkle_proof_index(1).unwrap();
+        account.replace_leaf(&proof, &[100u8], &[8u8]).unwrap();
+        leaves[1] = vec![8];
+        let merkle_tree = tree(&leaves, HashingAlgorithm::Keccak, 20, PairingMode::Indexed, DomainSeparation::Untagged);
+        assert_eq!(merkle_tree.get_merkle_root().unwrap(), account.get_merkle_root().unwrap());
+        assert_eq!(account.leaf_hash(&[100u8]), account.get_leaf_hash(0).unwrap());
+        // The neighbour still holds its leaf, but its old proof is stale
+        assert!(account.replace_leaf(&neighbour_proof, &[100u8], &[1u8]).is_err());
+        account.replace_leaf(&account.merkle_proof_index(0).unwrap(), &[100u8], &[8u8]).unwrap();
+    }
+
+    #[test]
+    fn merkle_tree_account_depth_test() {
+        let header = MerkleTreeAccountHeader::new(HashingAlgorithm::Keccak, 20, MERKLE_TREE_ACCOUNT_MAX_DEPTH).unwrap();
+        assert_eq!(1 << MERKLE_TREE_ACCOUNT_MAX_DEPTH, header.get_capacity());
+        // Deeper trees would overflow their size on wasm, and at depth 63 everywhere
+        for depth in [MERKLE_TREE_ACCOUNT_MAX_DEPTH + 1, 63, 64, u8::MAX] {
+            assert!(MerkleTreeAccountHeader::new(HashingAlgorithm::Keccak, 20, depth).is_err());
+        }
+        // A header corrupted to a larger depth is rejected on load
+        let mut data = vec![0u8; header.get_account_size()];
+        MerkleTreeAccount::initialize(&mut data, header).unwrap();
+        data[3] = 64;
+        assert!(MerkleTreeAccount::from_account_data(&mut data).is_err());
+        // So is an unknown algorithm
+        data[3] = MERKLE_TREE_ACCOUNT_MAX_DEPTH;
+        data[1] = 7;
+        assert!(MerkleTreeAccount::from_account_data(&mut data).is_err());
+    }
+}
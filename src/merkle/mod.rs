@@ -10,6 +10,66 @@ pub use errors::*;
 pub mod merkle_proof;
 pub use merkle_proof::*;
 
+pub mod multi_proof;
+pub use multi_proof::*;
+
 pub mod hashing_algorithm;
 pub use hashing_algorithm::*;
 
+pub mod merkle_hasher;
+pub use merkle_hasher::*;
+
+pub mod pairing_mode;
+pub use pairing_mode::*;
+
+pub mod domain_separation;
+pub use domain_separation::*;
+
+pub mod sparse_merkle_tree;
+pub use sparse_merkle_tree::*;
+
+pub mod sparse_merkle_tree_empty_hashes;
+pub use sparse_merkle_tree_empty_hashes::*;
+
+pub mod incremental_merkle_tree;
+pub use incremental_merkle_tree::*;
+
+pub mod concurrent_merkle_tree;
+pub use concurrent_merkle_tree::*;
+
+pub mod merkle_mountain_range;
+pub use merkle_mountain_range::*;
+
+#[cfg(not(target_os = "solana"))]
+pub mod standard_merkle_tree;
+#[cfg(not(target_os = "solana"))]
+pub use standard_merkle_tree::*;
+
+pub mod transparency_log;
+pub use transparency_log::*;
+
+pub mod merkle_sum_tree;
+pub use merkle_sum_tree::*;
+
+pub mod indexed_merkle_tree;
+pub use indexed_merkle_tree::*;
+
+pub mod taproot;
+pub use taproot::*;
+
+pub mod bitcoin_spv;
+pub use bitcoin_spv::*;
+
+pub mod bitcoin_block;
+pub use bitcoin_block::*;
+
+pub mod solana_merkle_tree;
+pub use solana_merkle_tree::*;
+
+pub mod bubblegum;
+pub use bubblegum::*;
+
+pub mod merkle_distributor;
+pub use merkle_distributor::*;
+pub mod merkle_tree_account;
+pub use merkle_tree_account::*;
@@ -0,0 +1,167 @@
+#[cfg(not(target_arch = "wasm32"))]
+use anchor_lang::prelude::*;
+use crate::{MerkleError, Result};
+use super::{DomainSeparation, HashingAlgorithm, MerkleHasher, PairingMode};
+
+// A proof of several leaves of a MerkleTree at once. Pairing hashes that can be computed from the
+// proven leaves themselves are left out, so siblings shared between leaves are only sent once.
+//
+// Leaves must be merklized in ascending index order, which is the order of the proof's indices.
+#[derive(Debug, Clone)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub struct MultiProof<H: MerkleHasher = HashingAlgorithm> {
+    algorithm: H,
+    hash_size: u8,
+    leaf_count: u32,
+    indices: Vec<u32>,
+    hashes: Vec<u8>,
+    pairing_mode: PairingMode,
+    domain_separation: DomainSeparation
+}
+
+impl<H: MerkleHasher> MultiProof<H> {
+    pub fn new(algorithm: H, hash_size: u8, leaf_count: u32, indices: Vec<u32>, hashes: Vec<u8>) -> Self {
+        let hash_size = algorithm.normalize_hash_size(hash_size);
+        Self {
+            algorithm,
+            hash_size,
+            leaf_count,
+            indices,
+            hashes,
+            pairing_mode: PairingMode::Indexed,
+            domain_separation: DomainSeparation::Untagged
+        }
+    }
+
+    // Set how child hashes are ordered when pairing them
+    pub fn with_pairing_mode(mut self, pairing_mode: PairingMode) -> Self {
+        self.pairing_mode = pairing_mode;
+        self
+    }
+
+    // Set whether leaves and branches are tagged before hashing. Must match the tree.
+    pub fn with_domain_separation(mut self, domain_separation: DomainSeparation) -> Self {
+        self.domain_separation = domain_separation;
+        self
+    }
+
+    // Double hash with defined hashing algorithm and truncate to defined length
+    pub fn double_hash(&self, m: &[u8]) -> Vec<u8> {
+        self.algorithm.double_hash_truncated(m, self.hash_size as usize)
+    }
+
+    // Double hash a leaf, tagging it first if domain separation is enabled
+    pub fn leaf_hash(&self, leaf: &[u8]) -> Vec<u8> {
+        self.double_hash(&self.domain_separation.leaf(leaf))
+    }
+
+    // Merklize from leaves, in ascending index order
+    pub fn merklize(&self, leaves: &[Vec<u8>]) -> Result<Vec<u8>> {
+        let hashes: Vec<Vec<u8>> = leaves.iter().map(|leaf| self.leaf_hash(leaf)).collect();
+        self.merklize_hashes_unchecked(&hashes, false)
+    }
+
+    // Merklize from leaf hashes, in ascending index order
+    pub fn merklize_hashes(&self, hashes: &[Vec<u8>]) -> Result<Vec<u8>> {
+        self.check_hash_sizes(hashes)?;
+        self.merklize_hashes_unchecked(hashes, false)
+    }
+
+    // Merklize from leaves, in ascending index order, rejecting proofs through an identical
+    // adjacent pair (CVE-2012-2459). Only the last node of an odd level may be paired with itself.
+    pub fn merklize_strict(&self, leaves: &[Vec<u8>]) -> Result<Vec<u8>> {
+        let hashes: Vec<Vec<u8>> = leaves.iter().map(|leaf| self.leaf_hash(leaf)).collect();
+        self.merklize_hashes_unchecked(&hashes, true)
+    }
+
+    // Merklize from leaf hashes, in ascending index order, rejecting identical adjacent pairs
+    pub fn merklize_hashes_strict(&self, hashes: &[Vec<u8>]) -> Result<Vec<u8>> {
+        self.check_hash_sizes(hashes)?;
+        self.merklize_hashes_unchecked(hashes, true)
+    }
+
+    fn check_hash_sizes(&self, hashes: &[Vec<u8>]) -> Result<()> {
+        match hashes.iter().any(|h| h.len() != self.hash_size as usize) {
+            true => Err(MerkleError::InvalidHashSize.into()),
+            false => Ok(())
+        }
+    }
+
+    // Merklize from hashes. NOTE: There are no length checks being performed on the hashes here.
+    fn merklize_hashes_unchecked(&self, hashes: &[Vec<u8>], strict: bool) -> Result<Vec<u8>> {
+        let size = self.hash_size as usize;
+        if self.hashes.len() % size != 0 {
+            return Err(MerkleError::InvalidHashSize.into())
+        }
+        // Indices must be unique, ascending, within the tree and match the leaves given
+        if hashes.is_empty()
+            || hashes.len() != self.indices.len()
+            || self.indices.windows(2).any(|w| w[0] >= w[1])
+            || self.indices.iter().any(|i| *i >= self.leaf_count) {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        let mut known: Vec<(u32, Vec<u8>)> = self.indices.iter().cloned().zip(hashes.iter().cloned()).collect();
+        // A single leaf is its own root
+        if self.leaf_count == 1 {
+            return Ok(known.remove(0).1)
+        }
+        let mut proof = self.hashes.chunks(size);
+        let mut width = self.leaf_count;
+        while width > 1 {
+            // Only the final pairing produces an untruncated hash
+            let s = match width <= 2 {
+                true => 0,
+                false => size
+            };
+            let mut next = vec![];
+            let mut known_iter = known.iter().peekable();
+            while let Some((n, h)) = known_iter.next() {
+                let sibling = n ^ 1;
+                let pair = match sibling >= width {
+                    // The last node of an odd level is paired with itself
+                    true => h.clone(),
+                    false => match known_iter.peek() {
+                        Some((m, _)) if *m == sibling => known_iter.next().ok_or(MerkleError::InvalidProof)?.1.clone(),
+                        _ => proof.next().ok_or(MerkleError::InvalidProof)?.to_vec()
+                    }
+                };
+                // Only the last node of an odd level may be paired with an identical hash
+                if strict && sibling < width && pair == *h {
+                    return Err(MerkleError::MutatedTree.into())
+                }
+                let (left, right) = match n % 2 == 0 {
+                    true => self.pairing_mode.ordered(h, &pair),
+                    false => self.pairing_mode.ordered(&pair, h)
+                };
+                next.push((n / 2, self.algorithm.hash_truncated(&self.domain_separation.node(left, right), s)));
+            }
+            known = next;
+            width = width.div_ceil(2);
+        }
+        // Every pairing hash must have been used
+        if proof.next().is_some() {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        Ok(known.remove(0).1)
+    }
+
+    pub fn get_leaf_count(&self) -> u32 {
+        self.leaf_count
+    }
+
+    pub fn get_indices(&self) -> Vec<u32> {
+        self.indices.clone()
+    }
+
+    pub fn get_pairing_hashes(&self) -> Vec<u8> {
+        self.hashes.clone()
+    }
+
+    pub fn get_pairing_mode(&self) -> PairingMode {
+        self.pairing_mode.clone()
+    }
+
+    pub fn get_domain_separation(&self) -> DomainSeparation {
+        self.domain_separation.clone()
+    }
+}
@@ -0,0 +1,45 @@
+#[cfg(not(target_arch = "wasm32"))]
+use anchor_lang::prelude::*;
+
+// How two child hashes are ordered before being hashed into their parent.
+//
+// Indexed pairing keeps children in tree order, so proofs need the leaf index to know which side
+// each pairing hash goes on. This is the Bitcoin-compatible default.
+//
+// Sorted pairing places the lesser of the two hashes first, making node hashing commutative so
+// that proofs need no index. With Keccak this matches OpenZeppelin's MerkleProof.verify.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub enum PairingMode {
+    #[default]
+    Indexed = 0,
+    Sorted = 1
+}
+
+impl From<PairingMode> for u8 {
+    fn from(value: PairingMode) -> Self {
+        match value {
+            PairingMode::Indexed => 0,
+            PairingMode::Sorted => 1,
+        }
+    }
+}
+
+impl From<u8> for PairingMode {
+    fn from(value: u8) -> PairingMode {
+        match value {
+            1 => PairingMode::Sorted,
+            _ => PairingMode::Indexed,
+        }
+    }
+}
+
+impl PairingMode {
+    // Order a left and right child for hashing
+    pub fn ordered<'a>(&self, left: &'a [u8], right: &'a [u8]) -> (&'a [u8], &'a [u8]) {
+        match self {
+            PairingMode::Sorted if right < left => (right, left),
+            _ => (left, right)
+        }
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(not(target_os = "solana"))]
+use anyhow::Result;
+#[cfg(target_os = "solana")]
+use anchor_lang::Result;
+use crate::{DomainSeparation, HashingAlgorithm, MerkleError, MerkleHasher, MerkleProof, MerkleTree, PairingMode, DOMAIN_SEPARATION_LEAF_TAG};
+
+// Hash an item as a leaf of solana-merkle-tree: sha256(0x00 || item), hashed only once
+pub fn solana_leaf_hash(item: &[u8]) -> Vec<u8> {
+    HashingAlgorithm::Sha256.hashv(&[&[DOMAIN_SEPARATION_LEAF_TAG], item])
+}
+
+// A Merkle tree compatible with Solana's solana-merkle-tree, which the validator uses for entry
+// and shred roots. Entries, for instance, commit to the Merkle root of their transactions'
+// signatures.
+//
+// Leaves are sha256(0x00 || item) and branches sha256(0x01 || left || right), and the last node
+// of an odd level is paired with itself. This is a sha256 MerkleTree with tagged domain separation,
+// except that leaves are single hashed, so proofs are ordinary MerkleProofs verified from
+// solana_leaf_hash rather than with MerkleProof::merklize.
+//
+// solana-merkle-tree accepts identical adjacent items, so the tree is merklized leniently.
+#[derive(Debug, Clone)]
+pub struct SolanaMerkleTree {
+    tree: MerkleTree
+}
+
+impl Default for SolanaMerkleTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SolanaMerkleTree {
+    pub fn new() -> Self {
+        Self {
+            tree: MerkleTree::new(HashingAlgorithm::Sha256, 32).with_domain_separation(DomainSeparation::Tagged)
+        }
+    }
+
+    pub fn add_leaf(&mut self, item: &[u8]) {
+        self.tree.add_hash_unchecked(solana_leaf_hash(item))
+    }
+
+    pub fn add_leaves<T: AsRef<[u8]>>(&mut self, items: &[T]) {
+        items.iter().for_each(|item| self.add_leaf(item.as_ref()))
+    }
+
+    pub fn merklize(&mut self) -> Result<()> {
+        self.tree.merklize()
+    }
+
+    pub fn get_merkle_root(&self) -> Result<Vec<u8>> {
+        self.tree.get_merkle_root()
+    }
+
+    pub fn get_leaf_hash(&self, i: usize) -> Result<Vec<u8>> {
+        self.tree.get_leaf_hash(i)
+    }
+
+    pub fn merkle_proof_index(&self, i: usize) -> Result<MerkleProof> {
+        self.tree.merkle_proof_index(i)
+    }
+
+    // Verify that an item is included in a tree with the given root. The proof must use the
+    // tree's hashing params.
+    pub fn verify(item: &[u8], proof: &MerkleProof, root: &[u8]) -> Result<()> {
+        if proof.get_algorithm() != HashingAlgorithm::Sha256
+            || proof.get_hash_size() != 32
+            || proof.get_pairing_mode() != PairingMode::Indexed
+            || proof.get_domain_separation() != DomainSeparation::Tagged {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        if proof.merklize_hash(&solana_leaf_hash(item))? != root {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DomainSeparation, HashingAlgorithm, MerkleHasher, MerkleProof, PairingMode};
+    use hex_literal::hex;
+
+    use super::{solana_leaf_hash, SolanaMerkleTree};
+
+    const TEST: &[&[u8]] = &[
+        b"my", b"very", b"eager", b"mother", b"just", b"served", b"us", b"nine", b"pizzas",
+        b"make", b"prime",
+    ];
+
+    // solana-merkle-tree's construction, for comparison
+    fn solana_root(items: &[&[u8]]) -> Vec<u8> {
+        let mut level: Vec<Vec<u8>> = items.iter().map(|item| solana_leaf_hash(item)).collect();
+        while level.len() > 1 {
+            level = level.chunks(2).map(|pair| {
+                HashingAlgorithm::Sha256.hashv(&[&[1], &pair[0], pair.get(1).unwrap_or(&pair[0])])
+            }).collect();
+        }
+        level.remove(0)
+    }
+
+    #[test]
+    fn solana_merkle_tree_test() {
+        let mut tree = SolanaMerkleTree::new();
+        tree.add_leaves(TEST);
+        tree.merklize().unwrap();
+        let root = tree.get_merkle_root().unwrap();
+        // Golden root from solana-merkle-tree
+        assert_eq!(hex!("b40c847546fdceea166f927fc46c5ca33c3638236a36275c1346d3dffb84e1bc").to_vec(), root);
+        for (i, item) in TEST.iter().enumerate() {
+            SolanaMerkleTree::verify(item, &tree.merkle_proof_index(i).unwrap(), &root).unwrap();
+        }
+        assert!(SolanaMerkleTree::verify(b"bad", &tree.merkle_proof_index(0).unwrap(), &root).is_err());
+        assert!(SolanaMerkleTree::verify(b"very", &tree.merkle_proof_index(0).unwrap(), &root).is_err());
+    }
+
+    #[test]
+    fn solana_merkle_tree_sizes_test() {
+        for n in 1..TEST.len() {
+            let mut tree = SolanaMerkleTree::new();
+            tree.add_leaves(&TEST[..n]);
+            tree.merklize().unwrap();
+            let root = tree.get_merkle_root().unwrap();
+            assert_eq!(solana_root(&TEST[..n]), root);
+            for (i, item) in TEST[..n].iter().enumerate() {
+                SolanaMerkleTree::verify(item, &tree.merkle_proof_index(i).unwrap(), &root).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn solana_merkle_tree_identical_items_test() {
+        // solana-merkle-tree happily merklizes repeated items
+        let items: &[&[u8]] = &[b"my", b"my", b"very", b"very", b"eager"];
+        let mut tree = SolanaMerkleTree::new();
+        tree.add_leaves(items);
+        tree.merklize().unwrap();
+        let root = tree.get_merkle_root().unwrap();
+        assert_eq!(solana_root(items), root);
+        for (i, item) in items.iter().enumerate() {
+            SolanaMerkleTree::verify(item, &tree.merkle_proof_index(i).unwrap(), &root).unwrap();
+        }
+    }
+
+    #[test]
+    fn solana_merkle_tree_verify_params_test() {
+        let mut tree = SolanaMerkleTree::new();
+        tree.add_leaves(TEST);
+        tree.merklize().unwrap();
+        let root = tree.get_merkle_root().unwrap();
+        let proof = tree.merkle_proof_index(2).unwrap();
+        SolanaMerkleTree::verify(TEST[2], &proof, &root).unwrap();
+        // The tree's modes survive serialization, so a deserialized proof still verifies
+        let bytes = anchor_lang::AnchorSerialize::try_to_vec(&proof).unwrap();
+        let deserialized: MerkleProof = anchor_lang::AnchorDeserialize::try_from_slice(&bytes).unwrap();
+        SolanaMerkleTree::verify(TEST[2], &deserialized, &root).unwrap();
+        // Proofs with other hashing params are rejected, even where they would merklize the same
+        let hashes = proof.get_pairing_hashes();
+        let proofs = [
+            MerkleProof::new(HashingAlgorithm::Sha256d, 32, 2, hashes.clone()).with_domain_separation(DomainSeparation::Tagged),
+            MerkleProof::new(HashingAlgorithm::Sha256, 20, 2, hashes.clone()).with_domain_separation(DomainSeparation::Tagged),
+            proof.clone().with_pairing_mode(PairingMode::Sorted),
+            proof.clone().with_domain_separation(DomainSeparation::Untagged),
+        ];
+        for proof in proofs.iter() {
+            assert!(SolanaMerkleTree::verify(TEST[2], proof, &root).is_err());
+        }
+    }
+}
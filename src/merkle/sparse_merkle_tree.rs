@@ -0,0 +1,450 @@
+#[cfg(not(target_arch = "wasm32"))]
+use anchor_lang::prelude::*;
+use std::collections::HashMap;
+#[cfg(not(target_os = "solana"))]
+use std::sync::{Mutex, OnceLock};
+use std::sync::Arc;
+use crate::{HashingAlgorithm, MerkleError, Result};
+use super::sparse_merkle_tree_empty_hashes::*;
+
+pub const SPARSE_MERKLE_TREE_DEPTH: usize = 256;
+
+// The hash of an empty subtree at each height, from the empty leaf up to the empty root. Tables for
+// a hash size of 32 are precomputed, and tables for other sizes are computed.
+type ComputedEmptyHashes = Arc<Vec<Vec<u8>>>;
+
+#[derive(Debug, Clone)]
+enum EmptyHashes {
+    Precomputed(&'static [[u8;32]; SPARSE_MERKLE_TREE_DEPTH + 1]),
+    Computed(ComputedEmptyHashes)
+}
+
+impl std::ops::Index<usize> for EmptyHashes {
+    type Output = [u8];
+
+    fn index(&self, height: usize) -> &[u8] {
+        match self {
+            EmptyHashes::Precomputed(t) => &t[height],
+            EmptyHashes::Computed(t) => &t[height]
+        }
+    }
+}
+
+// A 256-level sparse Merkle tree keyed by 32-byte keys. Only non-empty nodes are stored, with
+// empty subtrees resolved from a precomputed table of empty hashes for each height.
+#[derive(Debug, Clone)]
+pub struct SparseMerkleTree {
+    algorithm: HashingAlgorithm,
+    hash_size: u8,
+    empty_hashes: EmptyHashes,
+    nodes: HashMap<(u16, [u8;32]), Vec<u8>>
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub struct SparseMerkleProof {
+    algorithm: HashingAlgorithm,
+    hash_size: u8,
+    key: [u8;32],
+    bitmap: [u8;32],
+    hashes: Vec<u8>
+}
+
+// Keys are read as big-endian 256-bit integers, so bit 0 selects the side at the leaf level and
+// bit 255 selects the side just below the root.
+fn key_bit(key: &[u8;32], height: usize) -> bool {
+    (key[31 - height / 8] >> (height % 8)) & 1 == 1
+}
+
+// Clear the lowest `height` bits of a key to get the path of its ancestor at that height
+fn key_prefix(key: &[u8;32], height: usize) -> [u8;32] {
+    let mut prefix = *key;
+    let bytes = height / 8;
+    prefix[32 - bytes..].fill(0);
+    if bytes < 32 {
+        prefix[31 - bytes] &= 0xff << (height % 8);
+    }
+    prefix
+}
+
+// Path of the sibling of a key's ancestor at the given height
+fn sibling_prefix(key: &[u8;32], height: usize) -> [u8;32] {
+    let mut prefix = key_prefix(key, height);
+    prefix[31 - height / 8] ^= 1 << (height % 8);
+    prefix
+}
+
+// Like MerkleTree, every level is truncated to hash_size except for the root
+fn output_size(hash_size: u8, height: usize) -> usize {
+    match height == SPARSE_MERKLE_TREE_DEPTH - 1 {
+        true => 32,
+        false => hash_size as usize
+    }
+}
+
+// The empty leaf is all zeroes. Each empty branch is the hash of two empty children.
+fn compute_empty_hashes(algorithm: &HashingAlgorithm, hash_size: u8) -> Vec<Vec<u8>> {
+    let mut empty_hashes = vec![vec![0u8; hash_size as usize]];
+    for height in 0..SPARSE_MERKLE_TREE_DEPTH {
+        let e = &empty_hashes[height];
+        let h = algorithm.hash(&[e.clone(), e.clone()].concat(), output_size(hash_size, height));
+        empty_hashes.push(h);
+    }
+    empty_hashes
+}
+
+fn precomputed_empty_hashes(algorithm: &HashingAlgorithm) -> Option<&'static [[u8;32]; SPARSE_MERKLE_TREE_DEPTH + 1]> {
+    match algorithm {
+        HashingAlgorithm::Sha256 => Some(&SPARSE_MERKLE_TREE_EMPTY_HASHES_SHA256),
+        HashingAlgorithm::Sha256d => Some(&SPARSE_MERKLE_TREE_EMPTY_HASHES_SHA256D),
+        HashingAlgorithm::Keccak => Some(&SPARSE_MERKLE_TREE_EMPTY_HASHES_KECCAK),
+        HashingAlgorithm::Keccakd => Some(&SPARSE_MERKLE_TREE_EMPTY_HASHES_KECCAKD),
+        #[cfg(any(not(target_os = "solana"), feature = "blake3-syscall"))]
+        HashingAlgorithm::Blake3 => Some(&SPARSE_MERKLE_TREE_EMPTY_HASHES_BLAKE3),
+        #[cfg(any(not(target_os = "solana"), feature = "blake3-syscall"))]
+        HashingAlgorithm::Blake3d => Some(&SPARSE_MERKLE_TREE_EMPTY_HASHES_BLAKE3D),
+        HashingAlgorithm::Poseidon => Some(&SPARSE_MERKLE_TREE_EMPTY_HASHES_POSEIDON),
+        #[allow(unreachable_patterns)]
+        _ => None
+    }
+}
+
+fn empty_hashes(algorithm: &HashingAlgorithm, hash_size: u8) -> EmptyHashes {
+    match precomputed_empty_hashes(algorithm) {
+        Some(t) if hash_size == 32 => EmptyHashes::Precomputed(t),
+        _ => EmptyHashes::Computed(computed_empty_hashes(algorithm, hash_size))
+    }
+}
+
+// Off-chain, other tables are computed once for each algorithm and hash size and shared by every
+// tree and proof that uses them
+#[cfg(not(target_os = "solana"))]
+fn computed_empty_hashes(algorithm: &HashingAlgorithm, hash_size: u8) -> ComputedEmptyHashes {
+    static TABLES: OnceLock<Mutex<Vec<(HashingAlgorithm, u8, ComputedEmptyHashes)>>> = OnceLock::new();
+    let mut tables = TABLES.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((_, _, t)) = tables.iter().find(|(a, s, _)| *a == *algorithm && *s == hash_size) {
+        return t.clone()
+    }
+    let table = Arc::new(compute_empty_hashes(algorithm, hash_size));
+    tables.push((algorithm.clone(), hash_size, table.clone()));
+    table
+}
+
+// Programs can't keep writable statics, so on Solana other tables are computed once per tree or
+// verification
+#[cfg(target_os = "solana")]
+fn computed_empty_hashes(algorithm: &HashingAlgorithm, hash_size: u8) -> ComputedEmptyHashes {
+    Arc::new(compute_empty_hashes(algorithm, hash_size))
+}
+
+impl SparseMerkleTree {
+    pub fn new(algorithm: HashingAlgorithm, hash_size: u8) -> Self {
+        let mut hash_size = hash_size;
+        if hash_size == 0 || hash_size > 32 {
+            hash_size = 32
+        }
+        Self {
+            empty_hashes: empty_hashes(&algorithm, hash_size),
+            algorithm,
+            hash_size,
+            nodes: HashMap::new()
+        }
+    }
+
+    // Double hash with defined hashing algorithm and truncate to defined length
+    fn double_hash(&self, m: &[u8]) -> Vec<u8> {
+        self.algorithm.double_hash(m, self.hash_size as usize)
+    }
+
+    fn get_node(&self, height: usize, prefix: &[u8;32]) -> &[u8] {
+        self.nodes.get(&(height as u16, *prefix)).map(|h| h.as_slice()).unwrap_or(&self.empty_hashes[height])
+    }
+
+    fn set_node(&mut self, height: usize, prefix: [u8;32], hash: Vec<u8>) {
+        // Keep the tree sparse by never storing empty subtrees
+        match hash[..] == self.empty_hashes[height] {
+            true => self.nodes.remove(&(height as u16, prefix)),
+            false => self.nodes.insert((height as u16, prefix), hash)
+        };
+    }
+
+    // Hash and insert a leaf at a key, replacing any existing leaf
+    pub fn insert(&mut self, key: [u8;32], leaf: &[u8]) {
+        // Double hash to prevent length extension attacks
+        self.insert_hash_unchecked(key, self.double_hash(leaf))
+    }
+
+    // Insert a hash at a key with a length check. Use with unnormalized data
+    pub fn insert_hash(&mut self, key: [u8;32], hash: Vec<u8>) -> Result<()> {
+        if hash.len() != self.hash_size as usize {
+            return Err(MerkleError::InvalidHashSize.into())
+        }
+        self.insert_hash_unchecked(key, hash);
+        Ok(())
+    }
+
+    // Insert a hash at a key without a length check. Inserting the empty leaf removes the key.
+    pub fn insert_hash_unchecked(&mut self, key: [u8;32], hash: Vec<u8>) {
+        let mut node = hash;
+        self.set_node(0, key, node.clone());
+        for height in 0..SPARSE_MERKLE_TREE_DEPTH {
+            let sibling = self.get_node(height, &sibling_prefix(&key, height));
+            node = match node[..] == self.empty_hashes[height] && *sibling == self.empty_hashes[height] {
+                true => self.empty_hashes[height + 1].to_vec(),
+                false => {
+                    let m = match key_bit(&key, height) {
+                        true => [sibling, node.as_slice()].concat(),
+                        false => [node.as_slice(), sibling].concat()
+                    };
+                    self.algorithm.hash(&m, output_size(self.hash_size, height))
+                }
+            };
+            self.set_node(height + 1, key_prefix(&key, height + 1), node.clone());
+        }
+    }
+
+    // Remove the leaf at a key, leaving an empty leaf in its place
+    pub fn remove(&mut self, key: [u8;32]) {
+        self.insert_hash_unchecked(key, self.empty_hashes[0].to_vec())
+    }
+
+    pub fn contains(&self, key: [u8;32]) -> bool {
+        self.nodes.contains_key(&(0, key))
+    }
+
+    pub fn get_leaf_hash(&self, key: [u8;32]) -> Result<Vec<u8>> {
+        match self.nodes.get(&(0, key)) {
+            Some(h) => Ok(h.clone()),
+            None => Err(MerkleError::LeafNotFound.into())
+        }
+    }
+
+    pub fn get_merkle_root(&self) -> Vec<u8> {
+        self.get_node(SPARSE_MERKLE_TREE_DEPTH, &[0u8;32]).to_vec()
+    }
+
+    // Produce a proof for a key. If the key is present this proves inclusion of its leaf, otherwise
+    // it proves that the key holds the empty leaf.
+    pub fn merkle_proof_key(&self, key: [u8;32]) -> SparseMerkleProof {
+        let mut bitmap = [0u8;32];
+        let mut hashes: Vec<Vec<u8>> = vec![];
+        for height in 0..SPARSE_MERKLE_TREE_DEPTH {
+            let sibling = self.get_node(height, &sibling_prefix(&key, height));
+            if *sibling != self.empty_hashes[height] {
+                bitmap[height / 8] |= 1 << (height % 8);
+                hashes.push(sibling.to_vec());
+            }
+        }
+        SparseMerkleProof::new(
+            self.algorithm.clone(),
+            self.hash_size,
+            key,
+            bitmap,
+            hashes.concat()
+        )
+    }
+}
+
+impl SparseMerkleProof {
+    pub fn new(algorithm: HashingAlgorithm, hash_size: u8, key: [u8;32], bitmap: [u8;32], hashes: Vec<u8>) -> Self {
+        let mut hash_size = hash_size;
+        if hash_size == 0 || hash_size > 32 {
+            hash_size = 32
+        }
+        Self {
+            algorithm,
+            hash_size,
+            key,
+            bitmap,
+            hashes
+        }
+    }
+
+    // Hash with defined hashing algorithm and truncate to defined length
+    pub fn hash(&self, m: &[u8]) -> Vec<u8> {
+        self.algorithm.hash(m, self.hash_size as usize)
+    }
+
+    // Double hash with defined hashing algorithm and truncate to defined length
+    pub fn double_hash(&self, m: &[u8]) -> Vec<u8> {
+        self.algorithm.double_hash(m, self.hash_size as usize)
+    }
+
+    // Merklize from a leaf to prove inclusion
+    pub fn merklize(&self, leaf: &[u8]) -> Result<Vec<u8>> {
+        self.merklize_hash_unchecked(&self.double_hash(leaf))
+    }
+
+    // Merklize from a leaf hash to prove inclusion
+    pub fn merklize_hash(&self, hash: &[u8]) -> Result<Vec<u8>> {
+        if hash.len() != self.hash_size as usize {
+            return Err(MerkleError::InvalidHashSize.into())
+        }
+        self.merklize_hash_unchecked(hash)
+    }
+
+    // Merklize from the empty leaf to prove non-membership of the key
+    pub fn merklize_empty(&self) -> Result<Vec<u8>> {
+        self.merklize_hash_unchecked(&vec![0u8; self.hash_size as usize])
+    }
+
+    // Merklize from a hash. NOTE: There are no length checks being performed on the hash here.
+    fn merklize_hash_unchecked(&self, hash: &[u8]) -> Result<Vec<u8>> {
+        let size = self.hash_size as usize;
+        let hash_count = self.bitmap.iter().map(|b| b.count_ones() as usize).sum::<usize>();
+        // The bitmap must account for every pairing hash in the proof
+        if self.hashes.len() != hash_count * size {
+            return Err(MerkleError::InvalidProof.into());
+        }
+        let empty_hashes = empty_hashes(&self.algorithm, self.hash_size);
+        let mut offset = 0;
+        let mut h = hash.to_vec();
+        for height in 0..SPARSE_MERKLE_TREE_DEPTH {
+            let empty = &empty_hashes[height];
+            let has_sibling = (self.bitmap[height / 8] >> (height % 8)) & 1 == 1;
+            let sibling = match has_sibling {
+                true => {
+                    offset += size;
+                    self.hashes[offset - size..offset].to_vec()
+                },
+                false => empty.to_vec()
+            };
+            h = match !has_sibling && h[..] == *empty {
+                // Both children are empty, so the parent is the next empty hash
+                true => empty_hashes[height + 1].to_vec(),
+                false => {
+                    let m = match key_bit(&self.key, height) {
+                        true => [sibling.as_slice(), h.as_slice()].concat(),
+                        false => [h.as_slice(), sibling.as_slice()].concat()
+                    };
+                    self.algorithm.hash(&m, output_size(self.hash_size, height))
+                }
+            };
+        }
+        Ok(h)
+    }
+
+    pub fn get_key(&self) -> [u8;32] {
+        self.key
+    }
+
+    pub fn get_bitmap(&self) -> [u8;32] {
+        self.bitmap
+    }
+
+    pub fn get_pairing_hashes(&self) -> Vec<u8> {
+        self.hashes.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::HashingAlgorithm;
+
+    use super::{compute_empty_hashes, EmptyHashes, SparseMerkleProof, SparseMerkleTree};
+
+    fn key(n: u8) -> [u8;32] {
+        let mut k = [0u8;32];
+        k[0] = n;
+        k[31] = n.wrapping_mul(7);
+        k
+    }
+
+    #[test]
+    fn sparse_merkle_tree_empty_root_test() {
+        let tree = SparseMerkleTree::new(HashingAlgorithm::Sha256, 32);
+        let proof = tree.merkle_proof_key(key(1));
+        assert!(proof.get_pairing_hashes().is_empty());
+        assert_eq!(tree.get_merkle_root(), proof.merklize_empty().unwrap());
+        assert_eq!(32, tree.get_merkle_root().len());
+    }
+
+    #[test]
+    fn sparse_merkle_tree_empty_hashes_test() {
+        // Empty hashes are computed once per algorithm and hash size
+        let a = SparseMerkleTree::new(HashingAlgorithm::Sha256, 20);
+        let b = SparseMerkleTree::new(HashingAlgorithm::Sha256, 20);
+        let c = SparseMerkleTree::new(HashingAlgorithm::Keccak, 20);
+        assert_eq!(a.empty_hashes[0].as_ptr(), b.empty_hashes[0].as_ptr());
+        assert_ne!(a.empty_hashes[0].as_ptr(), c.empty_hashes[0].as_ptr());
+        assert_eq!(b.get_merkle_root(), b.merkle_proof_key(key(3)).merklize_empty().unwrap());
+        // Precomputed tables match the computed ones
+        for algorithm in [
+            HashingAlgorithm::Sha256,
+            HashingAlgorithm::Sha256d,
+            HashingAlgorithm::Keccak,
+            HashingAlgorithm::Keccakd,
+            HashingAlgorithm::Blake3,
+            HashingAlgorithm::Blake3d,
+            HashingAlgorithm::Poseidon
+        ] {
+            let tree = SparseMerkleTree::new(algorithm.clone(), 32);
+            assert!(matches!(tree.empty_hashes, EmptyHashes::Precomputed(_)));
+            for (height, hash) in compute_empty_hashes(&algorithm, 32).iter().enumerate() {
+                assert_eq!(hash[..], tree.empty_hashes[height]);
+            }
+        }
+    }
+
+    #[test]
+    fn sparse_merkle_tree_inclusion_test() {
+        let mut tree = SparseMerkleTree::new(HashingAlgorithm::Keccak, 20);
+        for n in 0..16u8 {
+            tree.insert(key(n), &[n; 8]);
+        }
+        let root = tree.get_merkle_root();
+        assert_eq!(32, root.len());
+        for n in 0..16u8 {
+            let proof = tree.merkle_proof_key(key(n));
+            assert_eq!(root, proof.merklize(&[n; 8]).unwrap());
+            assert_eq!(root, proof.merklize_hash(&tree.get_leaf_hash(key(n)).unwrap()).unwrap());
+            assert_ne!(root, proof.merklize(&[n + 1; 8]).unwrap());
+            assert_ne!(root, proof.merklize_empty().unwrap());
+        }
+    }
+
+    #[test]
+    fn sparse_merkle_tree_non_membership_test() {
+        let mut tree = SparseMerkleTree::new(HashingAlgorithm::Sha256d, 32);
+        for n in 0..8u8 {
+            tree.insert(key(n * 2), &[n; 4]);
+        }
+        let root = tree.get_merkle_root();
+        for n in 0..8u8 {
+            let proof = tree.merkle_proof_key(key(n * 2 + 1));
+            assert!(!tree.contains(key(n * 2 + 1)));
+            assert_eq!(root, proof.merklize_empty().unwrap());
+        }
+    }
+
+    #[test]
+    fn sparse_merkle_tree_remove_test() {
+        let mut tree = SparseMerkleTree::new(HashingAlgorithm::Sha256, 16);
+        let empty_root = tree.get_merkle_root();
+        tree.insert(key(1), b"nullifier");
+        let root = tree.get_merkle_root();
+        tree.insert(key(2), b"blocklisted");
+        tree.remove(key(2));
+        assert_eq!(root, tree.get_merkle_root());
+        assert!(tree.get_leaf_hash(key(2)).is_err());
+        tree.remove(key(1));
+        assert_eq!(empty_root, tree.get_merkle_root());
+    }
+
+    #[test]
+    fn sparse_merkle_proof_invalid_test() {
+        let mut tree = SparseMerkleTree::new(HashingAlgorithm::Sha256, 32);
+        tree.insert(key(1), b"leaf 1");
+        tree.insert(key(2), b"leaf 2");
+        let proof = tree.merkle_proof_key(key(1));
+        assert!(proof.merklize_hash(&[0u8; 20]).is_err());
+        let truncated = SparseMerkleProof::new(
+            HashingAlgorithm::Sha256,
+            32,
+            proof.get_key(),
+            proof.get_bitmap(),
+            proof.get_pairing_hashes()[1..].to_vec()
+        );
+        assert!(truncated.merklize(b"leaf 1").is_err());
+    }
+}
@@ -0,0 +1,1826 @@
+use hex_literal::hex;
+
+// Empty hashes of a sparse Merkle tree with a hash size of 32 for each built-in algorithm, from the
+// empty leaf up to the empty root. Solana programs can't cache the 256 hashes of each table, so
+// they are precomputed here and checked against the computed tables in the sparse_merkle_tree tests.
+pub static SPARSE_MERKLE_TREE_EMPTY_HASHES_SHA256: [[u8;32]; 257] = [
+    hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    hex!("f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a92759fb4b"),
+    hex!("db56114e00fdd4c1f85c892bf35ac9a89289aaecb1ebd0a96cde606a748b5d71"),
+    hex!("c78009fdf07fc56a11f122370658a353aaa542ed63e44c4bc15ff4cd105ab33c"),
+    hex!("536d98837f2dd165a55d5eeae91485954472d56f246df256bf3cae19352a123c"),
+    hex!("9efde052aa15429fae05bad4d0b1d7c64da64d03d7a1854a588c2cb8430c0d30"),
+    hex!("d88ddfeed400a8755596b21942c1497e114c302e6118290f91e6772976041fa1"),
+    hex!("87eb0ddba57e35f6d286673802a4af5975e22506c7cf4c64bb6be5ee11527f2c"),
+    hex!("26846476fd5fc54a5d43385167c95144f2643f533cc85bb9d16b782f8d7db193"),
+    hex!("506d86582d252405b840018792cad2bf1259f1ef5aa5f887e13cb2f0094f51e1"),
+    hex!("ffff0ad7e659772f9534c195c815efc4014ef1e1daed4404c06385d11192e92b"),
+    hex!("6cf04127db05441cd833107a52be852868890e4317e6a02ab47683aa75964220"),
+    hex!("b7d05f875f140027ef5118a2247bbb84ce8f2f0f1123623085daf7960c329f5f"),
+    hex!("df6af5f5bbdb6be9ef8aa618e4bf8073960867171e29676f8b284dea6a08a85e"),
+    hex!("b58d900f5e182e3c50ef74969ea16c7726c549757cc23523c369587da7293784"),
+    hex!("d49a7502ffcfb0340b1d7885688500ca308161a7f96b62df9d083b71fcc8f2bb"),
+    hex!("8fe6b1689256c0d385f42f5bbe2027a22c1996e110ba97c171d3e5948de92beb"),
+    hex!("8d0d63c39ebade8509e0ae3c9c3876fb5fa112be18f905ecacfecb92057603ab"),
+    hex!("95eec8b2e541cad4e91de38385f2e046619f54496c2382cb6cacd5b98c26f5a4"),
+    hex!("f893e908917775b62bff23294dbbe3a1cd8e6cc1c35b4801887b646a6f81f17f"),
+    hex!("cddba7b592e3133393c16194fac7431abf2f5485ed711db282183c819e08ebaa"),
+    hex!("8a8d7fe3af8caa085a7639a832001457dfb9128a8061142ad0335629ff23ff9c"),
+    hex!("feb3c337d7a51a6fbf00b9e34c52e1c9195c969bd4e7a0bfd51d5c5bed9c1167"),
+    hex!("e71f0aa83cc32edfbefa9f4d3e0174ca85182eec9f3a09f6a6c0df6377a510d7"),
+    hex!("31206fa80a50bb6abe29085058f16212212a60eec8f049fecb92d8c8e0a84bc0"),
+    hex!("21352bfecbeddde993839f614c3dac0a3ee37543f9b412b16199dc158e23b544"),
+    hex!("619e312724bb6d7c3153ed9de791d764a366b389af13c58bf8a8d90481a46765"),
+    hex!("7cdd2986268250628d0c10e385c58c6191e6fbe05191bcc04f133f2cea72c1c4"),
+    hex!("848930bd7ba8cac54661072113fb278869e07bb8587f91392933374d017bcbe1"),
+    hex!("8869ff2c22b28cc10510d9853292803328be4fb0e80495e8bb8d271f5b889636"),
+    hex!("b5fe28e79f1b850f8658246ce9b6a1e7b49fc06db7143e8fe0b4f2b0c5523a5c"),
+    hex!("985e929f70af28d0bdd1a90a808f977f597c7c778c489e98d3bd8910d31ac0f7"),
+    hex!("c6f67e02e6e4e1bdefb994c6098953f34636ba2b6ca20a4721d2b26a886722ff"),
+    hex!("1c9a7e5ff1cf48b4ad1582d3f4e4a1004f3b20d8c5a2b71387a4254ad933ebc5"),
+    hex!("2f075ae229646b6f6aed19a5e372cf295081401eb893ff599b3f9acc0c0d3e7d"),
+    hex!("328921deb59612076801e8cd61592107b5c67c79b846595cc6320c395b46362c"),
+    hex!("bfb909fdb236ad2411b4e4883810a074b840464689986c3f8a8091827e17c327"),
+    hex!("55d8fb3687ba3ba49f342c77f5a1f89bec83d811446e1a467139213d640b6a74"),
+    hex!("f7210d4f8e7e1039790e7bf4efa207555a10a6db1dd4b95da313aaa88b88fe76"),
+    hex!("ad21b516cbc645ffe34ab5de1c8aef8cd4e7f8d2b51e8e1456adc7563cda206f"),
+    hex!("6bfe8d2bcc4237b74a5047058ef455339ecd7360cb63bfbb8ee5448e6430ba04"),
+    hex!("a7f23ce9181740dc220c814782654fee6aceb9f1ec9222c4e2467d0ab1680837"),
+    hex!("aef9476c89590a2c8cc9b3b74f4967c757c49d9866a44bacf21fa2ed675ddfa2"),
+    hex!("9a42bcad82f6a9e41284d808ead319f29f3b08209d680f0e2ce71510d071e205"),
+    hex!("d1a66d354a67b9cf179571d8e5f97792716e8dd4ec44196839a3f7c6b74f8bac"),
+    hex!("fafa3025f2f89509c2c71c74fba0cd92858ef49b0780fb5479746c8a9bfcb346"),
+    hex!("3334a7c1e7f6705aa6011a6a949645016db4acde0ca9abd66dc79d8266423056"),
+    hex!("0796fd75664faef744ee4e52d7271e2bbb769f91ed6f9b74d8b694f56606852c"),
+    hex!("7ba3ae4a417fe8545b142bc89f4adcd7ae13941cbab7750b83e9f0a66d16be64"),
+    hex!("788fafcc4aa520399adbaed195f8b12c4eb31ec10168e50aabc659a6aea516dc"),
+    hex!("e833d7a67160e68bf4c9044a53077df2727ad00cf36f4949c7b681a912140cbb"),
+    hex!("309eabf095dc6714f9f4d864bba5affae0b35ae2f5e3565bcc3a47b212767701"),
+    hex!("226a8ebefa288665a644a50273335efbb610510f241b5b720c8a368d59a69a5d"),
+    hex!("41abfd995425827625938131af0c4f33fe0bd4688c222c21fa9da8e89caa03f8"),
+    hex!("442c642ef50fa1a667a6e6d105c77c5cc3fec8d7aa2570cf1a3077b503c38069"),
+    hex!("a0a08dfc9b42d96c2de19b6d127b8ae136ddcf3e5ad0dce422c45a56f61f6a74"),
+    hex!("7d348382af096dbe0bf086c7bb39b2a2c0bc36b621ab0c738e9885d731d81740"),
+    hex!("3ab134751d191269026c86994eaa8b43a83b4ad1f6d0e77381c4e2974afbc8f6"),
+    hex!("9a7452611db2d23eae26f9bdbb88958ef44c64d0fe987be9f726adf938f50f6c"),
+    hex!("725c7f816037bfe452cd1e7ba35ac47edcb49a9a2b27aeca70dce483cb7ded1f"),
+    hex!("2cea1af51fb28b62887c39998ac9fef4dfdeda1f07e071ba558a173afd06cbc3"),
+    hex!("ff1d59f98b6c551d95089357057d5c8be26402279e9df0b1df1a10b72bf3927f"),
+    hex!("2f8a181f7c99dd215a7529bfe296a9603a1446737186d21aeb8bc7ae59e1fd21"),
+    hex!("ecc502c9b1145f3950cb7d3e3842446f81a4f0df1df537cee139ef64ea984bd9"),
+    hex!("c885c236140249c9e1640e5e99fb972d81fbb31ea5e29fbdde063627f0d6bdc8"),
+    hex!("303ce38809ba7a77b660ad0b074af9c6bcd5c02bbff2f3b0248633b0b876e449"),
+    hex!("2fd4c32b0a65616d4bceb9e2f2bd4dcf7535546f433a3e1d45ce54abc059c867"),
+    hex!("2f8d300488ab4f7464d9ee9e59d80aaa8a2039af5513f320e5a3083c63ea68ef"),
+    hex!("48562f2ab1873a6120f575267a37db470d4a6bc83ed1ad903e64f7b3755766ad"),
+    hex!("2820f9073707ceff6a0e5e2bcfca8d73d235ade70d0afd535c9177fb9266c9f7"),
+    hex!("f3f6a8151f64f6bdddc4b8c0963c5712eef47d6eb432f12699c5295914f08ea2"),
+    hex!("2ea941b101d99e7b6b18a6a62a0f573c4b80d0c68ca1d15f885de9ce0b4fc488"),
+    hex!("8212c49b0949693091c6672a06241f3df865a676cccdcbd16f0615eea6068383"),
+    hex!("e726e40dbd2f9841293b5b3c15e918a872aed2ba491f4e111ea0913a04ffe165"),
+    hex!("7729475e1ace968d096a7cbf0b88348158a37eef64b90994cbd37ddc3adf5370"),
+    hex!("fc487e466c2bf48d87b5d66f5aa24f9c5b3f990e210f5065050d5208d59b6b87"),
+    hex!("77fc88582c114fb77e9bc6666e3f3fc6e89e4cbd3dd1590a6f67adb547f348d5"),
+    hex!("dc243614ffda79e3d7556ef3fdea0b44df1757badae017b05f5133fd15c27aea"),
+    hex!("e705095fe3ed6cb2da458664229c5158d88aa0f775528dfb27ff3ffe270fc0c9"),
+    hex!("779f1ecbfae3d0e1e7328817446dbf4bcb6a678c6ca4e2726e9f3e6e65fcdaca"),
+    hex!("5e5c577e401fb0ed3c051979201cfc5ec100dae9942278e99e3434cfe560c276"),
+    hex!("1894ce6124659cb0d6f90a84424e372b59dd7e7220bdb16804539b51ba7c6ef1"),
+    hex!("641edf1965e87a70428712727ed1db13b2de91ed574c83b28339bc7502aec3f2"),
+    hex!("0a36dc4d1b170aec654cd6e3886f6ae3a2448d30a95e3ae5c56b7a5b00cb8f3d"),
+    hex!("571968a6a58857784b13e1bf9480285a7ae70ddf2e321d06b17cf0a933119cdd"),
+    hex!("e56813628ec57e8de059bca787d1ef7cb1cb9f608c6ba0bf780277beddbd3f27"),
+    hex!("5ef1e53a05f43d01472447d914409459ce5f2b0d24566187efdbefc25864da2b"),
+    hex!("ad19b79824b2a80a8b891450d2c7fccf9e51c25979b37dba8ed597fb93ba9d9d"),
+    hex!("180fedca06656cb910077013ad2679695090269fad1589e290162fe90e97d4aa"),
+    hex!("73f472650e8da09bcf2c8cb998db52bb27bba5c6335d4844323d68094c1028f8"),
+    hex!("ff746696955cfa91aed5e76102710dfe2e278279bf543de9c3df8a7134a33b64"),
+    hex!("2e98ae4b6e69b95b71048ec119719851c050529a20c67a2c401723b34de9748a"),
+    hex!("3b5c39d7274c2a74d4ed2d9e8410ead2f351dfd6158148f1b10d97d60d739207"),
+    hex!("423f672311c1a44ce5a44765f6217eabf759de2bd882b6bf0f7b09694e4b8043"),
+    hex!("a3ba0d85049395335718883c7451cd3d10a326b1d73e223aa5549ee68c3beabf"),
+    hex!("d3d47dffaa99a033c8c3bb62dc0a438b71f91b18a38c87a49fa88474dde87924"),
+    hex!("4189d201eed3f4ebcb3fd5a4c73f282923f010e753c3c5e7e3d0199037a3b087"),
+    hex!("142a03b09d66cbe5b45fea8fa25d6a297084aed9e03bee72862f9d6b3dbabba0"),
+    hex!("4cd8ea52aca52408fb87b0e697cd24632d94a6df757fa3f29cc0a5d5494edcc5"),
+    hex!("afa2c75dc2c5a8462c17092ee1efe21de9b10f0dc0dea0794f304513e1aa33d2"),
+    hex!("3d39740655edfc46fb8afd539e23070dfb35e0d6d1cf67eed7a1c25442e01696"),
+    hex!("7d609e3eee6fa33910f427d52e88efcca673e453b4acde37a8a8687f3d3af57a"),
+    hex!("7811af413c13feec4a8c2774f189a7e24069b688700756b947de0157185432c6"),
+    hex!("571c032857aadbec07310299b0041c26924a619669e161559a5b1f49aff9cc09"),
+    hex!("0a6b5e75c36a716b43d41d262adb047d165da4c39a512f145f5fc90f86923ea3"),
+    hex!("547374ec1a58c7a4d14d51afb52ed4e404a9eb22f008b3387d326edbd3066c77"),
+    hex!("f9e4305daad80dfb3776230d54edddf5050cefe0846999414c706ea60aa3fe11"),
+    hex!("560094093e5d3692d4918b79804f7eb6a0a1868000aac0659090d995c2377045"),
+    hex!("95cba54239a7304d36b0530219dfb8446e85ddc9bb6d401f6324fd888bdae4f7"),
+    hex!("ef97d5ba70031de19734533a11bc7a52ca5b05c3b1d2c61d53010a57878dae8d"),
+    hex!("854accef35670517593e126a933f9a77ffb7b6ced2735f5518f5dc16b16ec0cd"),
+    hex!("7f11203031a72517235fc9bcf05675a6f67062c3e2db8ec26f6841bc04125818"),
+    hex!("313a6522e9eaae01ff1e4bb545c042d2ce3a7271f668ecaa303b54eb70ebf294"),
+    hex!("bcfd4c9b524c615b741dd6f5d4aea9779e53eae70e56b884d238c96859f4b745"),
+    hex!("38e5e5559730caf93710ca7937ceb17511e70d5356979a700223828f5b58e9e9"),
+    hex!("c39ed9a49132e90199629a542f75e932dfc2a808684e19719af55eaa5b7da7fc"),
+    hex!("6b28b005159e4c86a041c4d927db665863bf9d1b24b1660d802f50dbdd84ab07"),
+    hex!("53eb316498b48aa6edc5b6a5ae8299430d2696a588c576febef380d965bc6e8c"),
+    hex!("77a3fe503f5c11aaf32d40ac768143769cd0f21ff89214ffda1bdbc69a5d4ace"),
+    hex!("d32df6cd44214942706b01b785b310d0102e1acb64b9786f1c5d666f3c4d796b"),
+    hex!("27803233e079f2509c07cbb25962a808c7a6c65f8a99bd99fe54da707b9a45a4"),
+    hex!("765ff12c95a406baf48020d6ae348e2021a94ce6b40c1426ea4c44a74cab228c"),
+    hex!("d0522d45ad5883d1dfe4c36925a94fcbc30e828083a87c53ccaa12cd679fed56"),
+    hex!("a52cb2ecc93349c4a6bd2f7978c4c9038ebc508211a2eb6a066e33b2bc9a529a"),
+    hex!("ec62a0632977f6fd261698a38df08b62cc56996838b0b19de2a398e7a0429066"),
+    hex!("088bc060f99c193b0168c7247e1a0622585bcbf802672e011c3bfb4d88b2cf81"),
+    hex!("ad484a007384e0b23f629cd335620edbae313dc07a31ca58fe1d97fac2ca0b55"),
+    hex!("1d55d05fcb68c7f58255d16397bd929f32b4f313075414742d363af357ad69c8"),
+    hex!("b4c9dacf8e194e353dce7638d76f282fe40399f7b0ad74a2229a6d4f5be774de"),
+    hex!("6fb8963ceb8052837b3fab4f986921791b82afe3dffe09a5e0fe54f86281a2b4"),
+    hex!("7a209efa3abf7e0e278d4f3eb9a7af187beefa55d5657b7d44cfaf5c02739464"),
+    hex!("a9aff778374b53b51b616d3bf2856f4adb6fdad2a3f55202355f70302ec39383"),
+    hex!("cdbce02682b6897a922e902c366d3aa91ba4a61eb5fb31f0af81c1c9a5147175"),
+    hex!("38334b001abba65528ab2a81bdd124a4ebb3aa9245d8665eed4747fe9887e6d2"),
+    hex!("3ce72d24130615d5d772d8015eeb810a0b01bcbcc134f7947c36f42e6f635be0"),
+    hex!("15cf750b7bedb11ba84aeaea4316b8491b5b3f3c2ea66260dce2b234c46d97e3"),
+    hex!("9c9c16d380ee2ea38bf56c28847c1a88e3dce7eee91f326693936b4549bc58ec"),
+    hex!("a4eaba691de0db77612509511112db76db988de972717075365a35718d990421"),
+    hex!("bca1e063a76c7d73265032929b4eadab4be617ed5433c0571649c63bd2734558"),
+    hex!("22a8f761fe210165da438ad5711a471fda8e5f3c0c91b5f1a2f1467244611d8a"),
+    hex!("1ed5177e70183ce8333cf11ad039ba6c5e3e520ff7fa89a91c90dbf2b3db7331"),
+    hex!("b72e64036cfff91f2ac171401ca8003e2f94b55d4184fe737303ab51590af450"),
+    hex!("d08206d65b51d895e2a6ea9bad707e72a9f87461dd46837ee4f930d7c4df0c61"),
+    hex!("829d361560d54e2dd41b1f719895b9e8e98f34137a8cfeb162430fe2c666c891"),
+    hex!("bf273a4700e01b28e69407ef41048098f29a6d98cb8e243a106e3c82d41982a2"),
+    hex!("0b125ccdf258da646b93c9f2d680d9cc4bd2e18841109760327d814882b0140b"),
+    hex!("951373e6f752e59746f00193340574df6d3529b07665f6da156552d5182e5489"),
+    hex!("191adb5f532266e046db16b27f63caf7e438d3fee916b742ce1d4dc0b64a1dd8"),
+    hex!("de189968460a1e88cad6fcadc03af1a821b9e8c938062b49bb8d2b5f751dd5c6"),
+    hex!("af5026c11eaa14638813adc4f6889f586a472a02bb5395b03dd432d2b7a38fbd"),
+    hex!("dda4dbed53bff8d2d814694ea8e4ce88fa6629e1e450d923ce4f1fa7d0491b5e"),
+    hex!("989923a8f20c4fa00dd52678f2b96d620f28ec8867307320d9b9eeaffef29d06"),
+    hex!("6bcb6e434274dc03f34248903dc45ce38909c3c32124db0a8d393d5c53788632"),
+    hex!("7a4234694dab0d28edc60c9312c0ed418c240358e271bd24236335694e3c10b1"),
+    hex!("fad91317824119818d4bea60cdd1553368cad703193510f6ea049ffd121b36a8"),
+    hex!("46e73c7855b43cc2d753ed92b2c857899416708d9dee835fe1b3eaa43914904e"),
+    hex!("0bd8c308e2b0e69e8c45a0d857642a490f365bdb1d444c5439ea91bd68543a9a"),
+    hex!("250198a7ea057d3617a61d8392ce8ecfd3913a3dda3c9de66292a19729af1f34"),
+    hex!("9e8efb2de6594b4e0c9ef31cbe56a2828d616e7bbaadc9f8b66822f19c60d9e9"),
+    hex!("d337256346a5878d3a13cd8810196dc529187031514adaf021f202d4de68fc40"),
+    hex!("0071bb79d0a2e58b2d539f99f49b08af1ad9c85e735227f04875a356c6b18edb"),
+    hex!("3f60030a750bf8af6d915e9d8c7b7c05c8a255c90203cb7b8970124b541418e8"),
+    hex!("e4e1e8eef0d58e9955e345919a9d8eb44ee5f2b3986f6de4c661896b8e128836"),
+    hex!("fa8b2317bc5d7b9ab822f3c79918481eea406fc544698b652822170087d46cb9"),
+    hex!("7f71344c776124c9f687317237a8c015a274adbc9805d3c03942e8f9052021a4"),
+    hex!("68ca8f8d2f1878a8f17f625bc6cf41c208b5ac4eb643734f73b629e56d7777c0"),
+    hex!("e24487eb7b3cb394cd868bdc8f41bb9c16414c429ca1f68b3adfe1157063cfcd"),
+    hex!("00e406a0bfe6d6f430081dadde423977bac40ae1c68ebb3f4f2fbe9c8698f49d"),
+    hex!("acb83f57c29f2cbb92bbba9a4d0f018ee7be99ff3b8c0e2d04a8f73e379d96e5"),
+    hex!("e44c0295bb220b51516b075e1cd1547d551d464bafdf8cbf8483c4c28388ab1c"),
+    hex!("516f8d48419db9b8747769bc4a04a82e7a368b88fc4456f905c9cf8881f58118"),
+    hex!("f5135282480ec94137a88ec21fcfa8047b273a2a0cda6c41f7adc18b57258a52"),
+    hex!("1428a84b473117c079c78632d3bd557efa2c9f0a4795d3cd6bfbe2eb93e58106"),
+    hex!("9b3079358080d1de88b5bad267018b5ead9a2f388798aa719cb303bde156af74"),
+    hex!("d16373e1873dfc8486796bb101253677a58a22a8ea55744c4c1424d716beba92"),
+    hex!("167a576d0fbe83512dc30f9e9cb5fc0474f9b860721c3e9e7d9ecf39ef298ccd"),
+    hex!("e6686c32ed91767b200970fbcfb022bc036a6962663fa667896aaedcefc164b2"),
+    hex!("6c6ad986cebf730ec593477de186609e9aa68ab84e15c15df3ca88669f7c9ec6"),
+    hex!("6bd2bf838ef7e14d552727a397e138d801daabac2e8b434788826ea8c201cafc"),
+    hex!("827c828a23692a1b477e5fa13e4ad8cbbd5488bf15a60772e629ffa56b49fe3d"),
+    hex!("6b224e497203715208f44b280f4f0839ab4155ffbba59c6762adf7eb34a961d3"),
+    hex!("ded73980da15312194a6da966a17babdfd33a9c5fe7583aea91152c604e2652b"),
+    hex!("5ba6d07cc9e01caa61389c0529d9fc6a21ea6caf66b8701f739faac2535d922a"),
+    hex!("6d63be408e4f8b96b65eb47ccd73983a01fbb163fec1e56b9745a5dc0ca67ad7"),
+    hex!("7b8228a258542d1a63c29a2f85111d35ef30d46704882b2fd63558f3ba380987"),
+    hex!("2b0a46ee56f357e4f68dd7633d768b0beb9483b3eabb934abb085b84331dfbd7"),
+    hex!("192b7df8cd768f4c0d25d8d15c81f07cff0582f54fcc5310352aea617e97f3dc"),
+    hex!("5c206313553aa4e61fe5f282d2a52bd437b5a4901f76f8493d331ecd67621c6d"),
+    hex!("038531c316198eb2463137682985ccb4093defc7af935d9778ee93cc77232e20"),
+    hex!("d1d0ffe2757e92387ff26802c2567d3b517edd8ab0ed735f1bda40e19189e4a8"),
+    hex!("9a0bf0a2f777aa15abfc65719d68e1a4b4c8b04d8c20986ab454f217ed5d1a77"),
+    hex!("f5ff0267d136ad5fb7803f9a52124305336f884578b8313d52aab6e68cc6ed71"),
+    hex!("ae33496858396ec389fbb59abffd16ee67bd59b55aa2bc58a026fce3f70d2ddc"),
+    hex!("bb0fa256f82f0145f105b62f434ba8e7d17f07f51b5a7a9543783190bd3831ec"),
+    hex!("939ee8e5bd14bff6c01075bf49e45d94f96808f01c99ddeafc8d4b8f44e6a05e"),
+    hex!("9a54efdd129fd4693c820f2716821442b1eca853e7c93741ef9f0161ea06d4b8"),
+    hex!("b27b48188002f9d0552742c5bf3604fbf0c6009cd4c98cf9ca10f383154a8e13"),
+    hex!("7d3926374049c1197716172c631f41d367ce8c17ec98262fe1000b9d51ccf309"),
+    hex!("faaa988db02ead8936809594f187d4900e83c1e8b227b768091f040369ceb22c"),
+    hex!("c7efe477b58a476456ce268710468c190ce895c72990d8c5f3d70f48bd9777af"),
+    hex!("9db840f7f4435a6e2601def426a1cafab40c383934d46efa8f637606ef17a167"),
+    hex!("7071b73f59d8ee9e262774d07ffe8e9bd13b4bb03812a868bbe8020dc6af9b4e"),
+    hex!("c91a2355b1d9c27f2750eaf0e4a470547a9c0884b536b9b5c27d791826a74197"),
+    hex!("55be44002962674316f8eadd80c25d9bb88129002bcdeecf8879f32c9a7222ec"),
+    hex!("b2947a07585b2362983b505170d37c2932e497d1b0086be58768e7cb11568fc9"),
+    hex!("01f2c4d66c4c10b75d2955794db414ac9ac47a3da4ce8d73df6e709a57a9da8d"),
+    hex!("93aafcb9e799308d55f04de188a2524277996a651cbf4687f5be20cb5718ac73"),
+    hex!("77b82bed4f49392ca8ed1f9e89e2224804ab1876d5ae3a0a38476fdc88a1e1bf"),
+    hex!("18b110e4533cd8e24dceeb0d6f25c6fe6f21861ad6738cbd07ea21b3fe6d299f"),
+    hex!("c1acc7d94d6c77a97ec33829ab04aad2cfe2a66f87e515c39c86281e1958bae0"),
+    hex!("eaa14eb68ba2d887583bedd1f962ccbbbffb69ffa8ed3fd394290cc46af90fb4"),
+    hex!("c1a6c34f8efa4117812b95df5e852c17d953f41897e6fc56b7930b3270a02d0d"),
+    hex!("94b82c26a215958ed5cd1969ed815570d2d786c5824fd76b7c145aaacb0b6bf7"),
+    hex!("ba27f8b62cd14886c7b40bc9723bbc1731f1ab72b1083792cbeea38437fc1fa3"),
+    hex!("369e9df2ac6ca05847434d3d7b75ea754aa406d96b534a2b3ddf99ee314bd756"),
+    hex!("f02ea520110c2ecf0b9d24e1a932df150c7ae6d0406c9702437331df11537d83"),
+    hex!("7adf30a5042aa23d0f2b86b943ffdf8e1a387f9db78354a99ccc70c759c3b3ef"),
+    hex!("422233b3d3990f51861cb4a0be62fc1158f7d66d1af1e6be190538da1a47675e"),
+    hex!("2865fdece0d6736d4cc927baf48bbcb71a3cedbcca33c907c95dedbe084e65f2"),
+    hex!("a219a6d9e8400cb724968b120cd6b0137147270219984475823e8931c315adc6"),
+    hex!("aab04620c6057ef8f3d152ee0c6fcdc5cf7bdf73111dc9119a08bd84a610035c"),
+    hex!("7ef52f38e6c3734e221b2476cdafcf8bdd91f772e2b5b1746c09f2ebb925fead"),
+    hex!("b455965645953a7cc469c9ef671c407b4692cee7974de006ef8376b781ef4c1a"),
+    hex!("d643d01d87883db2f37c1be44cbb68f3f561e3c5b395e5c502d6ca2e98119f0c"),
+    hex!("5244bcda8f680e760a4ff06f38c5354cab07c518fe51489741474c766f8a0e82"),
+    hex!("9ac06bd5cb3382066d394c337a34ac63004b95ed6d18490a5d5923eaf783370f"),
+    hex!("d48e5a4623aeef5c5a5a0b9c546167c264430f1f37412657b49e56f7810524e5"),
+    hex!("34cdc61dc537af35237b6cdb423ed5b9ded8be86e786241945f02d11e3e95574"),
+    hex!("ffead8db2882424c8ba7a9b7bf75257c1c845e695e19c19aef85ae5b08f86bd6"),
+    hex!("e34f7a98bf9a8c3961da2ace862cdef14917d661886d75cdc7bb9c467a3cf67c"),
+    hex!("7e8b64ff11d81d9e0d4fd388e5498d7cd7a2a84a967ce2b50c22b1671a15ce91"),
+    hex!("43b864520467e57468cad954cd568c45d823ac14b004574b373959485f86fe21"),
+    hex!("8210a952ae17e4fc00231912c7aff6c9b2a96ebd3131164dd0b490f1a74c5b00"),
+    hex!("20991a32e12d4c2fa136135cf47f2e2f58d1d7dd67946a5d4692eb69a4063d11"),
+    hex!("9b053c0fc4bcb75fce6a68ba5754e0f4a479f2d6ed3d3cfe3c8f8f11991209a2"),
+    hex!("2363288c85ce865622d14bc1d7b29bf5c51222ece0aca35d086747178af7ed04"),
+    hex!("63d1e5f8ca26eea7bf22844f516c8d8666446f612605c2b969448fdb2ea39f10"),
+    hex!("4af5de7f837dc337115b548461c18bb8ecf37a512f0e01d93065d951acc2129e"),
+    hex!("bb7784dae8eeb4fdb985ceead81bbff6337b046d89000af71a28429c46ac3746"),
+    hex!("edb7aeab2c401763dc273f390d59eb6d7a1e954e17cdad1f24f851d89e0b20dc"),
+    hex!("0348cba26f2bf55643957111f4746ae8a2d019c4992d1b6c5f9067e5f5262a2d"),
+    hex!("8aa217f6934166643f130cf934a506811651782040a457857313773f0441a896"),
+    hex!("247ea2dba8b59353286e8d5c0d0af41ad5299ec8423430892894783b0b21934c"),
+    hex!("820aa3db6e2cd5c94b3ee6927472a2b4e2dea2485d497d2166b8899658900812"),
+    hex!("2c7ee7353ee7663a908a6cc9542e260ef192b524f9a981d838c32aa521757289"),
+    hex!("a1dd4f1d614a4531701bc99bbc4ae3e49377f05ee1ce6a06cb561a872ec51d95"),
+    hex!("f61bd66396f4f5be168e389dc52d729896dfdc7979bbd3c588f80416fa4efd01"),
+    hex!("cb5a007507df3d7c285d15743d91526286c27afd9d89360f3cc501629fec519b"),
+    hex!("f0ebbe83feb1c91f2149786c827401326ff8fa6987f430d1ef5424b8cb42bbee"),
+    hex!("69b34adc3751bf09895c67d5cab057365a2571510edf27a0abc82cb2fc72d8f6"),
+    hex!("dfc37b264ff3ee0baddef4fed331ff3dd3b071b9ab1bb3953de983eceafafd77"),
+    hex!("811a2613205b53fa3f998b1d9cd39e6212b6094a7b5a0fa38682b0a20387097d"),
+    hex!("b2d6e7c753480a05a88fef18731354e934a75785009b07b268e844382a9b9cb2"),
+    hex!("5331bbc48eeaf872baea693d187c43dc5a267881b90d2f2659c9af724c3c48c5"),
+    hex!("e579b9be0b8f58daacc4f66c959ed3ec903884d1914e11e7e0c3bbf2a5627b43"),
+    hex!("b9d06312bf5aee1fa7c879fc61c62edf16e9b523a9f89e04c02000223fbd0de9"),
+    hex!("b178c245c947ea7e21ecede07728941a6ab1b706143c06873baff8ebd6de6308")
+];
+
+pub static SPARSE_MERKLE_TREE_EMPTY_HASHES_SHA256D: [[u8;32]; 257] = [
+    hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    hex!("e2f61c3f71d1defd3fa999dfa36953755c690689799962b48bebd836974e8cf9"),
+    hex!("7d24db2bfa41474bfb2f877d688fac5faa5e10a2808cf9de307370b93352e548"),
+    hex!("94857d3e08918f70395d9206410fbfa942f1a889aa5ab8188ec33c2f6e207dc7"),
+    hex!("19bf1203d3bf48393c69cc25598914bb9e0d302f363d9825dba0b9fb959ca33b"),
+    hex!("ad83a7857080ddaac962e156e92e3f07d488bc7f06ca9c070ec0daeb7370ee88"),
+    hex!("f74f537c27f0891ffe896bf116f704c338cef196e7c57217f76bbb0dd711f7cd"),
+    hex!("66b425a6e12a0878a639b9cc7f182f67f871be14964f2e95a259a9a75bee0341"),
+    hex!("6d07571dee2e35e1378bc43a8e1388d2fef6b3021cc9924b885d53b2ce390ea8"),
+    hex!("8689f7da67497b46fcfb49e202553931bf5fdc8c9597ef16588ea871176bd45e"),
+    hex!("82372984fdbd4ebc285f9bdc92f8f8997a1b3e6ea3a1b9a8b906129300eb047e"),
+    hex!("aa8439a0648f5ad8fc30fa5e73571fbfe06ccb00afafd695961555940d050f29"),
+    hex!("72d549f27b8374293cb219ba0a069d8f0367c1a6def1c3f7c66c9e421fa15b65"),
+    hex!("8757fab0c4022c2daacd0fb9d78b4b51f600dd232140fe512e867b522acdd04d"),
+    hex!("2c0c2a1c6baa1cb2a3806f7eedd59d8b16245a54366c32da09c1d74640462cc6"),
+    hex!("2df0d88e87121a380b03cdcc128f54bd2bc150c5f8b48d67a942b6faeec35533"),
+    hex!("97a565a45c0eb710ac45d9e5d7b7b6b2ac244c027030b30226e3cd8979e9c725"),
+    hex!("2784d4547e7a8d65028b92671e859a529a458c16a103dfb87ebbd62df3814b97"),
+    hex!("c142933c44902f9c7282f618e36deacec33ad1942c0c74ad776eee0a9656dead"),
+    hex!("597a55b1e463064e6e5121eecbdda835291687fb21a42c15d7fc2d392c89daa1"),
+    hex!("a10d48afa61c33f18a6e20f0976e410e60835146dcc5c6b39e33151e2bb8b6b1"),
+    hex!("b8eff62a5f99243e44a27703489b613a72f090984e5ff27c74ec711aca059f03"),
+    hex!("744f1160bd2d0f0bc45b16d5fa5af844aadbcb1de91f1c937cdb6d2702b36e9e"),
+    hex!("d5861cbb5f78afc2eefce6e275c530038b1e78804c94d25badcd89c1e07b16be"),
+    hex!("3ed881bfa80c39a9842d184c883e7f2f02af6314bc8859e758fe9602d972218f"),
+    hex!("a4c462803f246bb7d3aad32286fbcf61b4187603ee0e6974363a5a9422fd03e0"),
+    hex!("51e808a0daae7cee49a8de2851d84260ab7de3b4d16289e71412be18318a1802"),
+    hex!("bc480e864418d4c1a8525cc96430b4a58975018fdd05ef01168bb402264dc1c9"),
+    hex!("14014a7bc757e618999f864ec757233ecf76bac7b78151684c017d5244c2a015"),
+    hex!("0dc875a4a409e924f43c7388af812d96e6479a0115beef2ad85c5a14d1847d2a"),
+    hex!("88fd9b3c41251c502d808cc54e557e656a9f729f4c19c0cc3d1c1a4fc72d2e37"),
+    hex!("b643e956622928dbf254e88706208e511b8afc1e0697d044d19b3d0d936d41ad"),
+    hex!("46fa3a9dae096e99f4ed96cafd345aa23edf1d8dd7ac8f34f382b007541f0983"),
+    hex!("10476b39fbb7dcdfd41e5cf0c86b73594de2f4dd59d836f4cda17c571a0d0586"),
+    hex!("3174b90d9bef920d36ac8e737d9de9c8e282436647718dbb9b3173f834744afa"),
+    hex!("2a27b1705d26558418e077f5a691c6cfd771247f22a50606bfe390470280795d"),
+    hex!("195840ce393766da9969d83e41bb49b9e3b1c339ad051f43b889519ee217c570"),
+    hex!("83fe4cfe5f872f5935eec9e350c8adabc6370c939c1f4841a9343f14c60ffa97"),
+    hex!("22c56392f83d01d71d48b78afd27515156cbb7b3f12f7ba022c2d1a4f5de427a"),
+    hex!("804f8173898beda189b406cb48116ea4535adc159f7c26224951d94100235761"),
+    hex!("8a0bbd69e4839a641b8d453a41e1a7d69d4c22000003f0c1f0d3876480d6075d"),
+    hex!("b8d320608394335334c75ce7e9db9fb95e5f943bbd66dff94dceea35d7e41f16"),
+    hex!("2fb6dae04a51aecd1309db4b80d3f98f658cc188768dfd678560cc8959e6b8c6"),
+    hex!("66277f6b9249b68ae79a04427e87df67764a05cd2f8b2f40cb9d8bf98ceb1a6b"),
+    hex!("80d80ac3f75ab9457e65e4a60869c946dbf11f233f0e40ba95edd5146b8aa566"),
+    hex!("979127bccc5ee12779a895a991031071f140e80849b9b58d78f4068d86d91795"),
+    hex!("e1e377c8020f7b179fccf706d03d2bc02f696c623e984399895ca7ca3d239424"),
+    hex!("0d4a6ce09ae4231673e59c5ded788d7b68563e53675260e79d35637afc36b1f0"),
+    hex!("da2746b0aad80450ff70ce287f3df96d669fe1918323f4043d25165175cffca5"),
+    hex!("30cf5977ffbae041380d284e2689a0d95d3166b3549fdce16ac6a92fd40b71e0"),
+    hex!("abbbcd81b6a900130916e358bb1f722a7f6f15ae9c99b341a1ec5015b183b080"),
+    hex!("c979a7bc1fb11cae2c23b09a05e0350180f7e3855cefeb2c442c44609dd71e62"),
+    hex!("0902a0f16d93a3e07fb1a6c086cac2659ea0b55c682dfc113ce74e2c399ed03a"),
+    hex!("5fa90b0dd776e892aac58c2ecbcccd3f2cea8c2587e1704ac3798c06c4252b86"),
+    hex!("75bf232b652ea84687076df625988b33557b091e9629a5ef57997ea6c490ee78"),
+    hex!("9ef6d7366a1ed9d2c492b9af4627a963a3d2547837b19cce4f580c761b037614"),
+    hex!("07b4814e30596d0949ef0de137e24061bfa07492cce0e82de0d22af9d8d81b6f"),
+    hex!("28d5e1e83bec0e2e2f8c92432958164d08ac5de6a1c9beb47994679bf3605bce"),
+    hex!("45f156e51ba56c1f897fa7c079b59f862a13b29be365882871339737172176ec"),
+    hex!("1e4486f334e0e9dabd41f1e6959278953c26483451e3a2f2bf028fdb5e20dc50"),
+    hex!("0a625b5a52ba438e5fe664205a67f6b28fcc91b20a0c10c0154b8d6ecfdd9ed2"),
+    hex!("6ee2db94d2bb955bdbcfc3a25abb076e7daae7006cf779b225dac03d53fb9865"),
+    hex!("fa4b633ccbffdc8b3af8e8e32530965d744eff67deebe1f43eb3307a46adfd8d"),
+    hex!("245fefe6b7498de9e413df860b8344c6af6f3b751ad186c87773699b03fc6a65"),
+    hex!("5e050406c8592f6ba4fb96657404673ad7122b583cbbbf2c2d8f9b249dfc0718"),
+    hex!("17b7f9fc9bf6261346b041caaabb7d6ea967a95d27ed513e5dca91f9a31b29e6"),
+    hex!("24ee23b1faf837fd0ae11d48b1e1b2eabf4fa353c82826c88a25c884dfe3bb1d"),
+    hex!("1b73905e1662087f20dd559f525dd5c15a4dd71eb2da53e37a87650089a1f953"),
+    hex!("99c782e7a7f9008d0d59752ffa9085ba9c3f4b65db2bb1c31aa863ec65e01265"),
+    hex!("b360d9aa37321de7bd49ad3b2fb5125009efc7d81051e1d591da8ec3c4c5acdf"),
+    hex!("2a8cfaeaca2f5d28098bd9bfd5c1d0ff5dfd54f450870e13873f669c3fa7dd07"),
+    hex!("f799ec58ee318d1a6fbd46cbb040be2e7dd1db30aab8661f8ac34e37be8ad1b8"),
+    hex!("06e8b8a3643ee65a87dc2aabb89225cfd660acaac5fddf30c54a464f116b4408"),
+    hex!("4cb4d93d9b5319a9436f96e160624bc1fad55e35e97ad8008af4a7a9717c6223"),
+    hex!("e885a705a3f8997cb96ea0778e90924b881475e23c76950a2cf82615aee8cc60"),
+    hex!("505bc7750adb8254daaa524d5ce6c2953078a8f36d815f68d53c15b5c5182355"),
+    hex!("dc3f52140208a95c23f1b72ee7ebdb075ff998969ac467d0470b118816bfa336"),
+    hex!("a02c610c68d08bab5111da5805484cf13a19e42537fc983f5550774ea51bcdd9"),
+    hex!("f2271bedb16d677bdcaec16c48120039f48617ce7f8149a6f5de171d0097b124"),
+    hex!("ce18970e24d1db1efcf1b9858f07bbf6e7a19f576a496a5eebb25e38ca9b4ae8"),
+    hex!("19650e2ee0280ae2391250fa2943e031c14a35ddb518c45e82a9158e1bde23e4"),
+    hex!("b6c57e33a71796a008ededcb81f529f68a531da14a73a3dfe782c67719857332"),
+    hex!("66a8c841a2e7efff9b6ae32c336f8bb4133d86de114c664e59763a810cb2b29e"),
+    hex!("81e7d33164cb29c392725a6dcd1c9f1435dae067e999ad3721200c792c03b94d"),
+    hex!("db759f283ce2759e7e2ce89e71419cc0d91815e788485b391b3c38dc50c84605"),
+    hex!("548a3dc58678695fddf8aaa3cb15ab4616c877bc6d4d5d25bf8620200c2aa9f3"),
+    hex!("0135f5c126db738d757eb0cd3b163079543d054587088357d1018ac5e5a42a56"),
+    hex!("98939c5a75c358a7814296cb71bdc4bb591903240f6d3b07436bf982a75ac3df"),
+    hex!("553a49ec4ed1fde1fd35a832d6883817a027fbed7a5b853351f52e5a95814b39"),
+    hex!("16d8230e50f3a30dfde0fa694d62ddbcbcec4c54e0a467366a27a61471f0b26f"),
+    hex!("13fe7b2a22627827565c11038effa559e5c643ef168e52a92f2e482b66a08b56"),
+    hex!("26165979b4ee39d9d60f6909d6ae1ebaccd65d893b1190f6bb49b80ccefef3d7"),
+    hex!("7306b0b6074dfa59b745dcb9f098d3bf1869b048f09a5536c6f7df634331b786"),
+    hex!("3239603fec442ad2faba519023d0dab50c40dd6405b11cb9c67a3ab26ed92dab"),
+    hex!("b4c2012b6f09db5bc309ca09f24175c157410aae73aed1dde05813dadd9fd284"),
+    hex!("1c8500a8835d3d823a1f47b7f41b54149ab42919a29c7f0ac6cb54eb34f6594a"),
+    hex!("3a1e1d561d962594a7edfeba28d01ae4c16328ee4ce394296cdd27f35ef096d5"),
+    hex!("49fa9049ebe8b4b0fab037eaf659e619ad02d32d0c1745544c69795c527b52d4"),
+    hex!("cb1b7a47930cf8b823534cb9defb447876be77b18bd6fe62f7c3292744e94a0c"),
+    hex!("c9b1e3db4268c9739676cdf405cad2abc4d4a2e69fe22e35c1651cf404feee69"),
+    hex!("a666ccbbc29fc2591ffd137a2ff3b424180e4afac8217d1b22b197178decb12b"),
+    hex!("1ddabe9ae517d91744c2e8d4c4b81b7e3165e028fb6663cfec83374e21f7c2a6"),
+    hex!("94c53586ff0badee4347adab0dcbf7370399b6ad34e3d52cd67f4c055c221a41"),
+    hex!("b1867e5bf0169c62c45feaee94c6899767ca070ab73fa9e7f0708ba866701a84"),
+    hex!("a40662417d54bdf912a04f1af9a0336f0bc0c8cdb11663713aa226cddb2018e9"),
+    hex!("84a4a42e644b5827939d9fc4ba0772ee12fa7eb6517247d5b74aff984a2e6b11"),
+    hex!("ce4f04e92b186e7058e8ca8562cccb20b34cbf490a460633b0e1609fe4d2a799"),
+    hex!("7a162edc8670817cbb30865094651b84c60befbc8db8e8dd96f5340f1a5b6309"),
+    hex!("79f817b5a78bc92d701e925171dc64394b5acd30438fd127427417653d3e2f0f"),
+    hex!("3fbb07db8470c554604ad000572083c6edc07bc3bb55eb44b938fba9a8ad4ec2"),
+    hex!("cdd66fe136c288d48770b8b65b2be8113477c5b7af3d0da588bb0f51e6eaa533"),
+    hex!("82a13afb943e5b7774598a982cf9ef632b9cbdeb10f76637803e4f853f40c9a2"),
+    hex!("70f9b91f3c894a6827e3b5e435f7e29c7daccc63709e6bcdc5514d28376e0448"),
+    hex!("5bf3456c0d4167c32d672fa5aaeb600dd0ac3eb6774cbd52884723e079e6fd3d"),
+    hex!("e824bf77e94704f721e5ea3d7ffb7685c48caa686943578a9d09c64e34b953a3"),
+    hex!("173500e802e3817fdcca5b1ab1fc3519765d0d965d3c88c5b2a8d323b18bc217"),
+    hex!("c7cd0fc1311199967b1e224e974b3ccc512a9611632a28c56734c9ef62f169af"),
+    hex!("d949873fec01210f366259793c0907da773b8e8d344e1fce836588d2976fab2e"),
+    hex!("0ba2215af8bfb817f0a404f1e89c1349751c8e3c902e80cb8bb4ef7919414b68"),
+    hex!("9a42f7f8cb7ce5dd74f6335fd803e92ce77cddf6a31b0e2bbbf2117fa3616f53"),
+    hex!("1c32a9014dbe671de3ea3524257661c8157e531c0c8f358cdf7667236c79424b"),
+    hex!("8d976be453961ab7bff1c7397ae9a3ee649d27a35d52701af839f7a2593d45e8"),
+    hex!("47d9303fbcdc83f24d5a5eefa47b2b340af90a76ba55780c4596783dd9593e4b"),
+    hex!("9554ef04205a12df7897c28218e685f6cc96c6c976af996fd4623acd3276bf78"),
+    hex!("2926de8059ecf3522d122cba1fe763c09ceeb866a3e0b9a949e369b3877983cd"),
+    hex!("f78b33b2d7febfc50be6a97c6e45e9b374460b4955c71a211e6b5cc48f726c15"),
+    hex!("b81cff6211cc301bfd06ae2ef41f10691daebd6568e9b903f4c4067f07de3439"),
+    hex!("c10e077cbadc5d933cab6708e34595b92e7e6eaace3643896a296fe2a47816a0"),
+    hex!("e0a9004008eaa972662e17bba49e986dc048e851c7572b4e221fe9be2073e53b"),
+    hex!("52e228b538107bfc874ed3b45e16161ecd627697691410c18f1b61a14615caa8"),
+    hex!("16967effebea76b9c6fc4f52670fa1336eb5b7e09bd5043713bf9a42e0bb6188"),
+    hex!("f90cea7a0f7f62da93c572ce2b1da440355fd4f60e9889e5523215b115f798b5"),
+    hex!("00a985a3a50048b03e4d75dadcf83df59a15df2461c172fd975e72496492424f"),
+    hex!("0f769e7417f3e13508f29b803ac2eb73578e63a32b5712dca6ab544edcf203b2"),
+    hex!("d69875c69264a9525397a9e0a6d0d6fe993e6a4ac2af6a0cbf2987d7cf112278"),
+    hex!("05111d696544cc751f7a1982b84cbf08e15191c00dd772fb095a6cc2927b363a"),
+    hex!("49122e9582e783c2d0820c7c0949e4b0f188a5eb63b049d240703de35b345f1b"),
+    hex!("0a3265a0dcb0779a3f4e9a73bdfcd51c7cb9a9f23ad0a8664e7f2d94986b50b1"),
+    hex!("d0f3d7dec1ee37749fef616444b85838d895bc66767cbffeab70f67e18811bf5"),
+    hex!("c5abeb07f331b96ed491fac62426da77cfb2967f432de4804f1ff08114a210c7"),
+    hex!("47de0e66d4c7f63933a6d31f054f30039e956033ca78d81985246e41f9e7932f"),
+    hex!("907c93df1e9156727d448953925eddf5d47f714d1fa5b7c083c1553c0888a87e"),
+    hex!("b8516c4a4b4cb01becf77f8ece38767eb6f1e10150d5ef2172c2a1bfe9539524"),
+    hex!("cdd60253765188881b8cc35b670a35b6f4b4beb9b9b2e6d34a40dcdf9b778cf5"),
+    hex!("399beac46a12e206199c336b38ca0451832b632095c74d4f064d6a813e578599"),
+    hex!("35164f7bd0e670fe5393277321233e3a22c0c905b85d976d6c659cc74d399694"),
+    hex!("35f6a149f8d677c55fad6fb1376ba21d13a1b992d8d7419556ddc69df91e9321"),
+    hex!("375598ca2e7f8c04b0f87c1e6fb8065ab7be4fbb79bcce227fbf1cfc0c208b29"),
+    hex!("bae449185a984aefc5500e0d5afff4e392957907c491f83cb77f91fe816e4eaf"),
+    hex!("dc64d235629235ab7582a829642e2e530d4a91beca579843b55d4d322d5c4c2f"),
+    hex!("b44db36bc549b5bb883ba9333ea7055a244de76e3bde1e45c7973345dab5d432"),
+    hex!("030bf9a1a02215ddbad53cb23fcd9b2ea6336a25f9fef2334b526058cad4bbb3"),
+    hex!("c15b69972d13056a098c68a44e38262c3a97cfb501f7c4941fc24bd765f14265"),
+    hex!("a3cb2f74cfc3387fe3d96752854cdd29b83f9396cf0b8a54603d0b943c2e1395"),
+    hex!("6baa06360ce3c3a1ba05f35048e5463c66c9e86b9ef4006a5945dd776c3ee205"),
+    hex!("dc24b87c709ed6cb77bf2948db49bbf7bbef435caf0dde045da593b8009a053d"),
+    hex!("75b06bed18a63fa69b802866d8ae8f2efaaefd0cab652b9cdfe0d3c8966af04d"),
+    hex!("13d68404b07d7a22e2e21cc65a634123ea2abb7c1ee2fadc06abe4964e11a5bf"),
+    hex!("d2d359894d3324708cc9b820d0bbb82c05b5d95f4e391d2da6bc76a672243077"),
+    hex!("dfa07821bf84c89221029feb3f0e88576ab6db08516259588a2cc1a9162e148b"),
+    hex!("0d6d3e82391c61f79298ca4a1d7a5aa55a2b4d12b642168975af16b8de793a73"),
+    hex!("de1f64654d5f65da5ee5045109f1a2fd43b717f79726ac3994e19396990189a5"),
+    hex!("9459500e391a47055e9a4833e8727e77dda910729dc444b75443735a26ac7e66"),
+    hex!("4ebcae9a5dd8c48902129021d96ac52f8792d66e07478c4545dd6493ed3d1db7"),
+    hex!("6c7b1fd9476711fd797ba4d28372ae89c52072bf0616ac9fb3114cb4e0d086fd"),
+    hex!("b010af5c31d439b051c2568784eadb58eb830b313488c59a3add628151782c73"),
+    hex!("fb1aa79e93a22730df344624908eec55d876e6a54a18e013d7ed3dd8f5e410f6"),
+    hex!("77ee5b13907642b70171d5f4e3d16a0f07a5b8d5adb25ee5374c19847e04b10c"),
+    hex!("2a54c0494db054d8088dc5505625dc8bc894f6a66fe0c28db7672f2444a96bda"),
+    hex!("d95221f93398a91f8195a8e38bdfeb00a13a5d0e468382a6e369d584210cdf22"),
+    hex!("764733407673302a61c27e83cb274c5637a18289cc7cc294a31ee1dfc7191d5a"),
+    hex!("61a86076cd94c47b24bd8945dd757ee31395e8ae8215be243974a0ad6a35dc97"),
+    hex!("22c9c3ae82bb8590fe8dd7834a3bff0a35000dc4448f71d9ffb6ff6f891eaee4"),
+    hex!("89b5f8161e451b893518f552262bf0df558803a3e2ae3934e7d583c393e62d39"),
+    hex!("239fc3e466fdd16530591d148cfcff85b562682465c7185774605f91e2e24aa1"),
+    hex!("2b626d269e27750321e7c9ab341568234248c2ed614c37f3464e7cad52489a9f"),
+    hex!("9d249af4dd870c241eb41a0375a54a62771238c0cd29017a6d6b05a3641b34d9"),
+    hex!("57e349d63b4d485622012ff3f14877002fe8bfc3f7e7886e100e95af856fc223"),
+    hex!("55c90b75a757171a2e1a40cedd80af830b4d432384078294e510dcb70b457d76"),
+    hex!("04e7cbf59dc54e65b3683d7a99c62b3018297940f5ac318223aac237e3b202f3"),
+    hex!("8bc6177fcc9a99b11adeef506d16740e58b28e546d2015460a94c97ec3a66a4d"),
+    hex!("3c5e86d7fc295a1fe110f0be3cd9309bc9b6288bf3806c654c42570660379c33"),
+    hex!("07ef08ba43fa2b1ebfa7e7d33d426b2dd4fd4675c4d1acf6447180c08c9f4a52"),
+    hex!("bd1e2c41bdf8e2354687315b5f0979cc7152652edbac86cdecf05f5916007099"),
+    hex!("532542dd5acc0f923ff60828bf1699a29c8413ed6e80ea9bd03a881fffcdbd5a"),
+    hex!("e386cb19d462838a92700208b0243c39524fc4d2a5b65d224a417e2cca92790c"),
+    hex!("a9c2f1756a8b03b84be7fbba8cbf9b2f8b0195d94ea3f873b3c933d94e0ce7a3"),
+    hex!("bf16aa0f4379f00f5aaf67bae5f43a8b675b666e23094f5dee77adb7e8e7b586"),
+    hex!("eac01286fc3e9ce61a1f41ac89eced1bfe2871ae5ef61d6facd781ec9b875eb7"),
+    hex!("b422ef1b11c22f1821cf78d7f3285d2da9cb672b56e887d8074c3a80f43d31ab"),
+    hex!("f74420c7892f218917e9eca32a4cae69c4ab8924ff6dc20ef15a9f9298dee5be"),
+    hex!("ab47380d2e229b7b9044ef885a56a026aad806dce1084bd2785529fe74ebeb50"),
+    hex!("2879cdb9ab7b8192cfd47c02b0192b35187155a3ea24dc52edb65530e9b9e4bc"),
+    hex!("3b67e0cf983184e8eb9b08554a7f66a2e566b4821a5794bc8bcbb32828c37999"),
+    hex!("343c9aa612043da14be48d107c9c9f3dbbadfadfed218bd66e191ad93cf00f18"),
+    hex!("0c4b003f54668b48cd3b2718601a7c73151fd89500f67a8a6872b0a29e0ea75b"),
+    hex!("6b629434300ae7e345730f3348696b2b8dbf52ffe0831457dd6f3f9f7556493a"),
+    hex!("97b99c599e8af2b2b7f640ae4ee472efc405cb19b118dfbead862421051d3b06"),
+    hex!("ea6e6f3b98ac3569abab315f06a793bc6f91d4c8dc4aa9c5b61ff5d8122cccd3"),
+    hex!("0deea0ab51980f6eda2a2b6a4d5d358f324bc85dd5fd5009850d53a5caf51cc0"),
+    hex!("d23f5939c015d1112e5b5b837dfb9a08c6b997f47fe9a55fd54b8681afbec50f"),
+    hex!("ebcc2f5f7065ced1baf6edf7d633ecaf0dd68c06d5bbddb9ecf01b7c66a7ba64"),
+    hex!("15039702658d1da3560e78c19df983922b5eec25a498beb1a07a7301c2f57a09"),
+    hex!("0a94f5da2ff4ba4bd7db35980b9ab00d1c0671ca23c3af18f4293948f637b826"),
+    hex!("b476b7a5c1e20c73d8a0ee1c140a5f81fe96effb103148a0bfa2c8c8e7e1c808"),
+    hex!("28f4c6b47e685e34314e42851cf35c63dade1979f4929a4288f42eb2c948bb5f"),
+    hex!("f81512b0887596fdb4784df75ebaa31b5965b83130fcc4fbcf60e9a4b7e26b8c"),
+    hex!("9899f78d41d4c482d9b6cde1255c7623e960941c9a3850b51e40ef480a5b560a"),
+    hex!("2704c6c23d422312e7ee87b54f469260521e92aee9f882747a72326e18c4117b"),
+    hex!("e338a0cd443493f9ec588e5730c944246016dc409ae70bebb0ede7bf2dfb09e5"),
+    hex!("031026cb1eb483488ed29a8f1a39780b6106f9ef6f28657d573f1ee049620f27"),
+    hex!("d0ba88634483988af2e47c23a5fabb8ab44aadcafb9c5af05e0a3c431c0f641e"),
+    hex!("62df3e47e00dbd75509b1e34b2bef8ee38860f0442631f3dcb8439a03793e857"),
+    hex!("a6b8cac05432cc4387c9d53ffd79652ac45553da191ddf8facbf4d0293f1e01b"),
+    hex!("20960cacf0a9be4f4ff601b37617e4afc8b76ee188e731c7cbab21724cbdb569"),
+    hex!("6c296c021013b006be47722ff12fb28c24b552ccd92f5bc79b85543261f1773c"),
+    hex!("3a98c3459210125a403a8538d84ac434efb871700cacb53207bcf05ad7a999e0"),
+    hex!("9bcd179730e87e5e0f532c5bf433f0962d42fcb55655ad3a1a3bb6d52069e54a"),
+    hex!("4f01da6d7b7adc9710eb3be5596293c06a846b660e087bcf31a0f8c11170a74a"),
+    hex!("b436e4a1224b0d96e3aa06f67607e30b1483c94ea864303e4c53a4ebc0574272"),
+    hex!("32ba7d735143a1006c3c31d785ae5276ca5d58da0e53c8b0e68f8124002d9fb2"),
+    hex!("8e1fb34ce0527470cb79196186a5b5c36a4f6ca1c9161dfafe8cd81bb8c20d7e"),
+    hex!("e03193883c936dfb58313d2f7981db621454b3e22f3d2d9ff6fc19818fbe5e18"),
+    hex!("3f20d398ee36ca3b0dc343b3e2ca15ed6a01f4c91d9da65a66bb74cc44bec80d"),
+    hex!("ecb08e5f5bac576e184561e6763768f7de2c1294127d7d77cab2c86424c87406"),
+    hex!("7dc4c729aef556b109c9b93f51e51f6f6e7aab7ddff2d9fdb86ce3784a6176cb"),
+    hex!("3c3a0f04b058b1c0a1877f150301e05e71341ebdaf4e56300626271e0d1b24d7"),
+    hex!("8b541b1387f061416b5f45f05c097327e113931bd2954ee20727d6fea7f54aad"),
+    hex!("a5d54d7b852a0048db9259b5f7bcf65a72cf33998d530e5ddfcfd8ea3a47402b"),
+    hex!("059f4df11a29274458400409a9d87e4c5b27b2de600ff16fe7fc39a9982834a7"),
+    hex!("aff0828a2e1d99f2d074a83cca9e9595aea080c1ed3b52b5456fd5aadd2cb947"),
+    hex!("5a7833af5329d9e38272f8516d00a1d083c561494492f851101cd467321f7f38"),
+    hex!("9af40f8483699672b73dd9438f6915a6a233cee11f49519f7d3534296fe47848"),
+    hex!("2348811c39cd1c871b47d837e929bee216a3abd96a3be24d9903fa09c8c442ad"),
+    hex!("79b745a87eba62c8b1c1a18140b1fe18cc61c4a146a37901dcb743eea0769de1"),
+    hex!("05dd88439d86642f290686b97a6fd60bb4225f0e7bff926a3bceec221b9d8a82"),
+    hex!("137b9a9e470c3b06717237632442c83dbccd3eca49bebdb4540b5e33e43ac070"),
+    hex!("69b3cf3601fd4452f4ff3aeef45d26bb62e7663ca547491c39f1494c02a25c88"),
+    hex!("873fd55df4da3e06d09679f1ecbe8aaa77fcb6c4f4ae99ff1cbf8edb6bbd96fa"),
+    hex!("0b2f49cf0a1fc56ff392393e08f8bffef801615c82344fee22ddb5082f62adb6"),
+    hex!("9168b4511e9537bb299d32958d4109ae770971460e01a45d5d395159eef056b1"),
+    hex!("fe6c11664a585d69caf9357458fef96792a6909ee90fa841938f58025acad026"),
+    hex!("6c2c36d0106d6592a892b3cbfbf766295fe91331a18cb5c6315772a9836f4184"),
+    hex!("5110e2c4d8d35e5822acf5ec4b5503d86969267a1d1f2d39e363ebbfac92ac74"),
+    hex!("f3088ab14d39eca2ace8fc2ab89d173f13366bc06a39bfd9116306c94e9d2262"),
+    hex!("eea62d8886cc361019dd3ad4070e08adf8152d74edfc6a4687aaaacbcad61541"),
+    hex!("a7df40d1ee42db62f7581be51d31fc85e38f1e0ad1ec263b439738493a7cc404"),
+    hex!("cdcaf30f75782f2860f67e450a3fb563b5d9fbecb4254fdc5b05e3c1b1c081cc"),
+    hex!("1f398649a0d54e3a912ff9c4205f2b3b847c79d52a270ef1a883a719295808c5"),
+    hex!("3865ea7d6e3e924127e874af9fb45258b53a041837baa379796c1e54d97684e1"),
+    hex!("0af90cd4248734f03499f80b1b715ed8061d8a7f9fcd2769dfc269fa6c5f57a6"),
+    hex!("f8ba30f53151bbc3b9d8d36b2afdcdb50e2b1c55e1109d2901a4d0cf1aa949d0"),
+    hex!("f274a82c6e7f52300d3007e021994a615a97ee43cca61fb58947d11457bf1527"),
+    hex!("e66151ab23efd77c0628dd789ad6ed04cbb1eb5fa9728d0a83475fe360abcda8"),
+    hex!("9f6c88dfed0575651f6b0d3c9996eba836e34771cc1c4a66dda346f0f76442bd"),
+    hex!("a71c517201554ed5d4b7b3ff532b6c9acb5cad39bec5142516ed7f4730545782"),
+    hex!("f0c2b99e1eb17e4e376b063876753f6e4129aa7544bf45fcc587d7e6dca5c6fc")
+];
+
+pub static SPARSE_MERKLE_TREE_EMPTY_HASHES_KECCAK: [[u8;32]; 257] = [
+    hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    hex!("ad3228b676f7d3cd4284a5443f17f1962b36e491b30a40b2405849e597ba5fb5"),
+    hex!("b4c11951957c6f8f642c4af61cd6b24640fec6dc7fc607ee8206a99e92410d30"),
+    hex!("21ddb9a356815c3fac1026b6dec5df3124afbadb485c9ba5a3e3398a04b7ba85"),
+    hex!("e58769b32a1beaf1ea27375a44095a0d1fb664ce2dd358e7fcbfb78c26a19344"),
+    hex!("0eb01ebfc9ed27500cd4dfc979272d1f0913cc9f66540d7e8005811109e1cf2d"),
+    hex!("887c22bd8750d34016ac3c66b5ff102dacdd73f6b014e710b51e8022af9a1968"),
+    hex!("ffd70157e48063fc33c97a050f7f640233bf646cc98d9524c6b92bcf3ab56f83"),
+    hex!("9867cc5f7f196b93bae1e27e6320742445d290f2263827498b54fec539f756af"),
+    hex!("cefad4e508c098b9a7e1d8feb19955fb02ba9675585078710969d3440f5054e0"),
+    hex!("f9dc3e7fe016e050eff260334f18a5d4fe391d82092319f5964f2e2eb7c1c3a5"),
+    hex!("f8b13a49e282f609c317a833fb8d976d11517c571d1221a265d25af778ecf892"),
+    hex!("3490c6ceeb450aecdc82e28293031d10c7d73bf85e57bf041a97360aa2c5d99c"),
+    hex!("c1df82d9c4b87413eae2ef048f94b4d3554cea73d92b0f7af96e0271c691e2bb"),
+    hex!("5c67add7c6caf302256adedf7ab114da0acfe870d449a3a489f781d659e8becc"),
+    hex!("da7bce9f4e8618b6bd2f4132ce798cdc7a60e7e1460a7299e3c6342a579626d2"),
+    hex!("2733e50f526ec2fa19a22b31e8ed50f23cd1fdf94c9154ed3a7609a2f1ff981f"),
+    hex!("e1d3b5c807b281e4683cc6d6315cf95b9ade8641defcb32372f1c126e398ef7a"),
+    hex!("5a2dce0a8a7f68bb74560f8f71837c2c2ebbcbf7fffb42ae1896f13f7c7479a0"),
+    hex!("b46a28b6f55540f89444f63de0378e3d121be09e06cc9ded1c20e65876d36aa0"),
+    hex!("c65e9645644786b620e2dd2ad648ddfcbf4a7e5b1a3a4ecfe7f64667a3f0b7e2"),
+    hex!("f4418588ed35a2458cffeb39b93d26f18d2ab13bdce6aee58e7b99359ec2dfd9"),
+    hex!("5a9c16dc00d6ef18b7933a6f8dc65ccb55667138776f7dea101070dc8796e377"),
+    hex!("4df84f40ae0c8229d0d6069e5c8f39a7c299677a09d367fc7b05e3bc380ee652"),
+    hex!("cdc72595f74c7b1043d0e1ffbab734648c838dfb0527d971b602bc216c9619ef"),
+    hex!("0abf5ac974a1ed57f4050aa510dd9c74f508277b39d7973bb2dfccc5eeb0618d"),
+    hex!("b8cd74046ff337f0a7bf2c8e03e10f642c1886798d71806ab1e888d9e5ee87d0"),
+    hex!("838c5655cb21c6cb83313b5a631175dff4963772cce9108188b34ac87c81c41e"),
+    hex!("662ee4dd2dd7b2bc707961b1e646c4047669dcb6584f0d8d770daf5d7e7deb2e"),
+    hex!("388ab20e2573d171a88108e79d820e98f26c0b84aa8b2f4aa4968dbb818ea322"),
+    hex!("93237c50ba75ee485f4c22adf2f741400bdf8d6a9cc7df7ecae576221665d735"),
+    hex!("8448818bb4ae4562849e949e17ac16e0be16688e156b5cf15e098c627c0056a9"),
+    hex!("27ae5ba08d7291c96c8cbddcc148bf48a6d68c7974b94356f53754ef6171d757"),
+    hex!("bf558bebd2ceec7f3c5dce04a4782f88c2c6036ae78ee206d0bc5289d20461a2"),
+    hex!("e21908c2968c0699040a6fd866a577a99a9d2ec88745c815fd4a472c789244da"),
+    hex!("ae824d72ddc272aab68a8c3022e36f10454437c1886f3ff9927b64f232df414f"),
+    hex!("27e429a4bef3083bc31a671d046ea5c1f5b8c3094d72868d9dfdc12c7334ac5f"),
+    hex!("743cc5c365a9a6a15c1f240ac25880c7a9d1de290696cb766074a1d83d927816"),
+    hex!("4adcf616c3bfabf63999a01966c998b7bb572774035a63ead49da73b5987f347"),
+    hex!("75786645d0c5dd7c04a2f8a75dcae085213652f5bce3ea8b9b9bedd1cab3c5e9"),
+    hex!("b88b152c9b8a7b79637d35911848b0c41e7cc7cca2ab4fe9a15f9c38bb4bb939"),
+    hex!("0c4e2d8ce834ffd7a6cd85d7113d4521abb857774845c4291e6f6d010d97e318"),
+    hex!("5bc799d83e3bb31501b3da786680df30fbc18eb41cbce611e8c0e9c72f69571c"),
+    hex!("a10d3ef857d04d9c03ead7c6317d797a090fa1271ad9c7addfbcb412e9643d4f"),
+    hex!("b33b1809c42623f474055fa9400a2027a7a885c8dfa4efe20666b4ee27d7529c"),
+    hex!("134d7f28d53f175f6bf4b62faa2110d5b76f0f770c15e628181c1fcc18f970a9"),
+    hex!("c34d24b2fc8c50ca9c07a7156ef4e5ff4bdf002eda0b11c1d359d0b59a546807"),
+    hex!("04dbb9db631457879b27e0dfdbe50158fd9cf9b4cf77605c4ac4c95bd65fc9f6"),
+    hex!("f9295a686647cb999090819cda700820c282c613cedcd218540bbc6f37b01c65"),
+    hex!("67c4a1ea624f092a3a5cca2d6f0f0db231972fce627f0ecca0dee60f17551c5f"),
+    hex!("8fdaeb5ab560b2ceb781cdb339361a0fbee1b9dffad59115138c8d6a70dda9cc"),
+    hex!("c1bf0bbdd7fee15764845db875f6432559ff8dbc9055324431bc34e5b93d15da"),
+    hex!("307317849eccd90c0c7b98870b9317c15a5959dcfb84c76dcc908c4fe6ba9212"),
+    hex!("6339bf06e458f6646df5e83ba7c3d35bc263b3222c8e9040068847749ca8e8f9"),
+    hex!("5045e4342aeb521eb3a5587ec268ed3aa6faf32b62b0bc41a9d549521f406fc3"),
+    hex!("08601d83cdd34b5f7b8df63e7b9a16519d35473d0b89c317beed3d3d9424b253"),
+    hex!("84e35c5d92171376cae5c86300822d729cd3a8479583bef09527027dba5f1126"),
+    hex!("3c5cbbeb3834b7a5c1cba9aa5fee0c95ec3f17a33ec3d8047fff799187f5ae20"),
+    hex!("40bbe913c226c34c9fbe4389dd728984257a816892b3cae3e43191dd291f0eb5"),
+    hex!("14af5385bcbb1e4738bbae8106046e6e2fca42875aa5c000c582587742bcc748"),
+    hex!("72f29656803c2f4be177b1b8dd2a5137892b080b022100fde4e96d93ef8c96ff"),
+    hex!("d06f27061c734d7825b46865d00aa900e5cc3a3672080e527171e1171aa5038a"),
+    hex!("28203985b5f2d87709171678169739f957d2745f4bfa5cc91e2b4bd9bf483b40"),
+    hex!("0a162946e56158bac0673e6dd3bdfdc1e4a0e7744a120fdb640050c8d7abe1c6"),
+    hex!("e2c3ed4052eeb1d60514b4c38ece8d73a27f37fa5b36dcbf338e70de95798caa"),
+    hex!("926bf520e7f453db475da42b994d9447de1f93ee91502a64748e371ed0d1207e"),
+    hex!("7516de2f5995cfafacfd70e1a2067241740388d324343a1eb5e71a10f6bb3298"),
+    hex!("ea5466fc04d601ab583158b9c6626cfbb66640d7dc229afaa59fd52ff415180b"),
+    hex!("806f45e88b008f79b47585bdf322be73560cb09d6cac65534e8764bae68a607e"),
+    hex!("8456107723bf83d4a67be05860c6730f8540e49ca0f515344ca83a076c622ff5"),
+    hex!("9837f794827f98f048587b3f2d2b67817b34821097cc60069dd15d62ee52273d"),
+    hex!("0cfbd2781f72955df5e33fc4b304fc362fb1637974575f166768f56da09b1fc9"),
+    hex!("98fdc3f9b823005b507065b58c622aac45efeda41706775668b37a230d8aff9e"),
+    hex!("df0b5e5db8973fa136e3fba2faabd16677494266a1bdc6b0a8dd7aa187b560b2"),
+    hex!("e67efc7167068b5ca5fddeecd68727dce6b03962f356df86edf32581c5e2f142"),
+    hex!("ac692525a14b469fa4c0069eff411001aac6c42d3438f043eb9c3115ec79b546"),
+    hex!("420f9e2a54121eef63530c80909eaed61fffacb8af31074df795e8d16d9d77c0"),
+    hex!("572b220bc6da4cc990acf6cb2e3c8a426408cb90c1298e3869cd55660625b2c3"),
+    hex!("3c2dd835b3f37d72592ee76ebced8b1a15310b824a0d62409263f1594da52171"),
+    hex!("10d9f6bbcc5fcd9cf7a8fcd37a14dc7d719fadbc7f75f98ebebb0719397c50c1"),
+    hex!("3324b6af8c3c1a134906345824ea56115ff5d46e863394edec5b4e6089e99d95"),
+    hex!("d70c6460f498b10eb3a4e739e18f8ed10110d89fb35de350c1e07ff7a300f9fb"),
+    hex!("21dfaa8164b31e8d7c6dc1e1d29344001be3ca30c6446b8903f5476553d94c63"),
+    hex!("0d1064e0c1618c57517fc16a4c2f360cd9089464794d8d907c85d0a286a02da6"),
+    hex!("3c29cc1006a2062d4ac52af124a42a0d937f120260434eb3b234c74fde8256cc"),
+    hex!("ba6bd22d33b9fe2010eecc7663983e56d39a9b3a6ef9ba7ad252ad47bb3268c4"),
+    hex!("e5aed5fd2336862ff33e1648d0fa058520dbe003049dfe2433d6f9e3305aa08d"),
+    hex!("7275275a0ea97bde92cd92fdd57d472b4db985d2814951c88373f3bbdca84131"),
+    hex!("17e6b3aa581f972e331445b8c661894178ae8852201c8df34706d1dfb6c92f75"),
+    hex!("aeac70a85397030d84193a54a6e596750e6ab3926b530c127a17c9e24b20b8ce"),
+    hex!("bc12436ba2b2cee8586cc4714522d1866fa0e949bde42ad7d9645cdd8fe58ba0"),
+    hex!("49c40fe4834a5e78ff1a24b37b6e40119f520de73a0cb51479d91d73cdebab8f"),
+    hex!("d8df824c861814311dfc864f00253ace3f6745cbc9562806186f74ac957857c1"),
+    hex!("dbf0c1a5316e43762e4a4ea443b9458e97e8a6e7991421bb2eb1f8f346804d2b"),
+    hex!("a94c80c79017fd7ff0e134c5bdee69867a580cd0aff9f1d67f36a9785c02e920"),
+    hex!("966e6f6d7f644467cfe28dc9f9db78d5a025ad5117fc5641184724590e7654e4"),
+    hex!("929bdd7feee9229494a3eafcc2eeea3a5f51db20f26fdb8a9d13344534d8f1fb"),
+    hex!("81b24f93bde1395b538944e740855d144d996fcebaab447637fb47a7e2b74e20"),
+    hex!("04fa584f5a852d22ed2a1834964da6d267e7bca3d9f3a9513c6c077fec5a9501"),
+    hex!("847eb542c8078dae0f4195c0fc6f00341851f26f58963efc59e51c42bfe63bfc"),
+    hex!("1c541f19d04dfaec842b7d790b185904de53503ea4cc6f78fd3f17549082a6ad"),
+    hex!("1038dde7a37f37bab144466ee29afed96179e9f324aea58d4fe991036f1e2bee"),
+    hex!("7ab4e529bcc484ecfab23aee965ac0c34da462d314b34271a5d54768399d7582"),
+    hex!("58c31c137dc3fc670a6a08bea893f6340e80ad7d5f744c90d87f6fac83ff4410"),
+    hex!("1c6457dfbed1d1982b4303e9c8f2aa8efc3b36a0b73b24007503da3936b9643b"),
+    hex!("af04efc3d21fa0dbd3d0969859f456be53bc855d667153e96a1077c258f5c47c"),
+    hex!("f64f3afe4825e212efd8d5a09b38c24907154b962e87c13494453fe84360f5af"),
+    hex!("2215baa129243bb2489b96eb079f9fc52ffc5a75fe44e4dc5525480b08cec100"),
+    hex!("c016d66a19e8c03a88c7d2deb1c2266264d0def276668c1530e0b4d0797f5bbd"),
+    hex!("21537b0813609408495d2da79242fd95350051c055b282e880251dc7ef2c8604"),
+    hex!("ce0e1a1ebd2dbf3788e4629d9edbe23d0320595cb4a2259968bd183fcfe784f4"),
+    hex!("4e31c84801b7c30a7f6a117b9409ae826a2d8ad0856e90e5325c02a171fd406c"),
+    hex!("f50bbdf427e8002383ef989df23898c8fa2ffd6cfbcda75d9c0f388b97b18a37"),
+    hex!("cdeba8b83fb1b00ad0ea7a73d33d5bfc63abbd0209a3a25fc1c6f612fccd4b9e"),
+    hex!("ec750d05b6ca921b7dbfc5c80e427c89d2a0746ffe884391445eb58700548374"),
+    hex!("86d55a186b3d9778b312e59bf6883902072e078e05387853e6a9daae02fa0cc3"),
+    hex!("393908f0cf05a59f17147046a51de9e3378b8988b777f97cd48fbbd76b4d302f"),
+    hex!("bbe6e8eeeca8137f1bffc740264e3f51078373a74aad94bb7e06318d0470fc17"),
+    hex!("af787d9946676534a6ae3b9052a019e2ec315ea1067c0ccfbe02d93c286c9082"),
+    hex!("8a9cd7b8b7fb4f8bc6892dd9862063dd8049c7a2ba1869e917ac4058c4fafa89"),
+    hex!("962bf93a871bae8ecca539235fd64e11d25ff2892c6b5696984247ca1a06ea6f"),
+    hex!("a61932131d5b86a81122ca2d99d9e8ca85a8823d383e4dd529ce6167b39b1b7a"),
+    hex!("0ffd5897716f91d481cb2fae38c1a715048d24f49b4830ed6e0f38df400aca73"),
+    hex!("80c0f853e1e11595f38eb9ce932e81decad7806b6ce626e64664c63d0161300f"),
+    hex!("fe89d1594b8077eda30ce89ce37de5d40326d13fa59af5b65b056d637086493e"),
+    hex!("94aa8010138ccd8527712e6ba00df632f1cb3a26f8bb1a0148394b4da26b9389"),
+    hex!("b5770e44a24cf2e9c028441c87df80f9cdd26899f2a95d149ae42422491261cf"),
+    hex!("ccb2e65c2b007fc48a929459b9e55c7cae243a1768db34f563c2f900d9a4a8ad"),
+    hex!("302898c897fe6821c3034bd415821ea249af1158aa1e01223e9f2f7bdabe2a91"),
+    hex!("e2e77f6be80bc97c465771de809cd1d51d18b0c683396f667dc345a80a013d2f"),
+    hex!("f23cc0a657d3ee03226073d99c4bf36f87579727aa1dde29be3b56081c923ecf"),
+    hex!("3722d7964d96ed3cae69957ac1b17c98ea779c761a4fb85854324839a45adc49"),
+    hex!("9dc68abbb997d022d6856ace0a1ac4658c6a50c2d49f0364122de47b674b6987"),
+    hex!("1b6e7c1bb16fcbd954fb343570f8af720ef19c99a4fd9e25385e55adf44c5479"),
+    hex!("e0d3d80775c56a2c2f851a5d85947481174365da46141c42392743675d6ecd3a"),
+    hex!("b5249b4847705e8d30ab13298e63fe8235be99b919a8227e483ef09061897d5c"),
+    hex!("02e85cd2718f3c51347a437a639cd8bda8012a03116bfce7bc2527a1b4bcbe3e"),
+    hex!("d35124787675617fe78a0d0dc3edb867fe65b9e5ab21bcd397cd8cce804add64"),
+    hex!("c776a4515d2ad878e0efca9b85d835f72d8b4993f9d811d6b166ab5bb9fc8aea"),
+    hex!("b7410701f8479f08f86a2e0b4479244ff3dd5387d58a703f9a015fa4d200d89a"),
+    hex!("8b22cab77687d11e22a9a8a1435d8472151e02a58f76dfc43b72b251d171b88e"),
+    hex!("dd401defb6e37b2cf2a4ac58cb3243b951e4961cee4533bded902fc7a4d8e13c"),
+    hex!("eb9b3ef37f57873243293af6b915c758f5d554efeec10b4aa6b3ddd4950d2e4c"),
+    hex!("1ee5f653417fecfabb729e87549d52fa9c57d04f7aaa5227f751aa80b8dc8cf7"),
+    hex!("724122379fae39a109c8fa30ff3eb5dd2ee9a1a845fd9d2535acd55657410bee"),
+    hex!("db2eea906c679da20dfaaff3ee92905f9aafa3a7b5a2c01f1f685e362b58acb6"),
+    hex!("e6d4940f6ffabfb754cd67588efd188645caef892949bd5d668258018b56a166"),
+    hex!("7e636ee3d2914157a39963c308e93a492ea86cb782d96e47773775f544cbbed9"),
+    hex!("75921e88680163d20f21b0c5ff61d1b97bdbc6d5c69e746090c2bc6cc27d03ed"),
+    hex!("6e961332a631a45900d5c2111360dcff5631b58c0fc3d3ef8e2d57fed5ca15aa"),
+    hex!("2cceb4b84ce151df2873c66c6e18c38739a46d06b75541af46768215b6d50de9"),
+    hex!("a81341fe8aee600370cc4852b1008aaa846e58ba6356c096e2df04cd976f8e46"),
+    hex!("615afdde2756b92ae1012be3e0393856457c9def83fcf93bebc67f6e9d8ac16c"),
+    hex!("2566e509d27fe0c00ef2c3f76571a1de60050d20956554ccee77800c18862848"),
+    hex!("83a8a736815726b51d74b5e17aa40cd190dc8df1fea1d003a63d383befb78c54"),
+    hex!("3fd9e153ba4a11b304729c10f1aadb2c1ce9107dfcbc116a6386bb232af67d51"),
+    hex!("48709af2d94bc10e73010cfbe7c4f6d0c2bab4ae8b47e4550a61395406c45de6"),
+    hex!("c57c3276264b9553da224c0d1a17b68b3c56a4b15662d36e10a35c69b17667ce"),
+    hex!("23d988b412458381a1e7cc4694c7965079160b1475402094e36c5efcb5e2f909"),
+    hex!("09dc157ba005a4ddffe637c6acfdfbf00dd6a841bb68b071b10e780aa40cf85f"),
+    hex!("8263f9ed50c782f009a566c2b39d8190060b943a72eb5a293295de5781eb3f97"),
+    hex!("e98d07db4c54980be0d3163d829aba97ba0021f92c014bfd75073bda8cbf3135"),
+    hex!("df0ea686d3be86feebdfe4e5735f5e29f873dcccedf032dbe87ebb4ce288e7d2"),
+    hex!("5dc069939571e98953968137491ae284cbef1c8568ba52a6caf16be6686704b5"),
+    hex!("bd0b16f1fa0bc9347890e62b042484a96d935c3f4e4fe26f978a9fae857b29de"),
+    hex!("493f8e90be01b85720fce78d9a0b1723f9207fa40a138a289c2c874f77d44193"),
+    hex!("7073ea24f05b6e6bea9ebaa3fb1d09a27ec6be64c7b23ba47ea31539b7417457"),
+    hex!("7eaf8beb1144577bc002a8206905fca6cb9a92d4669a5e0a2b3e3ec3fec92639"),
+    hex!("bde92386815c070dea0667413a08310509d7c9b28bb1362e27b9971690e5c0c6"),
+    hex!("eb16656865d318680b7b0d5a8561d3dd6af56925970e4c9a4c267c81c3b636da"),
+    hex!("cb6bf96fa19de91de4b5188cce06be2939ec4451e1873e480d1e07bd3dfe918f"),
+    hex!("ef2e80422d46790a0431ee33d8aa549d6d4251b1d664c7058b45068c3a976e7c"),
+    hex!("9f4c26b40157d61dd2a2eb64f6a74d655250bc0647527b20216d836a7d48bc45"),
+    hex!("3d861614e2070c13bb97de44f75972ecd5d81e2025fe7127e6ed3f9a2f992c96"),
+    hex!("6925cbe00472cc50784ae9417c15e50c0bd3085b555ffba9160cd56ce0e02ff9"),
+    hex!("2c07ea3527207923e96a912c2dcf391b1e3886b22119b1635c2c2d62cc61495f"),
+    hex!("46fe259ea57d17992fc658f80a94aa2f37b63e6095de918ba699a8fae64fcd2b"),
+    hex!("70fc67c42f2605058653e35656c960c69988e2d9f818b7d498a924c31a6a0d16"),
+    hex!("9f13f19042828f79c17800f6dc624ac76382de4dca5e1948f15e101e4d6c96f4"),
+    hex!("448b7d43a674b893bee7f4bcaf22eb92b8f13eace36cb34afd568a472d25135e"),
+    hex!("dbcb85bd4dc1f4260bf7afb24b264b25f63963d84c1937687ec9a384046af8b2"),
+    hex!("5a9c097d835e29c20a05ebea6b7f23f5e2d1263eb1bd93779efb871e4315d1f7"),
+    hex!("dbed462b6a1b14ea3409d192cb4082cbeff3687a3c0fda1e839ef5956b3c2346"),
+    hex!("cb56b19a815523df24117788d9792fa68fe011ffc3a5910e998991038ecdba36"),
+    hex!("a13e3f556f0fff4fd35c0698d8760015d6eaa6227aec56d3ff68ef223c6947a9"),
+    hex!("eb0ce72e31fb969c4fd010bcea7af9af01685e198cae9b13bd38806166007ef2"),
+    hex!("064162cc07cb6234137fe3ac38224442b26d767c950ac84388a715862a5ffff2"),
+    hex!("67d79d88b9218ca1b4e7d7c178aea170fc0edd4afd6a264698bfa80791d5cd71"),
+    hex!("a28270f841b92dc8698ed5613ecaf11f5d577c023a6803959bdb6a60809273b6"),
+    hex!("447f12f05a198a6f28afea20d07b4ae3fc50afc212ceb0a5d11ee087d6674346"),
+    hex!("eb034ebad3d82683cf594e9971a45b6b67d19c9957086e08fae41c3150487fac"),
+    hex!("70425fd0b7663d80ec9605b7474ee594be89418423dab28378e6c87e666f28b0"),
+    hex!("b89931f7384aeddb5c136a679d54464007e2d828d4741bec626ff92aeb4b12d4"),
+    hex!("7ce243233824b0698d95f4ecb3497eb5a8df4435b6eeacde2315a027f00ad78f"),
+    hex!("c0aa37aeb710e65ae422652f38f11f8070f901005192b8c78223ae379b48abeb"),
+    hex!("6f254bc1e076a7df76784801dde0f19c0e505ed737e3e757f372d2d47fb252da"),
+    hex!("423347221fcd7574927ba888aea064acbf414372d5a62ad1e16117e3993cd677"),
+    hex!("673babc2accf35a04ffc95b876350213acfd7a25788657c1d0389341a53e2567"),
+    hex!("3ffb44d37de9dcd28993916ae580f70d6106cf4e70a4d34bc6648c7ba6771ba4"),
+    hex!("9332824ef368c2478ce674df35843faeeaceda4d677657248e666053ee01fb13"),
+    hex!("70c1f38a299f77e14d6a6c6e0afea94c32441639cabfdea10015c9af71ebab82"),
+    hex!("061b9f4ec1eaacc79cee97ac6014742dd382aebc463c01b87b281023a5be9984"),
+    hex!("3102780a3dcaa2d4fa9f80fb2df8d93c1f78b8ca6bf42e7064c61a7abbedacc4"),
+    hex!("4c03cd8bcc2fd100b9d55cf1734d0610ba2e8c77d520ee122809094f9aea4a25"),
+    hex!("0e249488cc9aabaee9d513bbdb623c78a72734f490d22c4cce73b36ab7af46b2"),
+    hex!("2b0108a7f4d9f1b5d6b5868d0bb49cc4efe01c0511efb9fa6cafe2630bbf8e7f"),
+    hex!("f4860fc94c5e4043f504cbe0c87d2e5fc8fd6f0ee2c730a8e5fe5eac9ab9b4d8"),
+    hex!("06b9194724bca5212ee2fa67d9b3bade90581e82c337efba29575e76588483d0"),
+    hex!("8cfa68a2f4d315a70c88917211d67e7d5d00e8d7540d1593d1588b044a708cc6"),
+    hex!("bd16b756d8984859394b076a600039e275042dd6f4f1d83ede4d6cf1eeeae0a6"),
+    hex!("49901548b8f0b385f5d4b66ece4d86d7088d31874acf74a67398a5ec5442d421"),
+    hex!("5634987e436260d9655bb52076ce394d7507827d7886db3209d8a43b625a8c43"),
+    hex!("0fab9c71fa94b034ff7829f8e5143571df082264a8b10f84e02a1bf7cd2a4da5"),
+    hex!("36b146637084b907e9a23b454b203658e9820941af6fe2c03a2f5fcc3210a69f"),
+    hex!("9ade6c91a2dcf10807dd96e28c81fe6bf14953fb0fd1451bcf8084c3ff1e3074"),
+    hex!("d98ea666cba629938bca5a6dc70dc6586a4211d7890f831a8f5f74e1316a0352"),
+    hex!("e567ee0f0510cb5bd6c5c3c256109863ac27f4fbaa2b04c716443dda47439f19"),
+    hex!("5a0b689972d503a757da449ebf2a649ff22d96eac16371023864bdd28dead6ba"),
+    hex!("638602355836f79873347ed2bb035cdb620191e66b07e169c02bd4efe40034fb"),
+    hex!("3de19a51923bc39ebd7967e173c1ef75c6ce263277650672892cbecc7f08ce21"),
+    hex!("623f203318c1f61436b7ee9ce4bdb946f79c5f305ab79e0db91c311451f6c90d"),
+    hex!("3dc3713d2f220ea9aeeb28d94574777eac7f3ba57c22f80f5f9be59b34ed20b4"),
+    hex!("cceda416b9f629cc37fc124fc0105b6e9482a94aad927f57f5adb3b6f766df61"),
+    hex!("b41ae12aec6551412cbd897ddbd8b3200a920fb9e50ab30a17780dd1f52dba84"),
+    hex!("06fe5fc1132caa34bdb1a9a00ae89b8b5ff2e14ff76001b3c2c33e4e22794d03"),
+    hex!("2c2c1049a7258bb26aa7b7307428d1b691e2d161e8f004b2ea05ec5f79e97da6"),
+    hex!("ab03092ddd92eedaed8cde9d6a14ea5680ea5cc5e56b076db47bb31f4198bf28"),
+    hex!("6555fec505cea1e237c5196e4b1a1fd805ae6ecc3d9ce473dfcf9c4dfe715dbf"),
+    hex!("c7ddac7a99a7b9910b8847b670d5af53f7958af0a7967b79b9ac0069c390244f"),
+    hex!("ba78706f57a574f063d5160fc298e05f2aab06dc887e797f2a97181414643d42"),
+    hex!("485da52d1d8900a627e2f3cc9f3cf6e653b8effed5972cd10a38990f2fefc494"),
+    hex!("3d2f6f07c36756184ef2c622365cbaffb8b62e340f384388bb5bee95a7c6c1aa"),
+    hex!("d17b42ded56f410a916f905ace0219c3f5b859b9e62da6ab4425792b1b6dae37"),
+    hex!("64101129829c8cedc9bd315090d55aaf67255d64c766f15bb38ebcd4f2217607"),
+    hex!("88fefa6ab20bb415c3d82a5a289a41ee611aac573c11d884b56e8788f769ffa2"),
+    hex!("d362f3b43a268c4f1677145db590769d5eddce0efccfb9e8ba1c54058f657fd9"),
+    hex!("6bd16aa587f6d9de55a4171252c85261f5695bba8ddf34002975c3386c9ea9ea"),
+    hex!("ed00b0a87e48f1fa043a23aa2bc7036783b8092c2471f7c3d987f09ab0b0e22b"),
+    hex!("75d0331a2a803d949f93a2b3168f76518c075a8c4fca928672678f87fd6bdaa6"),
+    hex!("ce6ac2100a499f6c6f8a702ffcc3f5b0c0f9461f11f31a08c475bcb7b9897682"),
+    hex!("a6b6783eb421c580bcd34282076160249de5a519062c4290916deb0cf172cd72"),
+    hex!("83dba80f08b844f2c87d9bd3672a01db0648be947d801be995311d829a16c704"),
+    hex!("5aba21b705a5b119057f2c9a72f1d1129c4415cd8e6e5af438f52b64ab4d19b3"),
+    hex!("70d3b6ebad9db60e0e1bb999b5e7c7fe576f4b22781f325af3a08ebc7a004a1e"),
+    hex!("3132f61390538fdc20495f4083337ba85936959ca5199e2ecbebe74a0380a214"),
+    hex!("8feba6e9aef1d63845f0d07b1cb47e864db044cfe32777db3129bf5f7b78121f"),
+    hex!("1d8d4c0d75f6229827bb9d26fd53433c0eabf03aa5a5103c52992c149cf14aac"),
+    hex!("9c876e32583d71eb49269f1473d6f1655372c8b16b85b477e9b0f0277c7e39be"),
+    hex!("1396617458c5084df53ea9b892b2f5c6289fcb5c0562b05eb07c058bf32bfe20"),
+    hex!("1eec9748c40ad9fc8acc6022d15886e5bd1e4580b811e237cffc0478c6e53caf"),
+    hex!("42af4d9f659bdb3d01867148f609687c6bdd3a9644565017f29fd3d9ba9b9824"),
+    hex!("2881d7188f16068d477b7d2ccd5dc1e27e1c38fd8821ed977229ac1bd53e1dae"),
+    hex!("5f083e405874f6bfe5bc919385f500ae444b9a171159b3b6476cad78c38a0077"),
+    hex!("65f2bd62ad4a29a593e38bcbaac0685033d2cdb4a8129d9e7672a75ba42820c2"),
+    hex!("bdaf0a25a0628973d4df7e4c053cda1318b39801d3f0fba827ebe792ec12cbfa"),
+    hex!("998eb736da0f9741c6cfc2dad952491708b7b4ddabb15d37699af13fed29c35d"),
+    hex!("a7ff9e28ffd3def443d324547688c2c4eb98edf7da757d6bfa22bff55b9ce24a")
+];
+
+pub static SPARSE_MERKLE_TREE_EMPTY_HASHES_KECCAKD: [[u8;32]; 257] = [
+    hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    hex!("f0df3dcda05b4fbd9c655cde3d5ceb211e019e72ec816e127a59e7195f2cd7f5"),
+    hex!("e8b1bcd51dbff5cef556daf568ee7e912ef92d8dd66da34ba295b01cff57eea8"),
+    hex!("958f030a38b18b1b1af84e054d7c23959b8f7e63b0cd55b1622b233dbbd2d971"),
+    hex!("fb58e1d942f0af66c65f5156567c32b6bb723bbc757db00772a6b785df6f6913"),
+    hex!("2cfaf9a8220cb379a865cfe90041fb4f080535e2158e5fe946f72d73333fdf84"),
+    hex!("86472dcbc3d3ca3c541133205f33162f8de02cbd822bb369621d9d502df039f2"),
+    hex!("1351dd2a80ad93eb838f16c00b3938b274102b59f6fb2432c925cda2d88e6649"),
+    hex!("4f30f8b60b547b37966faf2240499fb10c8f4264588bf59e67432fdc3ed9cf9e"),
+    hex!("25e602ab2fbc56bdd02e11a295f9b560e9e7a85eced8696638eb3c7bf27e5ae4"),
+    hex!("ee58d1135b945a921b238820aba21f22881efeec2c948769a6cdee5d6c2972af"),
+    hex!("c7a9ab567603e4f75d8ab5c04477719e091f17325ce45933ebd3be558a5760ec"),
+    hex!("f1be37e32737750e7038bbcf40cb00eb390368868fbdfb79e7378b2da3e6b392"),
+    hex!("1e8ef99d3949a683f48050ab0af94ccdd7891a31cb4852f929d417d740c1aca4"),
+    hex!("4d48b55d939e05b68b44b2baf511ce1b9d959df511922666710c6f8f91181cbd"),
+    hex!("2bb2cf5bcc8ac7f9caa51c6e2a3502699f24dcd7b37f921774c9700550423fd9"),
+    hex!("22f50bd31f8fa9f993d8808012beac1bffdf5e7b5db46d4fa1977512a6dde1a7"),
+    hex!("6c8fb1014e1764c00437967a404d05eb5765cbe97d3640ccdf5013b03db150b3"),
+    hex!("f36ad22b42216817ccc2f59c98dc0db01375c7d4146c43bc796e9bf6d15e3ac4"),
+    hex!("6c6328c903f549a5320439e5058eb530f6346c3f58d0584aacd6986287c2adbb"),
+    hex!("e89c3be8b21499347fd47d96d4d9f6d6a0618dfb017dce01f0d435e61fa7d4ef"),
+    hex!("d23c0d87248f2399408affdb1647ab75ea43e0d7691b6aaae7ccb85a83f2f8d0"),
+    hex!("2755eb5dada35834f275dcc206cb4f303414d36421c6db297d9136f986ee57b0"),
+    hex!("fb2832e94e200fd1d82c72b79d0beeaa456aab638db92b08bfba7abdfa428dd0"),
+    hex!("24baf7d31bacdf391021e582acf73b2da36f64ae5719f9029d48679c793bc4be"),
+    hex!("6702f9cb825e8ce303b1e64cf0be076ec4f1c349f5c706996f53ccd8dc747282"),
+    hex!("a341e5afd70c96bda92ad59860a5ea411b525c0b66801343bdd60e999a2a29ba"),
+    hex!("17e804a962b220e35d3ace9e927cc6027711d6191fd983a3b557e3149ed72066"),
+    hex!("ab4434e69910c268728f47f4183fca58a5fc8d5bab09bd22fbf9d27a18b71aae"),
+    hex!("59fb15ba48c94e65233609a6f542ae40260cf5efa5626c5027496fe6791e6d95"),
+    hex!("978fcb40f5f4325d6c2285eb050c11b78ac3d0dd4d77d15bb84cb1e15c2345f8"),
+    hex!("80b008bf597855c6d8aa994561f5e5630971ffa8022785370b9d9fbb75b67293"),
+    hex!("aed2a36e766708df50898c9856bf213309f345494b562b7955b5fbe9bdca3930"),
+    hex!("65a68116a5c3a24ff4c34cd0c75220aa5eeb8fc5d66a025d4d36a5ef4e354b4e"),
+    hex!("c65726fc7d8689ae412a13de7d7b8a1219d1bbbf8f8cf05df98add6233f6c83f"),
+    hex!("10b88bf65151b92e5bdae857f334fc8746bf9f859e9fc080199850d8d136379c"),
+    hex!("747baa482ba9d15f4dfac2c3e09465f05aa7a83944df34509618ae1ba2a92863"),
+    hex!("2a43334af2760919465823124bdac102f18678d0d40da609b0b27b32c63b2bda"),
+    hex!("fe4388f227a43349fba60cb32f1ba62f9b7e9f1528fd9b0a20da7bc9f2869c41"),
+    hex!("767e545f254d221a4fc929d0d750f76016a82be75f11e779622380d7d109f9bf"),
+    hex!("db593252ee37c713a40bb1c8ddcbb4076d2959355ba8a3bfa4c7abefa6214033"),
+    hex!("1dd7175da42190d58d352d9855735be786a4a694432fe2ce2c7193cfec9ea9db"),
+    hex!("3833ce2a93aa8bc0b6dbddffc21e17c514378b58044e5944f9cf6f13a8ad7611"),
+    hex!("2302b1d67807d3b13539ee5f5c9591bf37e0f2c3a3a87d73ea08a6d8bc72fa57"),
+    hex!("b3b7cd180ad42ec45bffa30b1738ba25e060b11e4a3f5a8532754d9a8ef5aa0b"),
+    hex!("dc201ad5fa40dbfff214d23b885c867063d4496f56531b885a485739f5b6722c"),
+    hex!("89711dfde7ea976afdd401c9dcd094a7aa15ab456700be6fc360e2844843c347"),
+    hex!("5b62eac558f5635e9cdfc006120dc3d1c82145dec3ef0068ea7c3e20d7d06bfa"),
+    hex!("07aea4b61b85335597671b7ba746d7affb898161d35df20b21ee751daf0e53be"),
+    hex!("6ed4068064ef984eb2e4e5b5b458c672b4207dcab88fcb9a0b83424fa1aef61a"),
+    hex!("d53faf8bb22bd6905865bd8866f246c3a92b8a058037a122f08432d1fab91c40"),
+    hex!("f751730749cdfaddd5f08ac253f47ece8fa2219bc6138248bcb2eb19d122d136"),
+    hex!("5e630c3a5d9b735cb9e5360a8002c3f266917f8f7a2dd0eb9d5db4c9acf8085d"),
+    hex!("b5f0000fe3a20d72bd65cb20aa3ce2ff4312d1ff50518637207a2579c9f29dfe"),
+    hex!("2b6eeb8b906d7649be5ca718684421f037d77fe7e377758315ef333c826e77da"),
+    hex!("e63194643d797c68ad564dfec79ae83c95b4a20993d45b69591b40c89eeeadf9"),
+    hex!("089ebcd20f3b3cc3d645297520c42ade0a25989f65c569b1437435bfaf8f6b8f"),
+    hex!("b1f471ba4335a664da27283e87d7544df18e15e92d0bfb9c888365f316e7599c"),
+    hex!("1ca1161a061c00871bd23e4752fb8f847725c5f21ff2cdfa457dbc08125f46a1"),
+    hex!("8e19eabcc37b14d224d95bed1f23a6232f794b92f96f67833dfd0d2ce7d62af6"),
+    hex!("a219b82bb67357e732b8db0066ab8932772fe5bcbd92841d29fe183eda39fa46"),
+    hex!("ffb3bbfa372a4a87be08ea4af78451563c9cf1bed8462284f7d280b290f69de9"),
+    hex!("d8ba6b17ade9fee65240a9284c54017a5b21bc173b89610785ecff356a9b42fc"),
+    hex!("1e9bbdee9f25dba38177995876eaee83fbc0764deff2311544b86ec5ed4e6ae5"),
+    hex!("70014f70541ae139b8cbc059e350e9f4c08cecddd72adb460cad863c444bf065"),
+    hex!("470ac74f2c70c82c0e9e5a58317cf63a7bccc4b881aab4eed5ed16f21bef6def"),
+    hex!("ea65e5a7b208c0e2085fc4b3b92381963b125e70eacf8927abde440f90312df7"),
+    hex!("fbd7b930dbe45311009f16c5f492572e1171885249650c619106d8ff830cd235"),
+    hex!("fc901f7918bd19a89414323606f1048d3fe4616cd667790f1a22fafea568993d"),
+    hex!("1db2a71b5680fd38e1e76c6adfcdd8e377526c2afa52e8a7393c2b2c98c50990"),
+    hex!("ca764e3394c339d5a51257b93cb1ad4ffdf32ee9a39e5ef9d5a551ba7608909b"),
+    hex!("336cf070e6fc56094d71e6663e8c1e01e82805acf1fae4cf2144dc98a01da6a2"),
+    hex!("ce7200fadb59d486fe154ad9c05f01b8429d726996d3832e12f4aad45e0ddda7"),
+    hex!("28b87479263b1f680c210f67f8cec1aaf89f6fbc5b934168808f1093bab406fa"),
+    hex!("5b58a454664a47e915119da5f2cced4df1d54d621a9db127b35b9c3cd07b26bd"),
+    hex!("8b9a23907c8d244b0d16788ff1f845baa64db554caa55cf4de56895ab590acc7"),
+    hex!("e1a2443360433c2b5606b8cbce2af03caabbb059d12ff17b128e4e8c098e0ac2"),
+    hex!("8b665129374d4e56d040a11c82d895699e475981a5651ab9313d2a989d36ef87"),
+    hex!("3628a34c8016f012b0200a4c646aca54fa5c2b38766cfc7779738662fff15be7"),
+    hex!("809f59ba65970c28f1734d6b9420a977c592a93e26e5614fa795eeefe624f4b9"),
+    hex!("453ec11531fc00ed2d296298eb8f73f5b48fc7764d0b577d96d0f191150492e4"),
+    hex!("b4748f7fcf090c7a4692c4a82c66249bdfaefac5835eb26224a09e5d54c0f80d"),
+    hex!("1f26e56c17b1a3b25787078959ae71820af4a430fc85232cf9de78914b26c1ba"),
+    hex!("4d9dbcaf2a63367f1aaa1714f7bc3aa0560f0cdf769eaf576fd09d4c124bbfc6"),
+    hex!("cb7902a23d0233780454115cd8f1279f301b7e30e107e35c37f77ad6d9485814"),
+    hex!("75145196c9609b3ced4b2dcb54b1afb32f69390fda1c04ffddf5b2ea94a7591f"),
+    hex!("33a1d05d5a0a180054206e0955b08573afd3585311dd05952f9fc4e974e9d54c"),
+    hex!("1c064791dd1fe352cee527376472bceb0dccd193e717155e984e64eba6a2d507"),
+    hex!("b34e517e3374f30c3b3e039e63c044d5880776dfc297c4869c681b91f910b440"),
+    hex!("9f3a2030ec8f3f72b26fe21f47153264449bd6c6d41332a628a3facb6186ccbd"),
+    hex!("2e45706ec2cae0e5d1c1771a9c7fa906b65f1986aaaafa8e75a3b182ce83bdfb"),
+    hex!("0d0ab5b1afa46fc211185b208af11c0db7050776286d3cb116c9ef09c791198d"),
+    hex!("342c2509c6b405e4bb44a48c1c1f15f46a9d06a59c03b60d1df42a63a7446bbe"),
+    hex!("cd59cc6b8745b74520bb8a0a7445a995071b171ddc2dee736a8ef4650145e3f2"),
+    hex!("05449f6966bb540c7d2f35307be3076c63615c2cc1496d9277bd18d73d25d931"),
+    hex!("b5adfd01a3e46c4f57aa0dd4af28512968676fa49b295a28b4961d8b7d00042f"),
+    hex!("dd595feedd1f6dca42d47e5bdce78e66689f5da7f22056085452e21a8954de7d"),
+    hex!("2a3bc7935a94b5dfd9e7cc249a022d96f0cd74b094139d484817c88e061a8da3"),
+    hex!("bf43904fd342019514a4e5574c4835bb30d4dc35c7c085105be39392a390666e"),
+    hex!("561a49361851b898d3603b59b5ba5998a15766129ee38c4eed5d602f5d2b946e"),
+    hex!("6f4b58adbb827fbe953bb9a83bcf0e48eb8c488a96bc404761f0cf8f9c406fb2"),
+    hex!("985e326bcebfa008fd22ec6e5100d24408e414dff67001ef6e1da605a7e8ca26"),
+    hex!("7cb2085c324dcc9561cf2d69b469de02b3190a5266f0965f5b50b4d8b283cc28"),
+    hex!("9d9a5836efb112f8ba9075679deab19c815ed31898edc1e09da85b4b9fee06b5"),
+    hex!("2ff03b8064b06756dd2ba4523208b601e7afc40eadcbdf93480559c731ba2365"),
+    hex!("782ea00ecd6959159824baaa32d1c2e8ce0f615a57a088058e46bffec4ab2e3e"),
+    hex!("d01299da2dbe392075d22ee4b6863ae7af72aeba46d9e4bda5f91b4092fd7fc0"),
+    hex!("5b3073f21ed664c5450e35b9e90265d6c78a91b27670ee972bf0f9df8e726f72"),
+    hex!("6221dcefb9092370bf8c015d1d272bd9da2a3db725b3214b1bdceaa116edb8c8"),
+    hex!("7872c8e01c45b6b309574d8acf9640eb20e47c2f9aca92137f2380c238eec5a9"),
+    hex!("3ffecb238f269c45c78a42884992894003a6709709aa64fb30633d4a8a378c32"),
+    hex!("28870fdf9ab7be668b0fc9de24db2d277d521b7d7580cdcb21acd48a28549676"),
+    hex!("c68d4de585bcfa3c48472537f56f8917fbaa3c6d7040127d7bc77af3d0c430c1"),
+    hex!("97e8da2a65e6e417aada572a22fef3afe1ad05fd175a4b72bd5a9be39b91d0ce"),
+    hex!("be09f475892c8b830fd4a52c58001ad2d46dbe57d977afa054364b769b023a5a"),
+    hex!("e7da3eba93804b5a2664b4aeb9cf4f81d2eff82c90f2858a27873a4ed699cdd7"),
+    hex!("7a67627c685a89a732fc3898929019aa47514e5fd7cb942318e128b393ee8307"),
+    hex!("8a401ed1700c0d3e5f23dbb2687d7ca4b1e569274a0fee1411b337e7b5f265d6"),
+    hex!("708b069f8c0d193974d7e89c68bab721441569ccd750528b1bc0d23d84c2a6e4"),
+    hex!("a50afe7a319019864c6bb15e72cf0e23974df65aeec6c0fe5b4a2a7d0488188c"),
+    hex!("eb9eb20ad4ff76301ac2d4fe58710d0ac1b4e7e284414d3f23b5553ac557d080"),
+    hex!("065f009a5e1b9801453ab73745967ad682891ae7578ad90e5d96bcdb67c4e770"),
+    hex!("07c51a7629e55de4d9b75dbb71576a419a351d5e05ef0884649da26717ce5a8c"),
+    hex!("f695772efe8d517218b890eae2a1f92db3e91ed18327cebbf1e3d12c7dd0baf8"),
+    hex!("7217abb1125a04c2d86a3ff6dbdc26974a0f7c8487696db406279ce980a2d981"),
+    hex!("c4fc0a83311fb3e51d826818d0953c91ffe786afca16165f6f51ec16d41fc8d9"),
+    hex!("6198656c7dda584b1cf05d783b0ef252490d36e4e539bef34061cb316738466b"),
+    hex!("62332e17d6623bc5f0e249e5cdc07e08098b1ff9f067a0fc1fc6860b4e2bd4ca"),
+    hex!("fe5f1129b3945ff7efbbf3816aeef96494b0f9b1d7a3414a8d69f33ecb7a2313"),
+    hex!("2251f9f6cdaf0fd4983a6f9a0fd72d5a465eb6023b6612694319f09284ea8bb6"),
+    hex!("241a9bf531f42d99baf024288709e717e960a405a9c33fc54f9ed707ba3bfb1e"),
+    hex!("50c322ceb46871925d552ab1ba70a6eb34b45f90e10a82e26fa3ebf521ed95dd"),
+    hex!("4fa47334c6c846fb72c8c107b73cf8296a89beec5e2eea94666214f50398af78"),
+    hex!("0d51187076f2220a12370d1bf33648ce9730ae20e8375ed6921599f1c50cfab6"),
+    hex!("5797512fb009e8b34bcd6ebc2799597cc609b790002d61bf700bc3482c3f2852"),
+    hex!("624d3d3f4c6b00704d5bd91618d650df78cfa87f3e1f4339b20c4ba70391699a"),
+    hex!("ad13c9d8180ee4f4d4c0a25e03c29c28e338e62ba9d1bb15f6aa4a9f4e48c5f4"),
+    hex!("f6c97c447269c2e90011e1980cce14ad8663dd027a0f9ce613e8544fdf5ae1ce"),
+    hex!("631f47c60e4bb96b3e53ddae5a69dbf3003bb5a2cff777c0fbc0d73ea927f016"),
+    hex!("7113a43242b7f00662a11282b7ba41fedc026413623c6116573aa49b079235d8"),
+    hex!("c0397f8316df4b496b59f4a9b92be0f84e215b952bfb3c033778d444036fac33"),
+    hex!("4013a0debe11a59c30ecbda86e4dbf0111a47d39bc67dbe720a60380edc0b94b"),
+    hex!("575238e2b65599a0e15d87d4b889ce8f91032fc32693f5904b8036f8eefa7182"),
+    hex!("d124959247cb18ae5f0a16c5274eb88d554593a41394a87b03c353e008e4dfd4"),
+    hex!("f72d791a5b11f706c1f177926ddf6b48f229a5142103e6f494b7d489e5e0d191"),
+    hex!("cfc6d34bfbb223059106660b5049a30da4a04e64a2d4c1f06bc53a9fc9160362"),
+    hex!("d33a128b3280333dba9b1fe8f083612e5e5d7aa65d461744db831dd69ea0389d"),
+    hex!("16e31f666b947c19ccefe7140ce2d7047f63540276d18bef3928e709eb070397"),
+    hex!("60ecc2b75b18974bbf417ff7f37a825dc4aab8c5870108c32d17d16fb0defb14"),
+    hex!("06659bc23fd1746f924cd3210a23e0dd51b4276a07efc4d615c080c06c35cf3e"),
+    hex!("4e80881ad9efe79241cdd7878145b800aae06a62f93c009c92d3f7894626f292"),
+    hex!("ee3f374225a2436a7e0fc626f16c5a1830679c201a5448422b8270cbd70c80fa"),
+    hex!("701b875343813a0f16cf13dd4aa345b2f670ff05f7ef9f8f511a0bf39dbf97be"),
+    hex!("a57e61f2fb6b378b3af59861238d54ea4d0bf4d442fa9d8948e4b5142eeaaeaa"),
+    hex!("7523046342f5b0be5fb664f10cc8d16c5274e93117d66c5c9348cff7c54aaa10"),
+    hex!("9226d256f501f08419100b232a1d4e8a5d16bd524cda909453aa39334257bb5a"),
+    hex!("3c7470e827b518dc23b23b43093f981313354b3f2a6a37d98b5c1cc459b7c8fe"),
+    hex!("da033125ff089e49df4ff10182763346cdd2b8a6593de5b87ab4756d64b0c40b"),
+    hex!("90b93709dff97437338d8a50093cb7dc52f53d4dbb907cf85a0ecbda022c01bf"),
+    hex!("6f2b1640a89d2de63a029f808eac113fc0d5d03d270a0d939f2f6294bde9e61c"),
+    hex!("fce8dc78bb6cda2f1b44f8f92e76576aaa740a8c6f649d2fd54a8d5eb56caa22"),
+    hex!("f0c4134a2186f6233d85c2593eac00c1f8a497580458c3e43fc512e6b7e35033"),
+    hex!("649000eb574c2e127831a0040d57dded52947080fb46c5cd76a59bddaedfc4cc"),
+    hex!("afd5dc7c6f1ce422ca3deae253187ced637c790f4647e8a848a52866ef9d0701"),
+    hex!("4318c5e2886c800bb13eff7153b09d1dc671850bdc4b98f9c7c61e6f3ce2cb67"),
+    hex!("ac6614f3c361e5b0cb86cc2675717e54bb187436dce4c08bae3669a2c3389d50"),
+    hex!("6ecbbb78044d500bce0005eefaece354aab90e3baf7c41178e613c1bd4377554"),
+    hex!("536d24af8afff0a7cc0da2d336d35fe3cae495b122dbb413faa2144fce44e7b2"),
+    hex!("fcb48620089e7371879faddc4e52055521830326e6d32e4c379014be603e0aca"),
+    hex!("42b9a19cca5f9f8625e52ea2ab1203775461164402effdd6cf62cdb1daf9eae2"),
+    hex!("8866b66484aef2358c9e60a854f45a43cad3541e17c994b55171975d609e34b9"),
+    hex!("5dfe11208ddd515740d69a134044d436819d744a9320a5fb415001fd9ca2f808"),
+    hex!("e068d9f3afcf95b2571a38a378b50c2645419ffce9bdd4ed1669cfc38ba8d580"),
+    hex!("4ac13123db4074897c118884cb7a7b488641495b3a944e1c27c73c43a6136d8f"),
+    hex!("a526fc5dfd50c71ae326518f5a49e2c5086c1814735cef1b03c6ced8f72f1d28"),
+    hex!("2884c14d0e742976654b803f65eb425c4390a97bb65b7fc34b8873e5c674c1b8"),
+    hex!("74824efd729d30ffccff1514e39549f967bc5f6c77177d17678d65c7217098fb"),
+    hex!("beb8236ba362c1d1069a2320652c0ed6011c86ebe619311e9f9af6af3200c97e"),
+    hex!("7935d213c4f9e57709b64def0c1cf24c0ed01b9aa460cd72647720320eaf9a69"),
+    hex!("47c8ec0f6ce1df19edf047019b8562f84050b331db7a097eb717d3fe392a520c"),
+    hex!("dd62d244306d85381eeacb50dcb2a07701ebcb709d6b8e751e60650894941915"),
+    hex!("6438e4211112f4c306025503d73ade2f2e885f15e64ba666923b887730993ccc"),
+    hex!("048ec74f726886261ce716b281d2adecac7ee514213477db8c0376b46a4dbbd4"),
+    hex!("d740c8e5383b11e4a801fcd5a311326837c0f281fed5bae37b24911afaabaf9f"),
+    hex!("cd35926a95d293e97239187a8b383b40c13723b765aaee773f0b02db92ff9bf9"),
+    hex!("dbec785110e1453116efac4515685eef58fc20237143a7114c6b59fe38fd8f80"),
+    hex!("39febd89ba06b4e11e810107bdf5e19942c02771041914d1943eec0ebc6ec630"),
+    hex!("5681ee0a641568aa64e8f861610f5480ca66ea295ce4b5e46f6c2989c9f0d4c6"),
+    hex!("e158251e17c1e3c5fa6a70ec58f08225bddf6b8cce0900b17bf6fee43b9c7b5d"),
+    hex!("332f06e50bbdd01b3387a8af5036cf5c6c9cf30dbec1d3ba936b55bf968cf4c3"),
+    hex!("ee0413e4e29a26687f7a84a6632023a940278f94a237be7b70a98e1a400e3c64"),
+    hex!("f912805c2a4695ea6032236f9f882d8e43b5f877de85b4365d618c1a933db4f5"),
+    hex!("d1782c82d9cab3e066bdf1e752851de8e554b11b82acfe9d321e416cd26cec94"),
+    hex!("ea582ee557997a3e916db61af645644cef918ad0a81a6c1f7a5473825e3a3883"),
+    hex!("fd348d58473acb71541de78df3041202026c7f3d4daf0075b187cd414092ea94"),
+    hex!("b85b5df8aec2ce656342e835511ffa76c12b17265e98119d9305659b20d019a7"),
+    hex!("d4da5f6d4fd7e2c8b415693e07220cdc23e354cfc2195aac2b54ae036cfde4d8"),
+    hex!("4433633a8720ba34dc8b4afff85859f62a3e9b4faf39c424469a915b40374e9e"),
+    hex!("10da551f599704e9adb09fd0b4c885ee820793537f9e79af3b42be47f475caa4"),
+    hex!("95254b147df6e971e516b3e0eeb2a9d4a8588eccaf9e94cdfa454ff0dc9f1059"),
+    hex!("2bac3e6adbdbcae49974a259fd3aebd2df6fa58cb0905f3b133cb0eaee765e35"),
+    hex!("fc40939db77d8209019fedba9f991d21c59408d7871ec47b9f91591f93c66da8"),
+    hex!("03f2822ea0bbf340505e83420283eded0d98fc3245da22200c22927ff2badfaf"),
+    hex!("906233c67e5e01e5ab937d95350dc8ee73fc5e8c0ce9516fab4f3db059daa94f"),
+    hex!("7fde1cd7e5d6bb303fb46eb7cfe699647d966be88e8f042170bdb8df8a49ca72"),
+    hex!("06e8c5889b9fe1e9ed7af9a22c97439779b89c294f40fb0f48e8272f8d01bb7d"),
+    hex!("df0992e0f1fb6b1f49a45e0c961f86e06cf325a28a7787eac6adfdffcd2b166f"),
+    hex!("f204bff68e7bde044285f8c43bd8837733ba17bc897e897538daccae7e9d5058"),
+    hex!("969ab4ad861f08fb2d4c7370c7a68e0213bb202d6e2b912690db9744d6a2577f"),
+    hex!("383eff2e7cd7d41dfd655445e2a3b195ac477301de3d222078fe6f11d6cf4aa6"),
+    hex!("dfc21bfdeee0469a159117db45dc89fe1c611ce62a90a56b55775e79a7ae676d"),
+    hex!("7568d3bd629832c653c8dd133c35315f56154702dd233e46d69f62559cb07147"),
+    hex!("8778df64dbb9a415a29d0359c8bb4127aa997c5b0123877321f933a96096f119"),
+    hex!("711b38cf1f15d2e66720bd404df7e13aeaa7c595def5c02868d616ff8706e58e"),
+    hex!("ff6040d78098f7391b287b47d8b67d6fef33c3298317a22cdd19ce2072c65f2a"),
+    hex!("5556716d1260e08c5dd973dbb0f1cd3fca1570b9238e06a150e2c5fb967b2cfd"),
+    hex!("1afe2050b604977a1c843545867f86b8f1e83a6f346833d2d6b2dfb5f7b17bb4"),
+    hex!("6f1488732d770e89bc618348322ab9eb50db61de946aa537f3c1afdfc990ab3c"),
+    hex!("66576a7cdb753168ee0e5387ce80e7ca23052c9cac0b91881834ab28a6bedd49"),
+    hex!("a1ed414b296eae551a9a71c666b94c6138932ce528ad2753b0f5d5f00959a800"),
+    hex!("5cb730dca9bc6ec1a9be8f6b123d0ae9b0a4398cbc4d6a2a8775f14751b916b4"),
+    hex!("c5dcb280df346ae6f7120221dc26dacea68a4c329bff7c624f3814270bc16f53"),
+    hex!("afb0396a349a085da2a93a3458b840fe55b66a614980f7f1d28ba93c890d2eee"),
+    hex!("8c62402d91c80c209560a3e2343e2e1660c3eb14cd33d6e6d1b38d44fa438b74"),
+    hex!("06cf321bc1345bca8c3a62b8da38887639032b02b7a6caceff01c278f82961ba"),
+    hex!("0b9851df5b71918be9ccbef5f92496fc42d853cef35c2b63543b7c17771a4041"),
+    hex!("867b100a090117d8e81029495db99b98e0e17024eada07cdb32eb1f16cfaf603"),
+    hex!("c82f029a3bf8e388585b53240fdd2b8f04f561d03b0b6b9e5deff83788aaa69f"),
+    hex!("f30550ae8a6a277677fcc449fbd7db9c0f09e22e0b621ba70e2f2699218ea700"),
+    hex!("9bf3c7346bec509014f650c5f600858f0c3753e8eb7e3f218c09b2323de6fb73"),
+    hex!("f0f06357d919a831e1012e06ac171bfbf7439cee9a7e72a44d421d49ebbf5b44"),
+    hex!("3eb2e837a181fad1277a443c7bc46c4ea9612566878b666f4829e2f9632c522b"),
+    hex!("7ff88f2bcb0a76743484df88419d00f999e05a5567230330f8aded333eb14ba4"),
+    hex!("53e741b967919a9c272bf505381aff377ab87f5ba83f9f842c299e7b74a9f295"),
+    hex!("71f56ad580bc891f2f764a2029eb37e57c5135699619c51a493e08d9e3dda41e"),
+    hex!("de73997016e60aa876881f68615e35018c968cf888dfa4592cea6737e4d150f8"),
+    hex!("4aa03ef9e17cbc1f8b8c6bb9e37d3c3ba0114640f484fc7ccd2a0fce1a0d5314"),
+    hex!("855ce3d5592a2b7a5a8ef58cc6e60e2d5867d91ee5febf933e8f3331d3962741"),
+    hex!("ca78ddb770e89c67a6bf278576d39c9e1fa4958b8aa38fa5c17d7bc2d347a034"),
+    hex!("4530a469defada4728aaa02ec95e7cf2cbbfedbc26177b399392140f98eb805a"),
+    hex!("02d56cd5fce1f9a9a70bab9e2288ac950f13c136a869912a8a2c63a0e26f44de"),
+    hex!("f6596576697f056293f0a156b7cfa0f3b0d51de99e05dc6228eea3082a3fe00f"),
+    hex!("ac05f0f0ffcd2fdc3f62e6800a2f08fd389d6a1c194002d4a39ec0d1eb4e434f"),
+    hex!("5f04fe80a6e5d70ced5b31acf6fc3a5fb9af06b4024c175b03718a410deea432"),
+    hex!("469ccba6974c03a3c3d30b7e92903b3fd3e6d39d7199a94a8c7a3d4dfc5a53ea"),
+    hex!("59b93fcc4d09db4eca2f3f11810ea669cea74561b4a8db573f36dc02c45d0caa"),
+    hex!("a30b4c3c026f759979e97ed0651892c69591f3e0dc046a084c00f2b082b67f51"),
+    hex!("d32c43780a1fccbcfbed75def1a964c2745c5727a6853cc6cafad43a6446f76c"),
+    hex!("adfa84fcedc2aef876d3eafb805f55151676be5968871c219464b1efba0253d9"),
+    hex!("2a41109b999fbc4c4903904c2d4eedf5ca040472e940a4539df2cb6ef4867f84"),
+    hex!("1eed2e5f0f1a90356022a0d17c25edfd676ed1af7377ad559d9f24679999670d"),
+    hex!("fe86705f1363b120e6311355fe5b05c1e6ece357a1f1287ff45377743b29e5c7"),
+    hex!("5d2835379b5ff6822553dfd0de35a58b213091446f379b7d71ee4e1485a6368a"),
+    hex!("a0032232c798e18219c8e4597402768e79b6198a0de72cfc0aee55b68da8a1a6"),
+    hex!("5c59bbc46746e4190c8282bc0f00130f471ae6c9b7cfd4be142a6f4c1afdc4b4"),
+    hex!("0994a598ec0d925e08972ed636e2d54575d72b7dba7f31026e56749f26e324a0"),
+    hex!("8c77a1244bd41d3bd470eeff7431163c6f920c6331de7b1f7c274667a8f6bef6")
+];
+
+#[cfg(any(not(target_os = "solana"), feature = "blake3-syscall"))]
+pub static SPARSE_MERKLE_TREE_EMPTY_HASHES_BLAKE3: [[u8;32]; 257] = [
+    hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    hex!("4d006976636a8696d909a630a4081aad4d7c50f81afdee04020bf05086ab6a55"),
+    hex!("498a8e7c02bc2c7afc598b097ad065836ea84e0edf2bf0895410968711d6b54e"),
+    hex!("06253c52ed8536e4b07757d679c547fdb2051181a9cbd1e3516bfc71742936f7"),
+    hex!("31b471b27b22b57b1ac82c9ed537231d53faf017fbe0c903c9668f47dc4151e1"),
+    hex!("622b6f650f3feac0798953f58ded296da9a2e298cb3566c8de2d27d9658e0b8c"),
+    hex!("dbc28eba6e19736e0c5ca790fd80fe9cea1856f6907925562b0b5e3b2d79382f"),
+    hex!("20068d2f4bc00c818254b5b049972be6b6ee5caa487b6211e4129d9fd4ea07c5"),
+    hex!("f6ffd15c6c47b3013bb64ca9d6fa63d3d9e4091281ddfb4391c4a021d44c6d95"),
+    hex!("e05bbec721edf4e51e0d933c8e2826fc305ac95b7d7ab99531e74056de018e9b"),
+    hex!("6c86e2095a1bc0835ee2c716c1d2620b151906fceb9999390346b9f19d8eb2fd"),
+    hex!("1e860fe45dc351eba80637e255d6b952b74561ffe1f532f07ae31f3a82e71ad9"),
+    hex!("553fda6b4ff7979299b9142affb934ecaabe56f3bbe2fc91517fa76beef99e4c"),
+    hex!("aad7b263b6984d670e2686f7cacb3a63c15d6ad6868c3c87011a72797d6b08fd"),
+    hex!("80df400394ba37889b303b0f64ff9d5e54769f9814ba8cd4c49c074fdf992623"),
+    hex!("3e25aa366e8934567126f385fa8f181a190b286803d1951b6433bb4a6f2309a8"),
+    hex!("b867cb2662f9492f76f9e1c204bcb4219f0b1056bc1360dbc62c35ee29a24233"),
+    hex!("7ce9f1493c0d63818e37f0e679ea8f6316babca85513333e26abdbcbb8c3d3e0"),
+    hex!("1464eebb34cefaf1970c195f000e059e88da78625937be6b174e7e6dddaa9e3e"),
+    hex!("b3dd41c4085582d036cf5f488f6feabdd030e166a507e1c41a3eca74156d8ba1"),
+    hex!("7525330418c188cad8dcf35a90730ded9826b081ff9ea59f311f6a3632ced73b"),
+    hex!("365990b85aa18dc0682476181b0cc5c8c82c54d45c8952f65e15338426a52408"),
+    hex!("6bd23ee2a725f5acd7d5f8b20fd2d5de0ff06a9a8994568fdbead311f335c12d"),
+    hex!("4441f39c141307068523e107d877bb696fc231376e53721b834f82611173279d"),
+    hex!("128bd8600ea7a2a0c15ee033531b61acbc0362cf2e3da1e0d49314037fa753e8"),
+    hex!("2181e6224cc8c0e276ed4d1b647b0587edbd82ec72bdead8ee19ac2a64da51f5"),
+    hex!("778f87794632f0fbeb6c2a53814bca5faa5fa988688d1014d2fb94298cb88aa3"),
+    hex!("c6d4684769f59e695c08638386aecff1b76618c2d69db27dadb6af0cb339fe8a"),
+    hex!("04ed107468b77b2dd4e18f6fbd977b2d8ebb415a9ed4a6e11bec9218139474a8"),
+    hex!("be509433860d1a2e215cbf8d01bde9d5bc9a755c8e0d9ab20b1c83d7786c67f0"),
+    hex!("35bf6b4d90ce56b9b3bab6b87906a1f18fab9db92f647fd429fd228904bbada8"),
+    hex!("6b2b3af29e0cfe065b7f86d94972d44a05bcb2a850e0163314e3603bdf21f4ed"),
+    hex!("a4445ecd22281e19c2b86a211503273ee0990e64dbf5eddd9eb8654ec259a368"),
+    hex!("f8deebc40d44ff6501e0638fbe503966204d0ed485004a9e3f61175415e430cb"),
+    hex!("7596179daec9762c54a2ea963b09822a8544df7db1c4497dab35b20586407974"),
+    hex!("36a4f5ad748555f03dee2997825fc9597124c56529c802e46cf8549e8b6b419c"),
+    hex!("1eebcde68f38a4c79b109fe2436bd6827c0fb7e3bcce253f9adf04a45c3915f2"),
+    hex!("5442ec1b0e1fcd701ab2d771211574a6d6bf0aa1773dd3e255536ecac2e09068"),
+    hex!("ef5e9c37d2d2f7a4a3410a1cee703a3b323de92a13e75c85b9e14e44439defd3"),
+    hex!("b4ffc12aab546e4af7236cbd4081d9158b2f4b1535bef2f817cf0180522aea58"),
+    hex!("917556268f27fb068da528bf8c7458914e08f261fddda5d24e85f51663c39d61"),
+    hex!("5ac014f632636945b1cbe7209c2bd0b6ae5e1d066e8908cd8860f1598f654cb7"),
+    hex!("ff7f723149dadeb3d1da5cedd56b32a11eb135a9613d943d1c3b87b39fb3ea55"),
+    hex!("cbbf7def707216826bb8de3b088965a887c6e3152e3430028d31a2b05354496f"),
+    hex!("a598d8bf7561cd78e419dfff5778388f4e21338774d0dcb1c2fc0546643428d9"),
+    hex!("cc7a9db3eead27ab9c21c5acc11aacc55db1c8ca8a61c7c7ee0f3eb315fea866"),
+    hex!("e487a9011b7f2d8da58748816f78fa35a709464ceeb4e90f4ea6a34c765aa36d"),
+    hex!("6c45f98d2f21699623778b338a321da7b1a4f057798ff608803be3ab83c9af9b"),
+    hex!("2f64360d1c0209a4012d75691960321f2cd12003f39cc89796d8efe433c7ef42"),
+    hex!("c9af1a1a5de8ecf2f41cbfc163927c7c2972071db3f7df0ce09cbfb04566a719"),
+    hex!("29c478db7c4837f50a92f4cb9d978aabb311d3daeffc75abf1bf833df7e2b1ad"),
+    hex!("7a3ddacd871cf4f3fc849c3f7b66281e0627117345f6816ff7620c77ac4e664f"),
+    hex!("51b6126fad2bfa3e703a14bb9974c758bc3a5ca5f599fad2157cde99bf819789"),
+    hex!("bbca62ac3fa6720284c65df449f8ebd5ab00357e4c0b536efb46803704268e79"),
+    hex!("5ba8e78dd5c9d955d8d041f76eb7d414e1f8e0d23716ef177b51c43041201e5c"),
+    hex!("f0dca3ef70468163b442a29fdf8aa3b480d25f35f06be672dcccc8aa35add286"),
+    hex!("530fb696607b8725e4ea0687fb2f4f6826d85db9c4f7e7becc1b2483604f814c"),
+    hex!("0682c3f804f43175e637ff0fd499bea1b041062d78d12ff0d477f0821175d708"),
+    hex!("be4af9dffb0f5c36f5d91807acc0da0fbd3810fdf831fe808aec3c2f10922e67"),
+    hex!("9cbf097bcc24d176ef477fe6a27f7d00b2ad5dd1e3b5b8dead8e528e47e44a6e"),
+    hex!("309dec705a325bc4d290c9f5159bd0d377e3ce9585c2d2f53eb682a04056e923"),
+    hex!("869d1cd37485089c4487cd09e260394312214aaba45a95d1d7d61aafa182a19b"),
+    hex!("e8a1bfed5be71c4c08c322aba8e3683fb1ff5ce1923e93a62ec7b3c80ed4d0b7"),
+    hex!("7b218969d944430d8f048b8e03f1a166fc150139d39dfe28c03786b1f9d311c9"),
+    hex!("7fc46609efd054ccfbf08a5cc4dc062976fdd4d271dd15db7d9b5129c3e2fa12"),
+    hex!("61a7df67d16ae727f31b672d40f09069042933a13c9bb4ce7d29edf3aa958b4a"),
+    hex!("3368a3010acbb9b48421ac3e7a6286698d58f21d5cb8508949ca5ad09dfc0996"),
+    hex!("dcc5abd2574e28e530171affe982e426baa79569d5c2b1926323c6be6f83542a"),
+    hex!("e7389395eddba94003433f6ef0b1ad7677395bfec27f7546a539b827ee4e27be"),
+    hex!("ae364d7673ff714f04909e4004e84a1c7551b9deaa758332cfad624f097a961c"),
+    hex!("8ecb8749cc2660c53981c534eb1d629fb609a1edc8f0278add1493fb75d29831"),
+    hex!("fba0e5b4fb2e0ac4b827d72d2077a41e960d671fa6c3bc36d82bb67a92b73e72"),
+    hex!("cdf005fdbe8987e5323eb1d56b8fec28c3a2a63e4532331856d9f2e68dc30c42"),
+    hex!("c5ccaf249440675d9811fce633ea7ac472ce7b2d2be0e6ada42c4d304cff545e"),
+    hex!("7c73858dbba6d5977f84e633beede66573e43a519127a372ee3bc7dbde1e481e"),
+    hex!("103efb6293a412d0591d74bcbff502113d701e5e194ae64d820cd2e74b06f281"),
+    hex!("72195e2ab599a209b5351501c7e380b25548b47b7fba39954485f804f1760b7a"),
+    hex!("6f056f4ed58b9939bec6696ab501476c8a8a264352f7d52c90ed2ae465dca17e"),
+    hex!("ab4e1a75a7242e19655cb1bafd933c2a794809e0d878c5e71b6ed9005057fb54"),
+    hex!("31572064fb1a571d5e5d4a71a407f6acd9586a3b7dd66cb49327ec1ec02889cb"),
+    hex!("37860f8a8a0bc4aa2e871aeffdbab9cd787f011f10cbc14e6013e2214cc641fe"),
+    hex!("65f921663d040ad64ee86fe20507ff9de2f932634e7b797bc2c378fa6b23f383"),
+    hex!("86c27a0b5063ca044dfcdea30e2a5ebcb10dac2963c14ed266080fb30387980f"),
+    hex!("97672103bc1430d11f9bf9ad8a88d1655d317de011de40c78ab42832d572e2d3"),
+    hex!("2f1fa1851ae6a1e678bdd4e3083c788df4ab2b4f275625f260bcfc684f439716"),
+    hex!("abcd2eb2eecdf3871b30343b6344a99f037a81fcc99d8cfe0c13b4e37c539a51"),
+    hex!("3bcaa789a936f53886492ad9458cce8c769d958171798b958bd6ff448239ae35"),
+    hex!("08a13e4ab60212797d1b2b3cd555999de2551325582f4513d6f8ca6be149eecb"),
+    hex!("c457a8550896aa51bbdbdba8f5bf20a8b7957a311846dedf66688a33afd96817"),
+    hex!("2895658d505169c8c605d3c9c23cf867199de54de62f5fbb24337215b23893c8"),
+    hex!("5850755a840e1dafa5d60250fc2c33c2270337c5bef60d5dae72648f2f15d2aa"),
+    hex!("55c806ca3eacd3c4a400ac560f0e06aa83c644e706ebd65ada28f5739c5639bc"),
+    hex!("2b44d3319b40d12f9924e0468abf429566d5d21491c0a53930bebf73eb97f816"),
+    hex!("3ef05ebf514b0e6ad65b1357e976eae04ddbd97e81c8ce014fe52576d3ae0638"),
+    hex!("0a10da2255f44df711d86e3afe4cd356643e6d2e857585795a46804d7b278abd"),
+    hex!("5288bdcf6252cc911621e68f6e87e8cce93708ce38811157fe0c440f72c87e4e"),
+    hex!("d9fa8ce50fd7b99eb1c81d9f50633f779840521b0c0eba57f41c65617ecf920d"),
+    hex!("fc91d9cd71d89079198fa11797a4726e0566995338d3ed5c3662e0d9d33d7b9b"),
+    hex!("378058f741e78e2c22ed9c08864fe909544ec653c70e394fb4340f2d6c223ada"),
+    hex!("9e0a80bc1b3087eb8e579ce3aca8029e8eb68b0dbd17f0b4e09e1feb7010a7a2"),
+    hex!("dd4ee95db7e9adb702003820f19c0ebfe2d2cab147cf43017aed5a54865aa3e7"),
+    hex!("ca11db26dce79dd9769a1683411f0abc80d7a22098a6c49ba3ba1598833b0e86"),
+    hex!("57350d4de72a7c0645b72e3dcce094b880969594cd0f8fb444d5656f1eeca886"),
+    hex!("9b2b83e30ee18a93659e653e558ede77c7515f2f9c51a539698130b96e553db4"),
+    hex!("8d66576992e6a31eda4ddc92441e06f31a9449c2b5f366f132e96c59e5743bf3"),
+    hex!("ec826829ca412aaac6f61728ec63e43091ef250db4932dd3554f621a70313297"),
+    hex!("d9c41fb184a750a7d5b10f8d45158c0312546f4e8db18e238b11a6973ed5b89e"),
+    hex!("dcb6d270bd4a0c601722692b4c9a65f7677f73a1b75f5fc9cfe024fe4bf0c14a"),
+    hex!("c757cbabae43eb0a0819cb6bb63529a7852f586f7cefc540431c2bb40823aadc"),
+    hex!("ed433d063b6455aa95a93b2d729d65af20f3ada151836c24a3bcd1fb1401ab7d"),
+    hex!("fb1e972e56562def05257325cd20a88c13d255e66e1b241aae9222cf1eff2e42"),
+    hex!("fda9c1f8e606b0ce69d12600a5dba15d5048cc1b622c1962a59895070569e1f5"),
+    hex!("336e42103fa3bf2377b23015cab74002dff9f41fd08dc8316236eca85dc59121"),
+    hex!("0f98dcd075ded64e2645e59a74cced0baa75c629bf5aff096d55ba0d7fa36d97"),
+    hex!("0052440ec945c209eb6f4b4a6b5d96fb1702982dc9495ee580cf12143863a3f3"),
+    hex!("cac411c7274dbb767ffabf3b331fed140cc6281bfcc474a35087ba54873575c8"),
+    hex!("f16ecffe46f17928ec9e00c897c52be5a27ce5364199b7dd410242056c25be90"),
+    hex!("e768d5a0735be08d2b8dfe3460784c7e17041274de77bad68eb03f8305a43f1c"),
+    hex!("6965918ae68be8377c8d4b3eda590a37963d5e573d935594bd5bda916d153469"),
+    hex!("ae0f77087a3899a0817f4309b5791106f08d9cfd497bfb770a6dedab7558c8fc"),
+    hex!("c6629e0d6e85a267d0d7fc70302971a97d0d5e3b840fa8178b8a6be88cd19eb9"),
+    hex!("8c8ba6cd46293f31215e32b0d09ca050d8b0e74ca9f024b23a02c49cfb95109d"),
+    hex!("6f1e9a16ba5080f16658cf0c5839bc0b29e99372e71b6e97a224e65ee9c5f248"),
+    hex!("09d57011fe32c6c1790807a024bd516a8f56c16e489b52530767f912701b186d"),
+    hex!("601528834b96c7125b62e432997dfccc94f5826d2f035ce061380eae870d18f8"),
+    hex!("130ddddb5c62a36b686d13e672d60c9c8750d1317e2d9136dbf8c112a949189c"),
+    hex!("68ea84f8e84edd46c2fb2f58fc9bc9bdf4cc03ac70cc638ac335b4d4a3671b79"),
+    hex!("879dc5de5e7816c3efa5b500fe4ce96a27e87c1b4d842c0fa2a2d6ee6c411f52"),
+    hex!("48d5a3d6ff68491683c80524ac8c6b95308bcbb944dbb5b1f182fe03c5ba8b0b"),
+    hex!("6cc763aa2d0768f08bcafe195c39979b2693817010bac234f2e22dc9d098d225"),
+    hex!("6d93fa7bde6089e8703b2948a27d314b5aa876018cd8540041d9df1f95d79d50"),
+    hex!("aba046cf324d26a183eed76d073399590d475d322a4a7ec02e495e62ce7a545e"),
+    hex!("90e7c91bf93ec6b9fb25944cd20f289952cb2e1d05c49f418b9b1ea1b551dbfa"),
+    hex!("57389d64d7af7819159e007d2af10505b4ca7de662701cd7492516dedd692f40"),
+    hex!("5682c4c530c21a4f1e98d3426c89f56ecfbed4f659d7dc044905e51b40f9ceb9"),
+    hex!("50836abc6349701cdaa53731892ee0155a5348131156aea7d05a8fa46be0b5bb"),
+    hex!("15a4bf113e12e1631cab72b2f7e066aed9a05004e365037613d84a92dee48f54"),
+    hex!("948ee827485cc683fbfebf2959bcaee3a3acb34e6abedd1306b329fa904377a6"),
+    hex!("edea47325d48aa05503a4ee946aea9594ad632ac1b2a7789a092ea7ccc624fb9"),
+    hex!("a2fca6cfa196c6dfc4414ecc559cc4902da31163013e17770a1f4e1a0f7f76e9"),
+    hex!("68b72a62c440b0239605673c61dc6fc7ee6a7c69c48dd7461e471657023b0edf"),
+    hex!("36497e25224690776fe957e4c2df66951fc4d7e2af19f17419cea01f7d5e0867"),
+    hex!("d7ff2bfced61710fa45de980263914f6e3e7f65ecd2bff9faba4d979f06664da"),
+    hex!("0ec1555f2d86116afecd8e88dfaebaeb1f6db6fb262f430a3e5b21a118981b52"),
+    hex!("de35f229b54897d50bfaa45782537b4bfd3637ca2072e75c2c91c756894a20cb"),
+    hex!("3be099e636a5b4256754f6ab9bf3f9d72f737b527a857329767dda43322c1ff0"),
+    hex!("1b5857a0512dbc553198271fd88e7a27029f2d74ccccf497df541e79c1ca6c0d"),
+    hex!("0050af50514c6975d82b05269c9c3dcf0c22348e9bad1c976ccfbcbb4343e0df"),
+    hex!("c613a8fa0569d04414ccaf0867306d3fb552a489817f5b8727ca6142f1516964"),
+    hex!("784d03d7af57b8a5b2e03603d22b5daf80ec7914e69cd2c1ed71c45742d97e1b"),
+    hex!("38dc2f14e29b8e2c35146de520a33eed982126a195b6ef98ba61f55805900e2a"),
+    hex!("f0f84f2aa05c229e8a129523f930259ada0a96f0a74178476e0865a8bbc719f3"),
+    hex!("ecd05989700c621e99c23a197a0bbb53a4276d4204474e0b4be4fce8b520db4c"),
+    hex!("db2e934e746275d8f3c751b1d4f5b4c1c619b909a6fedba1a096c4e555ab7dfc"),
+    hex!("934a60e959f2dc4b6dc5fadc62f0ebdcc7f06d56ed57936fe53290f6135751f6"),
+    hex!("e1a654575b55d5870e5eae9cad9379a2021283e1ebe7eb846b0443aa534eef70"),
+    hex!("8fdee947d929ff508615ed8a277dfbad38d189406e9daab372907e81c7f5aefc"),
+    hex!("0b29615262691642e84be728c0d14765b6d19dbf90f2e032e86d692e07d8e888"),
+    hex!("883544f653e37d1905c2e0d424044ebb40c2f9674748e6770e1619979ac0d8fa"),
+    hex!("445fd02b0ea3c55f88f73f7104ed37ba63d5769b10bc7555df3ea66107cc9fe2"),
+    hex!("a167afc77f3bb7ff1f3c42614e2a9acbf2700fde0c26e6c40937c439d60b60d1"),
+    hex!("2a81435c84c71a3c342b4940ee399d6e3d287e852afcc1a8b1bfad1cc92c6567"),
+    hex!("1cfc449e9caaa0745b80cee08ac97ed6bab391c1eb9e3e0ef794eab3e44813bf"),
+    hex!("17fa7a65745b39385c0a1d9d6d70da1bbf30189e39602b2798ac5ed0f5ead79a"),
+    hex!("70dfe1b18cbc68b2d9086193ab332ecf9320aaaeaa69308f027b7c9671742028"),
+    hex!("e39a1d09012d27347f931d7928ddc753f3ecee3485a11354e86d723fb88c6924"),
+    hex!("47145df194805495bbc11e4c911eec545a0a3a4adc5c346a47467841c67333c4"),
+    hex!("e44efa590797e4ed882a64b4f6d3c6624b44621f16b0dc2f3dd50864587245d5"),
+    hex!("8a7bb64d78aa961652a52996c9f73200fa5b118ed308539dffe5d6266f05d216"),
+    hex!("f9546d000688ddbf0229021d94bb0ed7235e56b62ae7fcc39453d7c3e1dc2ab0"),
+    hex!("39ec3509d2f814fba0967682fa962863a998cd9bf373d6da2e35c86b9c9e3b0e"),
+    hex!("960fbaa46539e52efdf613131f4afa4500506c0dfb112d1bf101ee1673c76285"),
+    hex!("5da61140523f85431e8af99c544d20724e78941ba31449d3c5296d3bc3fb997c"),
+    hex!("a14b8d16ae83ca86aba6399ab6ffaedddbdc4c03091f21f14bfab10f1790ac68"),
+    hex!("72b2e308431ed94f973c602fecaf76e59c1fac271f346e42839b7cab384b358a"),
+    hex!("59db747ef1009e012d882e5a8c2e7872b7e4edd9d5198526aa9bd34c2e7a165e"),
+    hex!("e0ecde064052db5ed9788f2df3c8c2525e4df68d0f9ce6298521a481459b51aa"),
+    hex!("a115b66aaffff8ba2ce11a845fbd36f5eaf225525e17424947f8ca0ae08f3a58"),
+    hex!("6be1418ae4b80a9f7baab1208a2fdc31dcc83d242b006fad7a8810ef2bd91121"),
+    hex!("5d5722d05b6ee08c4d964d8bf4b01461b1a4c7d537c9780dec407d218745a403"),
+    hex!("48858fcf757f67eb476b6b0e2e6709463663e0792e17ce2bdb70829b924344a8"),
+    hex!("c93559995915de95447a0966a9a7e80aa250aab15667d6c5cc16eaa22fedb051"),
+    hex!("57c50db7f65751cd499066f80db1d1a95a040443ded2dd5ee94da6846413d7b3"),
+    hex!("3b1820a19e48769b25708292a23f215949a9b1df77e6a835bfc07303d7c17f74"),
+    hex!("ee43d38269fdad738c04d5db8d913105ff12b9ee5510d3ae7c5f2665bfa69ae0"),
+    hex!("3825ed3ab9c406739b0dbb74a104e60498ff8e0ebf228800a6423a5bdc331c56"),
+    hex!("51cd8340b4de2aab3edf3ad309c4effc8826e1a053b4f5618558ba8b36a65859"),
+    hex!("46480f7a535437ca0221ac02af5e44984d77d0eb5583cf3ff5285fcd6e3f7193"),
+    hex!("8e10684bf44e4f27b8c1b7b05f88393e15bae717116768459f5bb5e59132dfb8"),
+    hex!("6b88c1e4961f8d585d13a1c87f8c7b3af99d3074b63c9980b149b24fd9a2dc13"),
+    hex!("f3d6d3c7d75e2ed700e2b36115c59e8f4fd0e0ec0f344d1fdd51d3953cab7d3c"),
+    hex!("ad910f6ad9da1d6f2bb638b5c683828030dcd58a66d597cd063f0097f645939d"),
+    hex!("56aaf3016dd0540366fe2d7dc3ceba531e384e913d6dac1e5ef0db2563d651d0"),
+    hex!("45c265cc80269ef4b29de5334d2e565ff689483169431537da3ff714491c15f7"),
+    hex!("4476fd0cf66e42bfe0cca582fae03c510d37e17976f240b16ae03cb92b239d1a"),
+    hex!("98689af39b26b4e7de55c4a0a153e773ed3ac22ee7fc18d2497e066f1beafba1"),
+    hex!("fc6d41f7510b43fc367c8b5f660a0b3933a427bd6c31852ac1300ec23f61f9d7"),
+    hex!("23c9a478265ec6ede2754111f3280c5f6cdd4d2269cdea0c3eadea59c1ae8017"),
+    hex!("7aed97323c9388aac0e68607b2b223ec81173d7a36ab7eb4f7d4a6cb62e7f159"),
+    hex!("373df1445ea448f1c4eb987cfc03a089d36d13947de05d19f5edcea77a3dd537"),
+    hex!("66f7d5e8f1d9502e1de98ef170de684733f88f11dd4061a30a4f8d0b13d69310"),
+    hex!("6466b381c44636b8b12f9b940a21942e196c30e2b6ea45f38820c13c29daab5a"),
+    hex!("345eda711c30cc11988147ee8b307e16ac0e79ac6b2c6c1d78058a04a5ea664c"),
+    hex!("42eac7b9d51b13d463a3673c7e76551ae86b69d7f648f1cef266632fb883abf2"),
+    hex!("9f76fa01ce5a47dc5015b3802fb8bce42965bbb97a847e6e8c93120ff1eaf5f9"),
+    hex!("82aec285cb13920cc4c5b9d11285ce2d4ba958d85abf291ce077f37d9d8ffcbe"),
+    hex!("0b965c3eb1d95a5ec02a40ee1d292abe3acc772fb565f9ed185eef52ef88334e"),
+    hex!("839e6e499e59c129f1ee6ba06763b74ad8a251be0c7016cf5af941381d7e1034"),
+    hex!("1656db2caf89b389173eb51c35db6a291145e35301b384ccaf8716b22225112b"),
+    hex!("8133d1f164a7da893e8e560eb8a89b01941bab3a0017c5cadd6feec0bdd5395f"),
+    hex!("8f205cc133c7ca3a64d617a1b19feaf3e824a29877f830fef1faee76124a199b"),
+    hex!("288043bd2bd65a793db0c7462c5c19f2efdaf05e85ae0fae2665f47a4aee86dc"),
+    hex!("17a277c9aa0eee30dec06f12cbedd2437163740655dd4721ecf99e47b2040791"),
+    hex!("23f92d9202da6c6f720f97146d5361b88f2661d3b434717047263ef6617812ec"),
+    hex!("784530f5ef81f84f9edb66cd17f5d0d97422a315cd20b93ac21f5c09c5cfa55a"),
+    hex!("279164b9b8b8c80aaced41a24d00c6fa70c3c64dbf8bdb4a550f20f97e39d0a1"),
+    hex!("c7ca1eaba389cbb50633d54c3c32bd4b1868a3fdde7ea301f557c89f34160f55"),
+    hex!("2784d59b8770d582ab31e06859631829aa87a6767832ee2fa4daf3559872ed71"),
+    hex!("c3d6b6db8368b05e8cb976ec8d3dc955a416a1923f94fd52f8fc38eb012542ca"),
+    hex!("861384262e4d1f3a4b4dedb4fb7697795b6a996b2219fff6825b078d806d5789"),
+    hex!("343ce80d545ded52f63d498cfcaebd047ed8b56cf05e8599a25e375026504847"),
+    hex!("a2ae6d048d27fb67309cfadb24aed9638746cc4a8a65059dd6901b9e15f42627"),
+    hex!("c0806794d8c2b427807f27cf9ba65b14f46f518150832409dff60dd9aac642a7"),
+    hex!("3d8f25c09ffd9fb5c82984ae96572dc87a6ae856d8be27f2aefef17a1cd12ff9"),
+    hex!("e49c102cf54ddf71f4dcb752d48a2869fc1c14a131ffd839cb01be6bb594efb8"),
+    hex!("3e2f275aabdb0f4e89948f8340bc26334c01b18fd43e6ec48fdc9f47dfc87e88"),
+    hex!("cc7403ccdd506aab4c07d7870f26c993d7252c97202d7cef22e65f82921b81f1"),
+    hex!("1ba4523bba830172997b7efda9f20cf28c874e3f6f32e58ad5b555d2ebaf90b8"),
+    hex!("ba6e76c268cae1d1604f125c89009b9e4499da96bb369207a390d847fffefbbd"),
+    hex!("5cefdc3f8047ba658df80aaa9252359ee9a5f267cf10499f076660e1860a7e35"),
+    hex!("14ca209c4865010218bbb61e48d8eba495cd7b613f9c934e091f947074b89acd"),
+    hex!("57eda7a1adf006ce1091b5c9b2f6afa6d1427413d8b1718a47d112407090ebb6"),
+    hex!("98fd77eea7e635b732e4537d3ddccd59cda30534edd805ddf42452b6550bb185"),
+    hex!("fb64db24cfed034f17d3845ee580df3aee361fd5d6a5fb21ad8a2fb90370bc3d"),
+    hex!("0057662c467ca7ef690e82f0846ea502c895b8ff7a459847760a622100e91810"),
+    hex!("8fa4b706d74583a7eefc1f1ad0720dcd4ec9254f5dd1bbd39b9ca6dba3d420bd"),
+    hex!("f80b1efb073b7da9bd1f31cdaa5a6efb6696a90828cdb57c8b3373c73109fe7b"),
+    hex!("5454624c29c3ed01fc75b18c8d876b58732fb8c2abdc81701d9019cc5ed7e9fc"),
+    hex!("a8a55ee6bd0140cb5cd23d5fcc050acfdd1c507e5185c8081ef72c1ada8843e3"),
+    hex!("544a706abd993d7bf7ee4dc704316a0021c3dc3264ed0627db164e2640e40f06"),
+    hex!("e04c2da700b5469681edcdf24784ed95debbe7f76ab047f4ff14ca98d0f8a047"),
+    hex!("30a0ab967d8136e94f4577e5a005e4b291d4a5e6df9a201a84ccd7e6b0dce778"),
+    hex!("4168b7b3d3c09e9dc0aaa667f5e076aef2e7905b5976faec3b510c307acc22e1"),
+    hex!("7ad556a17ce5c81a3e77fa8dfc0d7d54310942b6d2fc0fb15357b62707351436"),
+    hex!("6315b0f405772ed71ac802c52ad83225ec6fd0232efbb4ff136b248461174a48"),
+    hex!("65efc85d4b85a2b8855d3279b29d88bfe8ec8550959c8469a8f298d68eb009b4"),
+    hex!("5f0086033513925dc92d7e9709e05785e5c9818a8f0df15aa18eb9d996edc6c8"),
+    hex!("e91cbbfed762f7638e0c763533446758373dd93277ddfebd0aa301a3813c99e9"),
+    hex!("e119bf7dc4535df6c81d2f8d9635c55fbfa97c201b4a6987ca34295e166e4667"),
+    hex!("a0242c4d8cf247abfca05ccbbd1bc29ceac467907150bf7afd286db7e9b073fe"),
+    hex!("05a73e808f87aca4374e7d28d4856f00f415b2c8295e267ea0f5729779391765"),
+    hex!("33b27eb10b62ccf5739dcc459e59807b92bcd5993d24dbf794f38aacfe092268"),
+    hex!("f46330fa26679980ad001e23e62854bc61da3d001030afd639939a7ddba8f0bd"),
+    hex!("bc417be9d95a81835f5b69d38d9fb0a2130fbca41131e27bd9896307ae6d9fbe"),
+    hex!("26b15eaa5be41a693fc0bb0f5df58e23e1047cf06467a4e9055daebbdde3af32"),
+    hex!("95b04d28162caef5a4a2a0496762b5b45fb776b3c603078f87f6c9d3756f25ba"),
+    hex!("720c00acc36b817838cd85a884a87cbae1cc1f63b805e9d7ddddade1a91dfccb")
+];
+
+#[cfg(any(not(target_os = "solana"), feature = "blake3-syscall"))]
+pub static SPARSE_MERKLE_TREE_EMPTY_HASHES_BLAKE3D: [[u8;32]; 257] = [
+    hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    hex!("7c77f799e9b7f511d21c7c2e1e61e6a49416225a019d435e0f1b905b545a4f23"),
+    hex!("bcb9084ca69b8bbc89febc69d1cc41abcb0ecd4eea9686d473ff2b54d3ac4310"),
+    hex!("fa0e47e60a361e52d5ec41f6e8f4084c9d33419265929a7e07c3ad7d15393b8e"),
+    hex!("e6455e36e91acd8255bf10db7872888834429c14e88ad55862695e8c9d2f5d08"),
+    hex!("0db372551d4bab42d6f238822c7f8061ccf67d475e3309b4e9380ef5863f6bf5"),
+    hex!("ddb3d4556bd115053a8a5e24105e066cae5012e794d0d9ee8fc7a73dd50e89c2"),
+    hex!("814846d2934251814492c547cada79704089b1856e5f3e7d5f8242c541baae1f"),
+    hex!("37df9db673c1cb9c0a6e4c7ec65a91668fa9ae5635d1ac71d0a31739c3dafb0b"),
+    hex!("7cbecab8dda92e09d3cbbe7df154dcbccd38bd59df982315da15b798bfc792ec"),
+    hex!("a5e53800c2d76d479519920e7a3ae67b9d11bf1898c32cb8d6ad152ca2d67286"),
+    hex!("aa5af242ec354f8091b22de9e58bbf3ef32a0cd73f73f9fdab9ef251eb4089d6"),
+    hex!("d732f0a675481309c65d72479427e294db3377dfaca943c678d4992ef750c1a0"),
+    hex!("6c6bd476447f5997bdd34eb47a99b446dd554c2024f7d993bf152630fd80a775"),
+    hex!("02f5d2311578f0e8d05052b5c130944cefe791eab5a3948c8079ed79821463ab"),
+    hex!("274142723807360853c95f5493fe059409a6bd8446865856809a4648fc1cfb75"),
+    hex!("a0baef773226757697ca7f5be3a989785840641037935c404b454bc18b1277b3"),
+    hex!("800af3431f29cef0e328678fff0f4ea0ae65de6e0e7643dccb09f1556bb2aca0"),
+    hex!("569d62dba7a38429d969b3f56e59605e0ae9a95ab2fe15ca1d5fa320671071b1"),
+    hex!("fec5058f10c1861d270951a0f972f96e797abc13ba8ee0a80e014d84d1e483c2"),
+    hex!("ce32e7be3f3db8bc25586f62da269681e3e9cfa150372f4f6982a510d1d56fa4"),
+    hex!("e7e64c71499b561b0cb44a2c2a5b9c85c40ab4fc0ec55834fd184735ae5ff2cc"),
+    hex!("d6b86918e80a0d3c9bec5583c6db2c3c2bbebcedb677ca2d151d80ba69caed62"),
+    hex!("59f3992c2ace56324cdd61c8977d4e02c180fe95d71959cf44a1627c27fbc6e1"),
+    hex!("94153ced84c1359a406e3718011dcd9617d644678ce4ff86bd9a021ab2ef702b"),
+    hex!("d7e8c6f473787332a14d7cddfea58713b65c0cd1d44614303797ff0854dd3d35"),
+    hex!("af6421f921b803e918382b39dd06752f862e26f329cff162aabb821f6cc8c574"),
+    hex!("545eb077049e979574c8307aad337d3ed3bb264a1311f9a72617e83432345cbc"),
+    hex!("28c259662166f246b5c0211b5cff2113f309e1fe860e20092c7f5be090ca18e1"),
+    hex!("01e65a0cced1a059b5985113936f7965aa75dcb0756c1a03071d79b8a50452ce"),
+    hex!("eca60e70a2cfd1ec0d16fd360567c7feb24f6778a2209acf1c2a4d407ab9acfb"),
+    hex!("a0550eca5426db8ce5712beaa4ba3d8361fbb23a41813220ce562e6fac11eb39"),
+    hex!("f3929657342980ee68b2c6ea760c523bbfc5988256524fefdc37040c17171e48"),
+    hex!("14a10db166f9f4255e88a36c0710df49a3752348b52e47a5df09ce04aca390ca"),
+    hex!("6625e3e050d3f913180cfa5265d77c5d325ff58045654977df041d0f02bb4ecd"),
+    hex!("07cb527b7cf379605caa864892c1442cfcbc597b77ac629fa89c03a8f42a46ba"),
+    hex!("71b069b4ef55d7b68d0a009640501dc499710760eec82f463a275e3e0fb5729b"),
+    hex!("239724f1ae1a69da7a13354d0675ec42f7ebea327f4505f12ecb331092e994e3"),
+    hex!("5700d0d345c8c6654407d84c4ea8aab63bf15551010ef597ee9928bc10ac386f"),
+    hex!("4979141763186c8c794f6160abc19c675586de9ed9e97186e07a6fa9d87e8e42"),
+    hex!("7cbd240d665d950fdedbc7022182ee333304e3e2d78165193381615620753d93"),
+    hex!("b99b2821ecff5168e70282849dc16804db20d536c7080e5377f0cd923cd75227"),
+    hex!("9caebd14b476fce1dd6e38cdcaaf7b947ffd61588afdaa04ace9d4b744d091df"),
+    hex!("bf56ff63c04a89e8a672885c023c8d86e35d3dc5102c35e3ed3d90858f944bae"),
+    hex!("4d9711307df6a79167b7b8b37a801f176de04ab02f686c62fe2e89a3f42d0d28"),
+    hex!("025047d9c07e48368ea52d526e8c6d82e47dbf58283fb67fd198664d897282b6"),
+    hex!("14a26888d285b0854da358b0b0608b10b3255b04d3b20e6ed502e69a2bf621b7"),
+    hex!("5fd14352fb88c8cbb9e1447763b381daebe1002d1c29cf9f336ca2c123cd0aa4"),
+    hex!("3a2b9e17651b67f2577fd0ff2d8e43ea97f027544d8d19269996339f9a4d0992"),
+    hex!("5b7e4ab548205df2e56e760f03162c7a96836c29c530b2ce374b0ac967de6c39"),
+    hex!("a2b9aa67fab4313fb66be8b66da049202081697cbb688d650f10f487cea5b449"),
+    hex!("11f9d869ddb83137238e336da5c1bf9041e480180caf0e98fc6c0f15bff4a958"),
+    hex!("f84b2e507759fafc28d0c141b56b25eacab1e3c859059d14dbeb2f8be5457f0b"),
+    hex!("7ac2b81d43748c0d939321ce96e5e097539026b3b8bc89c82ae8c371a68de831"),
+    hex!("61a5dace85561ceffc10df4fdbf412eb386ece5c77eb7f1f6857b43667c4c6a2"),
+    hex!("c400345ae3a8742fe19a33b9db5cc3b3a76aa9bfc12c2a67906eb169f4f8ad6d"),
+    hex!("3945ba5d0722a6a53ab5b025e035b3940928ebce4f87a43174d67d5cafc0e8eb"),
+    hex!("4652aa9ff8180382de4d30c6863b5f79a61a7652bd57a79966907b247acae971"),
+    hex!("8e6994f7af3059ca3d5ae0fb92908c897bd9f74060cb3fe91628a3794fbd92c0"),
+    hex!("4ec94c865ba5ab380d0767573ef735b9748af3919cde63e0f8820a28c729d8b8"),
+    hex!("5418b9a49ded8973ae98640d811a0b2617c8b5e3979b1f0d903e5f5ae2c2c8ac"),
+    hex!("4e40b8b87ce2bc220824523d6853620f96dd56c4a1a254dfbb730ba87a0ede12"),
+    hex!("000f31b8b675a9751a32f00c52ff6d322a23ead3706caf86c5cfc8a63351e823"),
+    hex!("a0e1e75906c040c2a34db5cf8b8d9cfa1251be9542b37535395d93b8035707d8"),
+    hex!("225db70b378a566807e66518654b061faf2053eeca70a205d94bc5e5bb5edfcc"),
+    hex!("001057fe165219bb8f279f710a12f2e608fdc5864f63b85287041b01dda3ccc8"),
+    hex!("57ad21307f8eaa86b64d5591c758b87a95e0b705c402f5ee81d32c45582ea4fc"),
+    hex!("8a7c3667ea1c26ad0a1a0c255e2d9e91666fa4136aa24a78928b27821dc2d914"),
+    hex!("2e8d81fbd009cd39978e64a04e000094dd01837733d26b360b4a3e3984a49ff1"),
+    hex!("e5fedf9e55867576bdebfa24ed46858f6c82466cd3ddab5b63cd5ff3e2875ba7"),
+    hex!("daa1834ba66b2cc4a36bc09d1d74e5381f664356988e1e3d04b4a16dddb047dc"),
+    hex!("f5ee6a61e40bde682022575f06ccfa12526c53f39eb0b6282fb86bb6f6794400"),
+    hex!("488dd42c495cee920b7c94c8dcada2ffb7db4ee1c9bf266b7ac02a54c6a333de"),
+    hex!("549b2446986d68285aec0de9645630a59eee25280feec867bdee46ad3fa2bb76"),
+    hex!("bcc920c9ba2ac515099a1cf926fa08afdedbdb928fc69208cd82a74d7f0380ea"),
+    hex!("10a1a8fdbbbb8fd411d6062fd99a92b46f7338b6efcb5b22fe30bb92a934f6ad"),
+    hex!("2149a8fc76c0c2e70253d2af9bdd9617da8672c017a454e25359f8ab74c26cb8"),
+    hex!("fc02a5da64d88d8fed6554ba223cb787b2ff67efc5fc07c8f1ae88705de0f0a2"),
+    hex!("e762144efbb8ecda3e9fe56f72dc52e3e4a9bdea5dd9b401be6b9295dbcffb8d"),
+    hex!("e05f95d980f3a4f792b289a28509b111b697c56285a6bc2061a1bcbab4e79bf6"),
+    hex!("d3557a818d982d68ddaf5d21429dc2d2f57f35775ef00f9f3f52bbacb74fb8e4"),
+    hex!("cb94373214d3dc1348dfe25def693391b3c895885d5e2b4140dcfb4cb3b96da3"),
+    hex!("d3a159accaf4a2fd1b7792d5f4590eed45d8c7abb9245f1d06ed758b3378993b"),
+    hex!("57681d4cfc604eb1eee5547d8b42656c43d3995076c494e5bc432b2ba1b9fc1d"),
+    hex!("4607b42dd721f5cde9d4c04371e38175ad534516a26a276d2465dbb3c3cc05d2"),
+    hex!("116ddf69971fa928f205b4196b0b709746bf7e5d0ff63f9ef5764d05b11a22bd"),
+    hex!("cb4f8967a6dc098e7cc4a51570b1c0f70cb82c177b2c4e63505ce26edec036e6"),
+    hex!("80c61b4da0370ea121768bfcd8ea4c711b76271378592fbb6581c996a8ddbe52"),
+    hex!("08cfe9e00a0d23ad70331047a59499d0bf748bfde191f8c0bab69dab94cdb286"),
+    hex!("0f4cbdab3cd26a2328dd59f8c59f36ddd9933ff77bcceb6cb3e0ad2ca5076248"),
+    hex!("6b914641235d7953147eb97a7303757fd51567f88498bb6d2d73a32bfcf0eb34"),
+    hex!("0fb4303ffe5e78302120e86d8a45ae453c39b6638de67629dff69f28a3889dbf"),
+    hex!("cc5cb08d454142649bef76251b7ed657e5e31a64a2b6cb1abd7cab07e06facd7"),
+    hex!("f26ee58330f5358eb3fdc66af63c0181ccad89f6be3a7e995f96c7d24d23b7fa"),
+    hex!("1c5222d6eae35eebc64ac344631b4bec336ecf857ccad8b0d28aa2fa6dc43b36"),
+    hex!("f08fc83255784c467dc2f7466eb6793f4d49c8bb4b9ad9a298823e6b2d8df462"),
+    hex!("fa93292320b3cb2c710d448ce6ef7220983604085cab96d8271d541f120902d4"),
+    hex!("349b980bd9c3faab15c7b9d409d95d57abd7cd12eb902bb5360513be1197aeda"),
+    hex!("713b7c856c0d1e10f1e30ca9c118739af646030192acea06a87bc523dc3adeac"),
+    hex!("938167592d6968f7eb29e29aa3527000864781678e3c375be0cd7f103bc58369"),
+    hex!("8aa81d59a3b7ac9ba04b4840cdbbec8de963f8d1bd11a3f92bf26d72da7ee72b"),
+    hex!("98911ff0caa5bf965aa6b0d4916ac150790916bec1c53814ef9ffcaf785dec3e"),
+    hex!("a702bd851780b8cd36af34cbc264105be9d9ecdda4951d317677db53731497f7"),
+    hex!("4f4fa748b9b77d72b6ba783052d2702d5c880325b7136ff103b5a8f46bb11fae"),
+    hex!("cf3549c6b0c32e375172a5dc0745a2a296cc8e623bbd95ad0df5cf009fa58f90"),
+    hex!("1717cebfd2738ca9a67fc0e57e085b5cbad416eccdd1200f490cb4ff58a7a088"),
+    hex!("8a745f9bc6e99e567120f296f3ce155e5f23a86f2ddde9baab9c83b4980c51a7"),
+    hex!("ff3582c902e3c30fc498d5399f362128049a0615b6fc9773782d74984f282528"),
+    hex!("ac70d23fcfa9b1d40f9dd0763e1121d6bb2377c49316b34acb74790a4ff20df9"),
+    hex!("30de29868f2196f819b4b438b20ff9c6d7d2a1bbfc71363332fba606f774567b"),
+    hex!("6df97dcc51a9d1824d29fa47b92ff4f3ae127491672e9e840e964adc8b10b625"),
+    hex!("c0a2190816e96205a4eec9b05cbbaf060a3f1a043ae8f3d873c9a3bf309a781e"),
+    hex!("006a04085cc4b5279d300b2f8c3d9706e48910d05427b4c88d13cc869de375e1"),
+    hex!("30e5847e74a8570bd50736d105c31eece71d38077b72f91a45965e211651be68"),
+    hex!("dd00012e54249a2a3c84582e01f6f1fdd670dc15a297dc52a45e92ecbdc7abb1"),
+    hex!("72c937f1a970140f4b7715bcc1f380ad3351696d286be7936f343f20300dad42"),
+    hex!("7b8bb69c6ef8be0009c769897a58c474c04b3db464815a1b83f94e76dd49d69d"),
+    hex!("cf43a3dea1c214b07b35bc19366b4b369a1324eb92f410cd057fdabd9d007648"),
+    hex!("667421de167edb1716f71903167dfe54995b5e29fd85a1c9b50d3c2af857b871"),
+    hex!("a2c93f5cf25ac2a81dec0441a233378df069158dfbaa43310dbe85388ea8389b"),
+    hex!("89a101e6d693008665d1e999213451378316455901a24c64e0843a072d154c7b"),
+    hex!("8a12a6106a4cb7fbed4ce0148388be02544883b396b33ea004de4efeb6019c35"),
+    hex!("ff31ac6c451f2bfabe04199a14723c5a6b111803464aef1006edc8eda1401410"),
+    hex!("3f603d24c5b1e39eccf247918e3f03d66a94a5e30dd1b1e6e2ca191eeb8f509e"),
+    hex!("63a0665dfa6285e1eea3bb4935a7d7c20df4f522c1591aeacdb095f7ab135875"),
+    hex!("26f760ee60b449d3b143a897725bc8d82b1e13c4b6142c39772f490bef725fdb"),
+    hex!("8eadbc78511b2660c4a593acff1f15502362666b4d4489f2d7fcc5aed51de959"),
+    hex!("33118861610ecd2f5c1f519b0fe7e8184de9939872932ae27ed663973516d919"),
+    hex!("de015b9d009c0e2d1af7a81b675ed70485da8fae1c719fdad0993beaa77fe348"),
+    hex!("0af71111ed7331888fa6cfcafcd993f481fd15fb7f2f613adf69b0f45b5705eb"),
+    hex!("9eddc75075bf2a28d9a6277d808c606f722918f16c62cb68377eeb7dcce86ee5"),
+    hex!("3f948f3228494778bbf96edac8cc88efe0ef088b097e2684dae5efd55c93f0f0"),
+    hex!("740e50a861ebc8ef46a66f2cf5e7daae1dfaf3f6e98d807a415c0126aae42344"),
+    hex!("b612a0aa1565c4d3df668c48552419d8654a5abbfe252a8070730a383cfe435c"),
+    hex!("9f4b61259c1798003799edd7d1866d5b1855c788ea86ab05855bba767816a2a2"),
+    hex!("c349ebcb7974503b5e6b2a285b534d2cd884aefdf7d07337eea18921c4f72963"),
+    hex!("0a71d88e8a597d97a8287d7abd98273d3a0770463eb23f51fc33d5b44a0cfb67"),
+    hex!("04fb8fc8059947d8ef18b3dc1c04642298bf9e2fc4b9b76097fb97405b576fd8"),
+    hex!("bfa69024455f1bffa7ee6bdcfdcc685b87569b2521579f6fb5a843b31f54dd65"),
+    hex!("b77fd5016192dcf12fa83bb77143508ea2064bc43e03e8a89a3d1515526757c2"),
+    hex!("98a314fad9bf765e4bee77e622ce99c2861dba6fb29f604607e6097140055f4a"),
+    hex!("147974f802049c7edbcaee49288e9cc0fad3778dde1e4b99742ebed625efc648"),
+    hex!("ed88f8ef9774719025d4c86a08b3c0b29c61744e1313d1a0bf9fc28a67f6b793"),
+    hex!("ea9bd8bd85e28a82eba839ee33df26d809e1bab7afd139fc2616b2c2bc7ae984"),
+    hex!("d5b90fdee6487b09466846130fac5336955305c57164db739a4dcb2717d4fd5f"),
+    hex!("eabd6fdb32022efca89b56e72906a173a79d2af8744cb2fc3b6eb95323dadb33"),
+    hex!("7e117cf404939eb82568298b02a10b99017f5af246b21f3707309400cca5df90"),
+    hex!("93eb2a941026404456cdd2e485aef064d74f4aed15bd7832ce716e5996c3ddd9"),
+    hex!("2aec26cbc9bd57a4c0cec15f4e20a7868a02fe084de184468ee3cd22285ca083"),
+    hex!("2d4a8069ace5f4bb7f1c0f47206c5cf9ff6adc64adae65e6cc9f143c91089420"),
+    hex!("c96521121a3cfb96c9c6ad7a0314801ac14ee5b142fc932cb41d612fa98f4e10"),
+    hex!("273e125a7b9a1a2d815e8f36d3944c839fddefbf2b43adafb955e29849bac788"),
+    hex!("558ce7defbbbc0dde7e06e53bb3565c009cc028c0939e93d136f7ecc756f4648"),
+    hex!("5595a3d9e1810774757bbe0a93b3904b22471849363eb7af61406dbed10ba081"),
+    hex!("af1431fef984f85923d6b4cd6e44deff15c34cdb453161a08b31aae3d20230c9"),
+    hex!("fdc671714ee27a1792e0fb9352bbd48f3b880e42b378aa085674a07dcf65d6f0"),
+    hex!("b6cab5bc926f8fa9ea492ab61109bf039cf1195f5d9962e222987a8092e136c2"),
+    hex!("80d0834fa26dc8a6b14ff14686e7b64ec4e9fce589751c2143589abfdc8dc382"),
+    hex!("a8f59f7d54f4d4ef6b4e19fcee7a1d21da17fec958319b154a98d2cc0de1e9c8"),
+    hex!("5134a6828c462fd0ac37f0496cbb3a7bc61708a5882499830ab6353f95d2a55f"),
+    hex!("8d13fc4b3feacc3f2037df0feaa3c028b63711f03848732b642545eae899d1bf"),
+    hex!("6cdc167ac284f45ccee5076b1c3700a9dbeeed5e80ee4c3617d5bb8f7a740e1d"),
+    hex!("d59dbc21c5c14395379e5fbd482cb00aa2efd6160157e18548dd6cbddf9e7da2"),
+    hex!("3e6e4933354ac23480d7eb0852a891fc9438760aed41ab8497a722301ca2614c"),
+    hex!("5e928513948d68a230038cd97e26293ebd1696b7d335b704200603fbe63d6538"),
+    hex!("6c27ac29b54edcb7d3b6a9d309a70b8e5e8623b7190ab5cdfdc5b3c5ef2870df"),
+    hex!("d2b4d2e7f36702d217364c0dd1d406eff57b1e560b3ba400219cac5d04b8aa36"),
+    hex!("04bcbe93c7bc45ae541fedba07f2304dab8114c3173fc282227fbcd97a0529b1"),
+    hex!("2b60ceba7c3eb2be9b166bf47c7749e0c5ddf3ddac2903349e48ac812ee63366"),
+    hex!("042c251bcfc617811c8bb78e0ae0b690b13e92e04cd8989f5a5c37085f4e199b"),
+    hex!("8cff40456cea2dd0eaf2e7404a3b8a7d34fb4b2638aa57aba78363481a6c75a3"),
+    hex!("bc787e9ac4bb14745d953d7ca1601e4ac65e1ed696a5c27a5be772dcdf769772"),
+    hex!("6ec97577f763fd9e65926f81d41c770a8c611be5557043d7863dbc14dc5a457a"),
+    hex!("877cc578b3cbb55da21d30da270570a79dc5cf8392acb4ac61b08c04fcc6ae44"),
+    hex!("a4a90984d18d40fc29fdc8c5ce009f481b9a3a8285f48dcb76f954252dc8e9a6"),
+    hex!("fd47104ea124dfeb357ebf4bc9b9960ab926a588172e4f1781a5d770f541e179"),
+    hex!("a198a227069d1939bb43808bb0144386df5f623649e3867d579baacb58ec5feb"),
+    hex!("d5862245cf9bf0c70b40a8060f96eef022ba8992319c4b7068feb15d2a7181c5"),
+    hex!("cba5b0f184c198bca7ff9c045bfdbef3d5f5d88abf22dbe51e96b138e781b42d"),
+    hex!("279296aab662df18f2183b365e8442252d8025a51ee4441a33dffe044c98cedd"),
+    hex!("1d881b58f7bdcd10909add66f704af0db61921335ccb413b325af31ea1a346b5"),
+    hex!("86609ee1237b38230eaeadf74dd01319cd502da0ae0801e57fd502f13ec5c6be"),
+    hex!("d6374563555309cb1b97ff42325f704e24d025a37cc1baf915d094c5121925d8"),
+    hex!("d0beba6ade8bca3e0ce0a0d039b2253e299ffdd7314806a553eb8e89b5ab5c69"),
+    hex!("fc53d5fe168cfc9a5488bdc96a8910d23146bae346b7d360423c4ac62e20a746"),
+    hex!("1bef928725290549b8441051fefe9636ee02a086049ee2d4088ca716bb627069"),
+    hex!("be33927d1ba836b42a7b73df4e33f66f98fcb5ebac64791dc044d37ba25ce51c"),
+    hex!("9c9a0d56f32392e0af8d34077d292112f08c567aa2ff43910dbe45b8f450045e"),
+    hex!("6ccab152ee91ec1f50bf5ea252e0f25b0cc315af51de79b0d7922c67d8027603"),
+    hex!("77103c661e031ed8a7e707d098df720afd9caaf902fa2fe60d6938c293cdb32c"),
+    hex!("bef2fd0124c1d9b6acaca048607c03332b12552beb8424dbd1bf847267698aef"),
+    hex!("953368f7061f7ffeb44456c11d57c7a3acca22bf83824f252dab931dd7c2cfa4"),
+    hex!("aa2f0d62fdcbb4d2f54e1d93e79b52502face22c90f77c4992be25377a8f15c9"),
+    hex!("974c1a0b6686f7cc93cbc7d31b71402e067f34d8898daeeeb475442b8e341aec"),
+    hex!("9028138a022ef777840e212df3d9b7b688cea51c10dc20bf54555bdcb517ce8a"),
+    hex!("35c898dcad59201fb5ce0aff62904b8e3cc062db4f92f0425c220fdbda3ba816"),
+    hex!("4d13202374b5b564f1c91620ffc34d2599c5c479249750f7452c3a48a84b1454"),
+    hex!("0e459cca9d59bfcbf4ca7d9f4ac106489c4912aacab9418e0dd51456fef6e535"),
+    hex!("2ab80dad68b60867fa8249f8e1e35fe0a318bc2cae9c14acd7c1a1c5f564faf5"),
+    hex!("ba1bcc7ae591ac3697da12a75356eb3145c5a2cf201cd5194e1931544f524ac1"),
+    hex!("8ee76ce4d099cd7a4f75fb77781396c0e7088d4d4b7082605f7233fdac18c7eb"),
+    hex!("223b884f9818c9d7d68d45ce3b008ece5f5e2fa8bbc37eee291739679b46337b"),
+    hex!("90e8d2807074fd2f7fb930772ad75b3b917de8ea422e0ce6b86316707fb20ffa"),
+    hex!("a456a6b2c76828529b7985d315404c09d06ac932a6dd4333a2f2a187c40d7684"),
+    hex!("82752f1346292635b1e23c46d9f2f75e95f6df757c1a23bf4f0eb58c012fd07f"),
+    hex!("c528871cf1e9ccf351f7fb65c464af1231280dc8537255a483029c09649c29ae"),
+    hex!("63cdab8e06b87ecb3528d863f0ab5b6eb034adce93b19a4daabfec53d32937d8"),
+    hex!("d075794de0ebef69145f589c13fab246d94df9ebb9cfac288b8823dbd111d5b0"),
+    hex!("8665af2a03a5c4917048523ec6c525fab1735c04bd60a6b9070ced8824f89ddf"),
+    hex!("2a72140d369b07f4b0ce2794587785067533f1e8aceccf509e9e9d1a35f28b12"),
+    hex!("9c22a477d3ec768113e3248bfe7b0251699be7eedb7f6d054fa9df745d37f266"),
+    hex!("5b72070f213c8dfa4ff894e3bdb8d3934493e91bdfc830c989cf7998d4ae101c"),
+    hex!("0dec92763b7870c95067f5f56676e683ffae842fb4476bdd6e0844e29b760fae"),
+    hex!("8916e8cfaaeff056a529ba9315f7b20da89ee727e7e0d03436a7779a2fd052ec"),
+    hex!("fe95c508c229ac129dc8323bafe44f9763d49a1d068aa6f61bb94c4d62809662"),
+    hex!("6732d649e2c9ee269d3773a9581c2cce78be8791fcfe92fc601620338c349809"),
+    hex!("2fc46465b6f870d5cbc7b91946634285451e6b6357ab9d0a4a7ec8eb820af3fc"),
+    hex!("a2abfdfced9423f21632d66db875ef6edabcdfc05a9552b8f3a4bb4395b9c95a"),
+    hex!("25b41781330eaf7afc9b4429ba7d3c9e605e6305888466b004b54482a298eb17"),
+    hex!("8b5921164c7ab91f34bae31fe9d8ea9c0b225aa0dfb85d0793c9581f4feb5088"),
+    hex!("1044e904aaf3ca1949e6b72b5adceaa8af451dcfca5a26f6b014ab08a9cf0217"),
+    hex!("05ed4e3a0e2963e8b0cbb74698daf0f89e7a2277dbc7ac22a40447b3da40920e"),
+    hex!("376a1a1ba9b644fb60ace1f3c8582c2c416872be8cd6ce6929f3f7309f5a1fec"),
+    hex!("638821c9d566bbeea38af388503c6f195c3295a637efcbb0c17c775a96aeaa21"),
+    hex!("1b53330a4841a54035a530b72bdccab15a73dff7a1f4b22e1a5a915684e0806e"),
+    hex!("fcdb9420e59a85bde429005e4cd60875ebb49721cef8015315713d2c6b67b177"),
+    hex!("fc437491303ef3cd44e51ed66af316057a6ea9c04f96cea71fe943fa45fb9882"),
+    hex!("12ea6537a20da1c4d778b1375fa406fb9cf53ef6edf41cc8e8c70ec525fd59b2"),
+    hex!("6dc5bb9fb534a07c3db08c78c8dc75f6501e9bbee1d2d2304c8a664881cf1981"),
+    hex!("35eeb17f43b49e2b2f5bb71fb6929af67e9f0bf759273241f10fe57c6836d9ab"),
+    hex!("afa58c2ec7213586f96cff4b4cdba99f119e93e6142a8f9e9f479e3297fbfad1"),
+    hex!("173915a72e18baa0894b8dcb85c841ab0b1620527099b681ec08cfd7bf783023"),
+    hex!("c593b3b0718d3c475256d5785e063bfb0919148a7ea4e3e6c5a5ee7efcff5974"),
+    hex!("31a75a58c66b39ac9e9ae887a2ee0433a93c4ddc731b9e423e73f32528b5d299"),
+    hex!("2fbe7e99893f3b599638eee9f997ac0afb33f84e564bfb1df98c8be28b0fabfe"),
+    hex!("6efc76ddd38b343b2680046790809d04be21ae3e4b911e6f2e50a1b5a4775e6e"),
+    hex!("b45ec3d5da6879cbd758efcf720291d50375866c78f47181ce978e8c3f22634c"),
+    hex!("579d483eae2d315e942ef097a9423c6ccb85df46fd2136239603e9c08bde08b6"),
+    hex!("ed7419bfd4b3491a83a00d2547d3b1988ad9c03996ebaa7d4c2a4d9ea4178fba"),
+    hex!("c1f9f6fb2882423ebc730c1ba09979993cf7f0b1e81e84d5e26c4514700869d6"),
+    hex!("64feed655b9d19b5c41884f82c33c6dcd96186ad2252092410d6d6a6b2920c42"),
+    hex!("e585d095a542d81775d891de65bc27688c9c18c5b58a1cd442bc07bc815e5b3c"),
+    hex!("213e39253521e3fa285f82ae078f11525d9b0c9646e1783f43101b2d1f736a5e"),
+    hex!("ad7a6a516a0c43ef245e893f99dbed124ead778c87bad61a5481d0aab653bd67"),
+    hex!("7e53d5e8c32c05061bb79011bf699eeb61a3d39c9797ac28f10a3701526df56f"),
+    hex!("c529c2c5974c867a51e04c054f08f956020bde541db3fea66ea95eba2aa64a10"),
+    hex!("62d307fff8b2631263f270a3236619f5c11fe6343c340aa8dfe899fcf092bf9d"),
+    hex!("f4c2039a857fac00a6d7cd01b46e8d382f8414c45da33800bfee92c44e3cb69a"),
+    hex!("dd56f2cd9709d888f809b26f93df51f1a98e1a6aabb8501ba75a182c94cb8c95"),
+    hex!("a8bf475c809a3f812e0a06ea8e311c0c04e567d58c2e31824dbfc0f560c68847"),
+    hex!("35dacb6283fbe1f5b94cfc90495005d4757e3e6cd3ae5e94a4bbb1a6b7982b97"),
+    hex!("a6e7c1304e5867d0fa04cb50c95f7336f6eade1178fd31fb82149975f0efb1b9"),
+    hex!("fbe3450d83a5764df82386781db5ff3919133ce05f65c1d35baab16c8c77e690"),
+    hex!("e82bc7f483cce552bf304b7a4d4e1cdc01882cc25f226837e498ab7f2a044beb"),
+    hex!("64d24725d39b56739fcf4e9b003ed65e1c5031b4ede5c0c36239819ba5fade48"),
+    hex!("824e74c4cd4e43724cb29509e7beb1d4c3fd70ad8742b7b7b37ceeeadbcf8c18"),
+    hex!("39db8ed9176dd1ff20f9d4513fcf2a91eb530c7603154290f5033d399c36d431")
+];
+
+pub static SPARSE_MERKLE_TREE_EMPTY_HASHES_POSEIDON: [[u8;32]; 257] = [
+    hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    hex!("290c65a9dd68f7b72e7cac0a7ac54a92f50155ad789ae8db9352364c299cb6ab"),
+    hex!("23b1f9ff42af034ee588942d58ed6141c954fb76f40676224080dc2822aa9aeb"),
+    hex!("24f7dfa1e2e15c96206999238e383c58d0bcc06237c6e11dd56dc567c1a4c21e"),
+    hex!("05ffcb22e552f178bd351b20a7a5f3c538f42e67422de0f00c4ea1b807d6c7fb"),
+    hex!("059ed6ca00490305f81da8f0f1a57a279a8b563344ad753865a900e04f828ef3"),
+    hex!("16ed207094f120df74b1c48021aa6a35d6655446e200fd8daab36deb12d713b1"),
+    hex!("1378c5d935e99bacd301ca9efed00a98b4677ef80f5782998dbe2df479af16db"),
+    hex!("0335f9867a2f30caa2373f996b2bd547f57ff9756e1936b3a6c7b57e967e4bb3"),
+    hex!("18addc7d92de4d69c2b6230a92c3233fe8fec3409830cac3313252bf5c2d742c"),
+    hex!("3023cc0ad27fd233798fcdb24d1325dd88fa367491dce4705eabe3e38097c152"),
+    hex!("147f27a07cafd2fc78e9c5f910e29798e26a8a8bbc03363680f573a4ad6b8478"),
+    hex!("1cd09e36e38a0d4787664a13b79793b32ee613f95007220b72fadc366a70428c"),
+    hex!("27dbf279456dc89448d13915d25bc22514ea8ef7aac5317019b97a91fbd1ee9e"),
+    hex!("20008fc83e306ca951777bf7652b055d9d4f34e61911d8c9082d0e4f81c68a77"),
+    hex!("1aed8daad22882a40f30811b098ce6b1921f3764b5f12137e8d3865737ecaad0"),
+    hex!("28a4ad87cccad9ec3791499d22008f9ea526089edbeccaae2c7a421206f65927"),
+    hex!("120b50fe688313722bd662aa711a0f45cd78bf64e7671621c05c2f201a15356b"),
+    hex!("08a97c3086ab99335930648c024b3464c05c904b51384eaeb0e26699cd41445f"),
+    hex!("015b2d41e04f01025617af825d54544c4a18e0c3d40899d79c4f7d9af46edbd3"),
+    hex!("1c234de9fa9e94fa6bc74f4d94a77391bd24a86cde9535affa90c8a5b50403d6"),
+    hex!("0257a82b36b029617f888930dfc5432cfa39bd3a9539734a5d6d3ff21a2e0c65"),
+    hex!("207498456084c346e7e854c9b091bf3f51160dac4f0ac5eaa0d7e57e5b3cc020"),
+    hex!("190e7b7e3e8bd9064ed6d6aa29d2dbd33f1a5414321d8bcbf1d1165ff46c2b23"),
+    hex!("000ade70571437741eb9987f1238b11e630d41e9d4ffaf6b80d7a5dbdf386623"),
+    hex!("2550b33bf4de85fa9f4556fea86962b2813af3d4f15ff09b3798e455cbc9b302"),
+    hex!("0c99d9e6e12080577663a7d8d99bf28b170f8bf724cc784c94e2cd31f10b6d05"),
+    hex!("135366f319dc84c321c1c53d9ccd045ad58a888ab049789fcb30aa03f328fe92"),
+    hex!("191cb709142722c0fe5d9131296c6c217e8ba78d7c5735f56022d622a493b5f6"),
+    hex!("2d80fc39d51e648b3b7b911013b90f36bf0d245e36e53cf46b793cf993aa35de"),
+    hex!("2af2f6ffb904a32de57954d32f0e7b0b3c5426a52c404aff7d5d3eef42e10559"),
+    hex!("1f8013612c1af1973409d0fd06fb207e84076f57d1cd6f697db486a59ae7933a"),
+    hex!("1c20cef044f92d34e9ac3e97f92161cc5c99f6594ade6b5d9290105e72dc2670"),
+    hex!("1d4810b4212aee59db7012fd6ca363c3c609bff0220e57865a773abffe0ec4a8"),
+    hex!("1ca1d32336e80e1094315fd07a5d9f0301dd1da5e588cc78d3dec7ac2ab3f4a1"),
+    hex!("1eb749d445ba6390efebc3dc0bb8cc5a46b54cb81c64454440af4511bba2e82f"),
+    hex!("2fcb2df7d516b876b572b496750b7d03895d6b52f1a3e76ed82d531497dfee29"),
+    hex!("0c4ac5ecb74f2ba5030411fcf88712dadedc6de629c18279d6b8327f8f4435ae"),
+    hex!("0066999058f76d5f9e41efa289765f342be50d0bea387524b1bfb49177dfdf67"),
+    hex!("11ae5b3f466a972b1e6d95b174cb42518638b331a21518c2ff344205492f517c"),
+    hex!("124aaa200f14a7d765f67690a07afb619b8a93ce7638a120e98be3b45d5a5368"),
+    hex!("22af905c699e345e0d66b2e4a90e51f41173539355d950708d0b8ee641455458"),
+    hex!("1f3751c20759f8d77b7a9e18e1cb5839e7adbaf4a6375bf20b67fb3ff0f7e901"),
+    hex!("14b7f3bbdae0e1466f5878d2165cb11817a51d84ddd2de2b808ef8dfa4be0d6c"),
+    hex!("0b7f3dd3c7ceb352deae547f37cfdc26070fe53f4f9939a1923bf4c25fa73239"),
+    hex!("0f0b38664062d442883f0fdd80d1a33e9ae1200df38b64379da5ff1814f42a9a"),
+    hex!("1f3fb82d47667b5bc0beb6a835172d2b54107367899ed0b3ecaca1bd3fe7fb6c"),
+    hex!("0ef7d072b6689d99369491dcedbc281e2c97714e3bf02004c1f21dcabfab0674"),
+    hex!("0dcf279f572312854c768b09934f176606769a14c38c8cdae9d5f4c15f27668a"),
+    hex!("289ed8756a881e10a34cf99742e4a8113e2d6535b3c4b0145f793e17b8ea2af8"),
+    hex!("070c28113d16dc9998546ba4b4475b006c961116c79518961f1bd56626582acd"),
+    hex!("0e19ea4fa38984377d08942218f9bba949d6584c9806f247e5fe1c72c4eb9434"),
+    hex!("095d91dc2cfb27edf736087bbbf0c407aa6a0f0efe3b926fb3b938d84bb2cc8a"),
+    hex!("0cf993eab5be87dcd054270a2bce4e1c1e329823b57cb84099f080aed5b7fd83"),
+    hex!("0b84ddb9f8e4067cbda8a5320dddcb43f3bd23bc49e4b6c81a29d96cdfc91cb9"),
+    hex!("2f0d140557071f439f00ad642af3187f3ac4ceb5a60d2c10e44b9c05abb8242d"),
+    hex!("2933c859b8cd545cc364562831673881050340bec6980316a06b0f51db978028"),
+    hex!("02106c23670f6ab82056f5720d6d02723d3a8e20e7bfee925a7e6f66f412b648"),
+    hex!("084cb2fd789351cc3cdf9b179d5dce415c50e509edb2412ccba0742c3c751b37"),
+    hex!("1591bcbba2d05fae9e92be23be23d85f7c41c678e8fb5a889fcaf25e8a15b5d0"),
+    hex!("15d776e511c66491d12f637392cd60ccbf18b9362a04347807880946e217132d"),
+    hex!("1823d429de11fc4ea38513fe7df917935f93b43d798d5f5d100b61dcff965f37"),
+    hex!("0f08dfeadee532033c001fbbd4e55f7245485878808d2c5e046bee8be72a968b"),
+    hex!("0d36e4cd8a4ecdab8cb988496f4c52a94f7bd90239ee1718ee3b3cef5c3cd35f"),
+    hex!("23582a1c4fd129e16c54e977d8049496270a43a96c86184bc367eece0758fdff"),
+    hex!("0a088ea4310f33db79942a3dac824db2ef49418c6c775e3b307898c8f718db6c"),
+    hex!("06f88e57f881fc90976cbae4a44924cdcdb94aff352237a2ecd180397762e692"),
+    hex!("0159cd5ae3b8033830c192cec8fdbdc090f8e87cb781a58d7ba8314c9af34a18"),
+    hex!("2fce9aa79ccfbf6fe3d79adc6de8bdc09d2fe58bff5e67d563a450d97c1d63a0"),
+    hex!("2a5a55ad7d95f6cf7491b19fa1492a892e8ad0a42366ad65d5a418136e078283"),
+    hex!("21a78ddba215f50287462cceaaa89df123e21b78ae81c11842dee67ad8c77060"),
+    hex!("1109e0453ce23a4cb52fa857c719e36e71ca9ef8baf2c2895307f830e7b907cb"),
+    hex!("06f948dc6fdf01fab4fbffc2d9ebf2a012a8a1cae62722d846c915e67b10960e"),
+    hex!("087cfd771ee5720011ef62ffb4ec24bbc49d52617805813e1a4e53ceb5e9bf23"),
+    hex!("0124c8994ee3bf09f71373348e44cb2f85d2b7d095b96877e0a273a55abc3364"),
+    hex!("2c077510ddae0aa49591d9c99a4e9ff413f144f0226b9834e815d47011fc9fa2"),
+    hex!("03c5c2dbf8912d419e552689a15d5258c374f45e8c24e3bfec77993dd94befe1"),
+    hex!("0e3922e742192394dc7c0ec10188f0ab7bb29faa1c759fcfdbaf8f22b16d4f8b"),
+    hex!("0d8df9074a9d4a8f56c785aa41df407d08ebf148e0879e746087f39208592337"),
+    hex!("074df0089140918bf412a4902087518bcf3615ef9c30137355a7ce810aae004b"),
+    hex!("10fc432d9c10f9b58d34424eb5934476efb8755e9608a43bae6bd0db5cb74a5a"),
+    hex!("020109f35907c53968010f7e3cd8537483ca1ff496baaecb62c69f5e663eaf79"),
+    hex!("19054e4118aa59ad8810cc03ecd0c626e4cdec5a65f56e7983c380280f905789"),
+    hex!("2b7b6416af08febc262face1cc09e4d7bb5d4a6cc1348a8624bf856124cb86a4"),
+    hex!("0370b25c2d7e87a3264b45b9e6f1800cd024158f27311e8df7e195c21df0fab1"),
+    hex!("24975a6191ac22ff920d113448943cb695cd39f36ffc7b586c8428c024e62efc"),
+    hex!("29c07546823a2bec80c09bc8765b18124cc649602db4ae10f6259aa182b85666"),
+    hex!("0e0866088dda3592a282f0222babb41b197271316d4deaa10db213fa4f8d4929"),
+    hex!("0c65b3d12768fda795515a00eb3cd5be52ab5483cf2846b678963d1ac8082aeb"),
+    hex!("227a6834f8644b8bb678394c9b663c1efaceca4de06e78f66b96b5f28a4b28fe"),
+    hex!("13f625efdad6b6b91bf8c27f6d501df79b528e7581161c0d7bb88c0494d507e4"),
+    hex!("1b6a05fef0d1e42318890f6fc4e7585d184bedc803afa02c752a4b2d1472cc29"),
+    hex!("139f92c2764b939155082bcbaa1800f2bd50394591c48f1f914a38313d231f08"),
+    hex!("1b3545ba83f4c337f8226148c02ccaff5041c86a7af626b181c746f1a046659d"),
+    hex!("1a8ffa356d0c2f2718ffb4c1ddbf953697b2327915bf1dee871e5e39ab9d736c"),
+    hex!("2327e83957dbdf7ddbc570494bd9ddce3c071d06080e7a014740862ad10a7482"),
+    hex!("1a304ce20e1ac594374f4abf0a5a32e527c21088650e59577d534db3cf5413e2"),
+    hex!("2a1bc49cc90202ed904f01f5267b65f94c5857c140a2b7777e4a27afbce16847"),
+    hex!("20668baaed73a6dfd96522a92b8eb9691272a1188ba482ad7277b6dc02bde929"),
+    hex!("2fbb852af0bf385c18043cd036918b8dc90ca87818b9e6403d6e184e1266c7cb"),
+    hex!("06e0c91b910ab27391c9986d845b306b663d275c37d46ae93ed8083bb1d78852"),
+    hex!("0df6f66103a0d45d20625d0e69557ee08886d784d1f2a8485106fcc42b1c6b93"),
+    hex!("0270b6ee40cf08d56a61930e063768d145001fc9c594cdff6af50a2fdb20706c"),
+    hex!("202ed1749508498300ae5f7c03e6588324e9e180dfeb8613424f4def62d6b85f"),
+    hex!("2d316d2bddc5f0ec9907f13e8814157710972bb90e8d050e180f624455d108e0"),
+    hex!("0910434d67bfadaee3922281ca159ed9eae3027b39db80b47545fad0713a99fd"),
+    hex!("175f6739518418e5251a0a02c386616635d1b843fee54988340c8eebb3253013"),
+    hex!("1acf321040288227a25418adb53e3415f5daa1d8475f04d370d93e3772a20531"),
+    hex!("102711281d3053adf7de2efcbf62406281e56351964864764a2e9f1767067aa1"),
+    hex!("230accfcfcdb41e7d05152c24509599819bb7b016c58fcb74626cfb4f8d9aa2d"),
+    hex!("02e7070297dd194ed9ad7e806020864e4a1023df51b84c772dabf3dd7301357c"),
+    hex!("1101b3dc5f9e482365f4bd62dff0adb7af88beb4b5ad074f5be8a5fef7acbac6"),
+    hex!("2da7ad6d02a987d9563b170c5823bec129f02c3b5d4a25c87c1d20a587cd05ad"),
+    hex!("23834975b798a1390a456662d95a7896aeb2c4c70dc3121f7816ad994d019783"),
+    hex!("22c0eb347e54cb18f99997ae5622c539345a88bd3c23908690863f1ecadea438"),
+    hex!("0f0f65afdc9383f20bc97ff3413f4a1967883e4e9ca4423da62f9eb87f2e2ff3"),
+    hex!("0ad1baa8b7534e20c8ebccd0fde81e0d6ebcf5cef4ab962b07493e51f811354e"),
+    hex!("259331011f2f3179d3cb0ebf63388ee29dd02d7644615e4cd333dca4e032e929"),
+    hex!("2b1e891724295544b7d499a523c01ff1ac54639e32de7b590f0b22f8d18b2676"),
+    hex!("143c32d8f5a1020177be737d4c0d862610af33ac95d570dcf09fc4ec534ba33e"),
+    hex!("00bdc218bf68d51f9bee6937b32ac33d216bfdf66bd1f8fd105a788084cd81ea"),
+    hex!("0b82ae0f71d4ee6ad512c3524f24e1b7d4ee2fd8ad4ec48014bfb2417e047e28"),
+    hex!("194d831ea9842522e78ab52ab631c00390ee5f7f0c95326ec06bcdac0a607a03"),
+    hex!("00abd7194025dacee21941e7cabc1d4a5b8ca43e524771d3d5058cbf0489f154"),
+    hex!("21b64cf9d7f199315cdc1dbba5f15e33bb132c661186652cf75a48f647b4bc35"),
+    hex!("195fe8ef06217086deac3dec8c3d2342481a2132259b4e7978082bd5a0d7ce9e"),
+    hex!("0517a41060c95a275042e7c6d8a7f9d3a5c7267b71af80307ff161aa41af329d"),
+    hex!("13aaa7dd6bc2d8667ce2f9e78e6bccad1d993e79ffd0fbf362bf3fef868e3bfd"),
+    hex!("2669baeacf53cdd4ec444eb2ba910fdf56afdd4a409e12386d587ed61bd4b2dc"),
+    hex!("1728d07ff7724ce973a62b40e103e1cbf8d358e86c1a0a368cb318d469ee94ab"),
+    hex!("0fefab06a5ff973fda59f6f543cad18edce26c7fb54d0115724e9698d3cbe396"),
+    hex!("300c15dd738a18f428a9815429f04d63757258ed342e45dd9ff15ab654898842"),
+    hex!("2848d56bd9fd11f3c130f09b942a7afd327aef4eb5061c0422ed43ae89a1fbe1"),
+    hex!("07080020db44f5d0530d73a5cd20ba5a7fb29403ec349b77e69b035bf46db7ec"),
+    hex!("161e295638378eeaf187dc7f6ba2405fcc2ecca4103fc06db5a90b1ad1c33103"),
+    hex!("1875d2e62dcc09bfb5a4ea23492d548f20ae271101c8df8d7b186ae4940e26af"),
+    hex!("195d2cd3ea9ac0970784149356e2d5b8e270c6034d889b3b8c79765fe5a2e311"),
+    hex!("1f36d311ebbabc4731a659ee517721937f25ff9fa6f489daf2e9151ade468470"),
+    hex!("1bfb32ebab71c024466eff6df3995aa8489aab91830fb8fb99751c757e8fd43f"),
+    hex!("2fdf9843e19df2f1c970bbf42f8b6109c994fbbf37eddaeb5e79813ccd0d9b53"),
+    hex!("1d7884b4077c6e67a9694f6c618b76e5aba96379f6ba1ef0509ee6027f3169d0"),
+    hex!("1cbee1e3067de612ac8ace734c768b0752c8f6feac44277e13c40f6ab749b363"),
+    hex!("018cbc417e2ac91e677630e20b5c89a03cf31188dcb1195d60c6614ab28906a0"),
+    hex!("04972e31f72eb53d4bb4213c5c1a43bea96d81b14b339e81bb03ea183e524f4d"),
+    hex!("1c901c85da7bc6b90d90fd43b808e49a8db3c6eb163db520e3e48af1d1bb0897"),
+    hex!("04accae856c878369157594b47537485e9e987fb22681b31d8e47a02f6be5894"),
+    hex!("16569a987dbd2dd10fe6ea78f7c289cc70c0dd5645c141498c14f61b638b7442"),
+    hex!("0e8432b8b6a191ea36326207d15d2a81c168d973f2491aa8882d656ae26a2834"),
+    hex!("034dc03c9b3f7ed35ce3b654f154480579352586537dff77e751b79eeaf791bb"),
+    hex!("09b4f21ba5c7ebb2e078de943c51fc6d0bef43700a6f1246e97e453b26e96fe1"),
+    hex!("28deb4a695c289b855096526f2417cbfd7c082ef34dfad1053f8ea667a708488"),
+    hex!("10a73c6c3f3c34ec2036d363c9580b74670a3cd33a4e33311868eebcf3767167"),
+    hex!("26e82f09a9200d9987908a68bab86e9f25a3fe5c507ce68ddefa426f67d46b4e"),
+    hex!("2084ad1143ba37c4f6f7fc8e6c5a863a6832ba505dcae1f2201b66105719e2c6"),
+    hex!("227990c734f85331ba45b7b5c29744f47e2360e02f024aac3b7a743778ca64e1"),
+    hex!("0816c2d8a1226b8a53a09572c441e9e56a1de61a4c3451bfdd3d78a38aca6bdf"),
+    hex!("2aea9021c6305abb99feec930e4d8633e24fb93ea8185483321a88a9197094e2"),
+    hex!("114fd722cdfa9c7c41b2847c7782b14a4d2d59bf74ad15f42db9cba93372916a"),
+    hex!("221e320de59c7df450a336570e4543fe5332b86c1ada65b725ac24a36c3db207"),
+    hex!("2c0b32a858887a73a010d344602081087030964d29f3e14da882a2385bee6877"),
+    hex!("218679df612f677e34eec3bfd99b83d7b093fc6897ac61357431bcc8f0a51c37"),
+    hex!("118f564e718bc5e8a62e576f08b7e6ce924bda7bd366c204d7571fe313f3a3ad"),
+    hex!("2c12a18dbad9ad2061d53bd18fe5401df612abdb03a01f229dfb4a6c9d2c609f"),
+    hex!("2dd87ed811e1e83f464834dfc2837fb855643721a3bd4058fb1a6e9276058040"),
+    hex!("105ea3475bd73809ca0bbc906b97cd2be2311f95321a7c4df8af54d051da16fb"),
+    hex!("035ed71743e6be3a186993736363e6059f0d26ef073e1e8a058c5add2781fd25"),
+    hex!("1517a1c52322aa46315f920d3803d12c8323477018f6c4b4c6cc9d03eb390cb6"),
+    hex!("0d7911c564ac6861a1d54e891965811a091a138ce4f00517a2cfbafb437b4893"),
+    hex!("16baee67d76b46810466c4d6e3664b9a0a2535df3c26a90f8cb669744a11ff01"),
+    hex!("08306b691c485d95beb11f1c2dc69d0115bb48eb3a864adfa693786bfaf07ef2"),
+    hex!("26ecdcb792cc203e5112e697407b3d43808fb34a2650cc8654143ec2adbaab48"),
+    hex!("141487134f7846b95ba8ba9e21dad24f607e260e1c7d3d5f5dc96385b6d6304c"),
+    hex!("1de4078f5a5477c9fd3d8e662cdfcacbd001d9b7d5223c6b3f71b7f39d0d940d"),
+    hex!("29fe6c6b869f3834a34f903c6c4600fa004a98e259d3d97bd6e124300256cf9f"),
+    hex!("0b4d2de020f01576b406d93c9f74714bc311de157ad398c9b6906c9c04abdee3"),
+    hex!("2f316742ef43a5e9a9bf561aabf0dc71f5a5cdefb9ba9b3433b8f1c47742e1fb"),
+    hex!("07e89242cfadf657c93da248814271094dd76c63e9b8dc4d3a9cf8f4d3c2e893"),
+    hex!("0e975d2f8fc8fd5a880f5f3d548078260b1f24c619b76326915d60041b3f3f88"),
+    hex!("01f7a2dfb0afe4e37c3cdf55a263b87785068c11ba9d56ae970552ba94949da6"),
+    hex!("03922bd11d004f7b7da99e94228182638867ad1d51516901806e645f3ec0db52"),
+    hex!("1ce6aea9f052ea811a19d4386f216c6a581e1827ae6b8f525c7d381736f0b1e2"),
+    hex!("2c11e155b3ebf09d77265d770885c70ef28b8014c30d14bc1e9b4d401eace97f"),
+    hex!("08ddb758ae25f93d1f183f90911b28a776533f972ebf09b8aed4289d97aa121f"),
+    hex!("19733cb826d090db29b50827f4200a6330da1bfacc1b49ec15c136fd1cefec16"),
+    hex!("01994823a0b4981fa23c9e7f8af8bdc9426ca2678739292186ab9421f0562fe2"),
+    hex!("0da91d4d0ff4042381070d69f5ef6ebbbf93d3874efb603a5618e0037fe5a257"),
+    hex!("2779f2101347c4d085afddcb8df169139f1dd9bd4d75f7fb14f4044daf7757a2"),
+    hex!("24076c89e1f19e5ad7b0cb2eb115ecd10c5c0ba477a302b3806187c601ba7947"),
+    hex!("196c7d8c9c437f2b85817b2dd5a386391248bb74df9a465b3f86beb296bd1012"),
+    hex!("2028706b79eb82ae8f1786b18354dc67e0d15615162701cff50f202c4b7e55ff"),
+    hex!("15c8217e87695268a064b15198b2367ecc2a1f1a4b5a18131f6184c4b0b901c4"),
+    hex!("00a90cef5f4ed64ae646f9041cbe5c25222802d10de477d020b8d7bc31e4751d"),
+    hex!("25edd47ab41dd7f12913b0503ef99c73b79f665454830c750fe5cb255bdc9cb5"),
+    hex!("029be5d7820c97bfe701e82af5707ab03547645f3662acb276c37c48e76f0d98"),
+    hex!("0c34afa84e6eafd8fadfc53122a47085dd570c8ba69fa71dcda05cb71409dd8e"),
+    hex!("022aca20254a0343ae2a87583bc17d219f9ad8535558880850f8d86b944233da"),
+    hex!("147f15e1eaa0afdc2520b6fb82e82baddec4477fd1e1a9c2630ce29e3a5b3a34"),
+    hex!("1911a8ea6e76716f5a89dbeccda72482b174d808b5b9911c224678a03b54e804"),
+    hex!("04bb21aa6ec24d807ffbe2b46d90fdcefe413f5f3f0ad6763608b17360a2bda7"),
+    hex!("130656885484627eabdaa8d133280d224c18ee9b9552d2a5e670ba6d33a2a18c"),
+    hex!("0ae3fad591a3b39d2ca01befd5cd1a11c2983f82a3520376dcd4558459087f37"),
+    hex!("25fcabd13e4fbbe34675e56ab7367b0fa6ce9bcae9038e35df37375e54d62df4"),
+    hex!("1bc8c4ecb525879535a7e6254c236727f2357e25eed7e85b677ae222fd87e526"),
+    hex!("275be7a81d5ec49e1d34bde0f1f2be6feb4a91fd774cd2cf4bd6c977b78c9e4f"),
+    hex!("2c768c174df5f96cc74afd8a986caa36a39385d8acf69093f205f72552dccef7"),
+    hex!("26e853f7dedbb1abcf9ca7ef34b970e3dabc82a822630e4072cca75068f0dda2"),
+    hex!("0f9ba2963800ecc13c27dfdc9daf810c7cf78488514cd5205a791f7e4709d719"),
+    hex!("2da7d484e7f80491be343db8b2c0490bb41547c946bbef64ed566ecb762eb1bb"),
+    hex!("1d4b1ff7fa0054bd3c00170bb65f80544cdfbaa89de02024aa8e22896573e152"),
+    hex!("15d17de84336125fe37c942e5d088847547027e57f766c06ad0549084eb6db7d"),
+    hex!("2f5ef3ebd155029e0011f8cbaf845ad1f1d1d712592ee1c4e6093b01db50c236"),
+    hex!("21b003201be02ef4d642beb9af461ac8fbceb7da19a60ffe8e97da840035ee6e"),
+    hex!("264db8618893a4e05201a45c29b3666cf1e3ae53b1e90607288572ce032c29fa"),
+    hex!("0068e7a154722e39f9095d904aa104767da7c2ce36a74b0b2049dfd4a7ecee4b"),
+    hex!("1ef4eb3b4ff3339a99e5fdc16e909e5c8680bc33ff1e02ffc1538610f6defd1b"),
+    hex!("2632c9514b788f991f01c430c716da9ec909fb4f2c68c3b53f011f192bc74644"),
+    hex!("2bcb5f6c61a840dd45b2f073a92a0b1da4f25ba17286d0248e67d1e03850fba7"),
+    hex!("185cd9c0f1f0ba986383f70ca3cb83dc635a86bc678bb5fa8c423814a307ea38"),
+    hex!("2ded8bbd4052ec38b9df3c878a01a95cfb5c8fdeca8e7b1ecace583593f6da17"),
+    hex!("15e5304b291976c923818c4d904f129ca4739c19ccdb16b6b17eca623f84a7d5"),
+    hex!("1784fb015fc960eb0be9d01a3de62cb0d80122acefb02553b75cdb9ff8d13bb8"),
+    hex!("0e00338fbc07cb15495179928b263bc1820685d8ee9ab8ecf61fab8d625933c0"),
+    hex!("10ce9996158e41075bd22b3a5bfeb8050d362a2a84ad705a720faac5157b82fa"),
+    hex!("18ef2886d443e41b8ee2b265135ea0f200c2e36c3074e0b49b2c966cb9948bd5"),
+    hex!("282e2a400b81e116dac55b71de86243409f727cc1384c6e33a5ee75f9167e536"),
+    hex!("28fe88cc5b0e03888e475d99be8cfa5f7277fa35b85ef88e06ed47c26c9fa95a"),
+    hex!("0712260467c7f2917216d7de03531898ca27e37af72281ab2e6667d44081c24e"),
+    hex!("2d92bccbd2e1d35c64b0b2ab926c6e47fc4cfb6eb74b94157c8700497938d26f"),
+    hex!("185708a5bcff01a46145196f431692d3e761f05d351ab354c264c434bb86f296"),
+    hex!("22f8e29958e417ffd3f6b5884ca88cec2464c01bb3d1ead4d1cdc8076bb8c92f"),
+    hex!("008ef8b85b11ec6cfdc06d851ea75112778e70fe5bc53daae34a49cad00f1280"),
+    hex!("265118aa87b1f69b67b58da6ce7c0e28e380aeb87de46f7f6ca1d3efe218844f"),
+    hex!("238e1587459ff8b85e50a42d96e08702508080bf16c4b17a93c125726c70f20c"),
+    hex!("2d750d24612cce3b82d755704c5b74465f8f0e58bcc47c99de1e94a97adeb1e5"),
+    hex!("1980bbc0519e4f7786fd25a16012e4a7e7b5bc17763eca142185f903af2456cf"),
+    hex!("0e95d9fe2be8cf6cb6271802e5cd5fe8bce13d514eeab093a92b6d563ecc91d9"),
+    hex!("27757f329d82be95d0e3de82a10a6215cb54953e21dd33a698b7998204fa0fa5"),
+    hex!("1b7a7dd46598f78216c4b740a25b4fb297775e6847dff6bb7965f733d1ed4c94"),
+    hex!("24a4fdcd7b9e7f7b6adc3e9534f26e6f7f09004814011122dd4664e0e5665c3a"),
+    hex!("16e36d3712570597741c4d2eb32bc11c80b35c2392648a7c75c60cc83b1d31fb"),
+    hex!("1baa83e4b40315e75a6ff6552f13d0da09965335c4c1297f8cd6ff1ea1e6820a"),
+    hex!("21f78cc35d7c248df7e654bfb259b3b59568a7b070420086153077069d2d09ed"),
+    hex!("061cc9e514afd3367b12b19f96abc5d355c9858451f11f68459e4be530469c22"),
+    hex!("0e2d2f7d51b8e6036b1bc43c284fe992be309be6187ca2b09baa7c1d5ac10268"),
+    hex!("18af7e8357c7cdf089fdb261557a0b1b3849f02b230904b5d4933f2fbd9aeffe"),
+    hex!("252ff8010daed37336d53976f8622cf1791273feefa74ebb0015dcff66b7849a"),
+    hex!("1ce9c52ae5a2e0ad583cbd8417b258676cc12e1cf2e8e3946443d33ce2d8bcff"),
+    hex!("1631da8c4b1daaa76330d011ec9a167647c034e08ccbec1b22777ad86ff27a23"),
+    hex!("01b389ccebec507411c77e49a7ba43bc4aa0c5c69a38baa612c96e5ed826a5b8"),
+    hex!("0d8ed7c7bbc98254e5022f423f90a5d9f1a0d9340950a4201a0e9467e43fcedc"),
+    hex!("0844e8ddb9dbc12cf3066182bc8771e86c7873709b85310f830bf11946e8f691"),
+    hex!("08b69ac51dcf5fc9b9c5a5d0eb9bd171b559f41acd25ef7b31cc3a4a5bf930d8"),
+    hex!("24ecef2ac290723b0b9454817c8353153680c9c97ecd7d180357b1df74fb0475"),
+    hex!("0bdc9a65166e8a64214194f42b21bc2e0a0922c7fc8c82fc4b045f5584466a34"),
+    hex!("0809d1d3d953c9de325d56eed9c1d3d1664a929810e7dc8f91ddcb55833b768c"),
+    hex!("2ccf40c48f39b4b5f9821202a3996cc4da90892296abc9d61808db8b43c7ed18"),
+    hex!("19540afe9d4ffd408cfc327c2f8a7843104382e28fbfb116049eb2795923fbb1")
+];
@@ -0,0 +1,493 @@
+use anyhow::Result;
+use serde_json::{json, Value};
+use crate::{HashingAlgorithm, MerkleError, MerkleProof, MerkleTree, PairingMode};
+
+pub const STANDARD_MERKLE_TREE_FORMAT: &str = "standard-v1";
+
+// A Merkle tree compatible with OpenZeppelin's StandardMerkleTree, which is commonly used to ship
+// EVM allowlists and airdrops as JSON dumps.
+//
+// Leaves are ABI-encoded values hashed with double keccak, and nodes are hashed as sorted pairs.
+// Unlike MerkleTree, nodes are laid out as a complete binary tree in a single array, with the root
+// at index 0 and the leaves at the end, sorted by hash in reverse array order. A leaf's position
+// therefore depends on its hash rather than its value's index, and a level of odd width borrows a
+// node from the level above instead of pairing its last node with itself. Proofs are emitted as
+// Sorted MerkleProofs and verify the same way. Only dumps with a power of two leaves can be
+// rebuilt as a MerkleTree with the same root, by to_merkle_tree. Not available on Solana.
+#[derive(Debug, Clone)]
+pub struct StandardMerkleTree {
+    tree: Vec<Vec<u8>>,
+    values: Vec<StandardMerkleTreeValue>,
+    leaf_encoding: Vec<String>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StandardMerkleTreeValue {
+    value: Vec<Value>,
+    tree_index: usize
+}
+
+fn hash_pair(left: &[u8], right: &[u8]) -> Vec<u8> {
+    let (left, right) = PairingMode::Sorted.ordered(left, right);
+    HashingAlgorithm::Keccak.hash(&[left, right].concat(), 32)
+}
+
+fn to_hex(b: &[u8]) -> String {
+    format!("0x{}", hex::encode(b))
+}
+
+fn from_hex(s: &str) -> Option<Vec<u8>> {
+    hex::decode(s.strip_prefix("0x")?).ok()
+}
+
+// Parse an unsigned integer from a JSON number, a decimal string or a 0x prefixed hex string into
+// a big endian word
+fn parse_word(v: &Value) -> Option<[u8; 32]> {
+    let mut word = [0u8; 32];
+    let s = match v {
+        Value::Number(n) => n.as_u64()?.to_string(),
+        Value::String(s) => s.clone(),
+        _ => return None
+    };
+    if let Some(h) = s.strip_prefix("0x") {
+        if h.is_empty() || h.len() > 64 {
+            return None
+        }
+        let b = hex::decode(format!("{:0>64}", h)).ok()?;
+        word.copy_from_slice(&b);
+        return Some(word)
+    }
+    if s.is_empty() {
+        return None
+    }
+    for c in s.chars() {
+        let mut carry = c.to_digit(10)?;
+        for b in word.iter_mut().rev() {
+            carry += *b as u32 * 10;
+            *b = carry as u8;
+            carry >>= 8;
+        }
+        if carry != 0 {
+            return None
+        }
+    }
+    Some(word)
+}
+
+// Parse a signed integer into a two's complement big endian word
+fn parse_signed_word(v: &Value) -> Option<([u8; 32], bool)> {
+    let (magnitude, negative) = match v {
+        Value::Number(n) => match n.as_i64() {
+            Some(i) if i < 0 => (json!(i.unsigned_abs()), true),
+            _ => (v.clone(), false)
+        },
+        Value::String(s) => match s.strip_prefix('-') {
+            Some(m) => (json!(m), true),
+            None => (v.clone(), false)
+        },
+        _ => return None
+    };
+    let mut word = parse_word(&magnitude)?;
+    let negative = negative && word != [0u8; 32];
+    if negative {
+        // Invert and add one
+        let mut carry = 1u16;
+        for b in word.iter_mut().rev() {
+            carry += !*b as u16;
+            *b = carry as u8;
+            carry >>= 8;
+        }
+    }
+    Some((word, negative))
+}
+
+// Parse the bit or byte width of a sized type such as uint64 or bytes4
+fn parse_size(s: &str, max: usize, step: usize) -> Option<usize> {
+    let size = match s {
+        "" if step == 8 => 256,
+        _ => s.parse().ok()?
+    };
+    match size > 0 && size <= max && size % step == 0 {
+        true => Some(size),
+        false => None
+    }
+}
+
+// ABI encode a single static value into a word
+fn encode_static(ty: &str, v: &Value) -> Option<[u8; 32]> {
+    let mut word = [0u8; 32];
+    if ty == "address" {
+        let b = from_hex(v.as_str()?)?;
+        if b.len() != 20 {
+            return None
+        }
+        word[12..].copy_from_slice(&b);
+    } else if ty == "bool" {
+        word[31] = v.as_bool()? as u8;
+    } else if let Some(bits) = ty.strip_prefix("uint") {
+        let k = 32 - parse_size(bits, 256, 8)? / 8;
+        word = parse_word(v)?;
+        if word[..k].iter().any(|b| *b != 0) {
+            return None
+        }
+    } else if let Some(bits) = ty.strip_prefix("int") {
+        let k = 32 - parse_size(bits, 256, 8)? / 8;
+        let negative;
+        (word, negative) = parse_signed_word(v)?;
+        // Every byte above the type's width must be sign extension, including the sign bit itself
+        let fill = match negative {
+            true => 0xff,
+            false => 0x00
+        };
+        if word[..k].iter().any(|b| *b != fill) || (word[k] & 0x80 != 0) != negative {
+            return None
+        }
+    } else if let Some(bytes) = ty.strip_prefix("bytes") {
+        let b = from_hex(v.as_str()?)?;
+        if b.len() != parse_size(bytes, 32, 1)? {
+            return None
+        }
+        word[..b.len()].copy_from_slice(&b);
+    } else {
+        return None
+    }
+    Some(word)
+}
+
+// ABI encode a list of values the same way as Solidity's abi.encode. Static types, bytes and
+// string are supported. Arrays and tuples are not.
+pub fn abi_encode(leaf_encoding: &[String], value: &[Value]) -> Result<Vec<u8>> {
+    if leaf_encoding.len() != value.len() {
+        return Err(MerkleError::InvalidLeafEncoding.into())
+    }
+    let mut head = vec![];
+    let mut tail = vec![];
+    for (ty, v) in leaf_encoding.iter().zip(value) {
+        let dynamic = match ty.as_str() {
+            "bytes" => Some(from_hex(v.as_str().unwrap_or_default())),
+            "string" => Some(v.as_str().map(|s| s.as_bytes().to_vec())),
+            _ => None
+        };
+        match dynamic {
+            Some(Some(b)) => {
+                // Dynamic values are written to the tail, with an offset to them in the head
+                let mut offset = [0u8; 32];
+                offset[24..].copy_from_slice(&((leaf_encoding.len() * 32 + tail.len()) as u64).to_be_bytes());
+                head.extend_from_slice(&offset);
+                let mut length = [0u8; 32];
+                length[24..].copy_from_slice(&(b.len() as u64).to_be_bytes());
+                tail.extend_from_slice(&length);
+                tail.extend_from_slice(&b);
+                tail.resize(tail.len().div_ceil(32) * 32, 0);
+            },
+            Some(None) => return Err(MerkleError::InvalidLeafEncoding.into()),
+            None => head.extend_from_slice(&encode_static(ty, v).ok_or(MerkleError::InvalidLeafEncoding)?)
+        }
+    }
+    Ok([head, tail].concat())
+}
+
+// Hash a leaf the same way as OpenZeppelin: keccak256(bytes.concat(keccak256(abi.encode(...))))
+pub fn standard_leaf_hash(leaf_encoding: &[String], value: &[Value]) -> Result<Vec<u8>> {
+    Ok(HashingAlgorithm::Keccak.double_hash(&abi_encode(leaf_encoding, value)?, 32))
+}
+
+impl StandardMerkleTreeValue {
+    pub fn get_value(&self) -> Vec<Value> {
+        self.value.clone()
+    }
+
+    pub fn get_tree_index(&self) -> usize {
+        self.tree_index
+    }
+}
+
+impl StandardMerkleTree {
+    // Build a tree from a list of values, sorting leaves by hash as OpenZeppelin does by default
+    pub fn of(values: Vec<Vec<Value>>, leaf_encoding: Vec<String>) -> Result<Self> {
+        if values.is_empty() {
+            return Err(MerkleError::TreeEmpty.into())
+        }
+        let mut leaves = values.iter()
+            .enumerate()
+            .map(|(i, v)| Ok((standard_leaf_hash(&leaf_encoding, v)?, i)))
+            .collect::<Result<Vec<(Vec<u8>, usize)>>>()?;
+        leaves.sort();
+        let n = leaves.len();
+        let mut tree = vec![vec![]; 2 * n - 1];
+        let mut tree_indices = vec![0usize; n];
+        // Leaves are placed at the end of the array in reverse order
+        for (i, (hash, value_index)) in leaves.into_iter().enumerate() {
+            tree[2 * n - 2 - i] = hash;
+            tree_indices[value_index] = 2 * n - 2 - i;
+        }
+        for i in (0..n - 1).rev() {
+            tree[i] = hash_pair(&tree[2 * i + 1], &tree[2 * i + 2]);
+        }
+        Ok(Self {
+            tree,
+            values: values.into_iter()
+                .zip(tree_indices)
+                .map(|(value, tree_index)| StandardMerkleTreeValue { value, tree_index })
+                .collect(),
+            leaf_encoding
+        })
+    }
+
+    // Load a tree from a JSON dump, checking every node and leaf
+    pub fn load(dump: &str) -> Result<Self> {
+        let dump: Value = serde_json::from_str(dump).map_err(|_| MerkleError::InvalidTreeDump)?;
+        if dump["format"] != STANDARD_MERKLE_TREE_FORMAT {
+            return Err(MerkleError::InvalidTreeDump.into())
+        }
+        let leaf_encoding = dump["leafEncoding"].as_array()
+            .ok_or(MerkleError::InvalidTreeDump)?
+            .iter()
+            .map(|t| t.as_str().map(|t| t.to_string()))
+            .collect::<Option<Vec<String>>>()
+            .ok_or(MerkleError::InvalidTreeDump)?;
+        let tree = dump["tree"].as_array()
+            .ok_or(MerkleError::InvalidTreeDump)?
+            .iter()
+            .map(|h| from_hex(h.as_str()?).filter(|h| h.len() == 32))
+            .collect::<Option<Vec<Vec<u8>>>>()
+            .ok_or(MerkleError::InvalidTreeDump)?;
+        let values = dump["values"].as_array()
+            .ok_or(MerkleError::InvalidTreeDump)?
+            .iter()
+            .map(|v| Some(StandardMerkleTreeValue {
+                value: v["value"].as_array()?.clone(),
+                tree_index: v["treeIndex"].as_u64()? as usize
+            }))
+            .collect::<Option<Vec<StandardMerkleTreeValue>>>()
+            .ok_or(MerkleError::InvalidTreeDump)?;
+        let tree = Self {
+            tree,
+            values,
+            leaf_encoding
+        };
+        tree.validate()?;
+        Ok(tree)
+    }
+
+    // Check that every branch hashes from its children and every value hashes to its leaf
+    pub fn validate(&self) -> Result<()> {
+        let len = self.tree.len();
+        if len % 2 == 0 || self.values.len() != len / 2 + 1 {
+            return Err(MerkleError::InvalidTreeDump.into())
+        }
+        for i in 0..len / 2 {
+            if self.tree[i] != hash_pair(&self.tree[2 * i + 1], &self.tree[2 * i + 2]) {
+                return Err(MerkleError::InvalidTreeDump.into())
+            }
+        }
+        for v in self.values.iter() {
+            if v.tree_index < len / 2 || v.tree_index >= len {
+                return Err(MerkleError::InvalidTreeDump.into())
+            }
+            if self.tree[v.tree_index] != standard_leaf_hash(&self.leaf_encoding, &v.value)? {
+                return Err(MerkleError::LeafContentsModified.into())
+            }
+        }
+        Ok(())
+    }
+
+    // Dump the tree to JSON in OpenZeppelin's format
+    pub fn dump(&self) -> String {
+        json!({
+            "format": STANDARD_MERKLE_TREE_FORMAT,
+            "leafEncoding": self.leaf_encoding,
+            "tree": self.tree.iter().map(|h| to_hex(h)).collect::<Vec<String>>(),
+            "values": self.values.iter().map(|v| json!({
+                "value": v.value,
+                "treeIndex": v.tree_index
+            })).collect::<Vec<Value>>()
+        }).to_string()
+    }
+
+    fn within_range(&self, i: usize) -> Result<()> {
+        match i < self.values.len() {
+            true => Ok(()),
+            false => Err(MerkleError::LeafOutOfRange.into())
+        }
+    }
+
+    pub fn get_merkle_root(&self) -> Vec<u8> {
+        self.tree[0].clone()
+    }
+
+    pub fn get_leaf_count(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn get_leaf_encoding(&self) -> Vec<String> {
+        self.leaf_encoding.clone()
+    }
+
+    pub fn get_values(&self) -> Vec<StandardMerkleTreeValue> {
+        self.values.clone()
+    }
+
+    // Get the ABI encoded leaf of a value. This is what gets passed to MerkleProof::merklize.
+    pub fn get_leaf(&self, i: usize) -> Result<Vec<u8>> {
+        self.within_range(i)?;
+        abi_encode(&self.leaf_encoding, &self.values[i].value)
+    }
+
+    pub fn get_leaf_hash(&self, i: usize) -> Result<Vec<u8>> {
+        self.within_range(i)?;
+        Ok(self.tree[self.values[i].tree_index].clone())
+    }
+
+    // Rebuild the tree as a sorted keccak MerkleTree of its leaf hashes, in the dump's array order.
+    // The value at tree_index t is leaf t - (leaf_count - 1). Only trees with a power of two leaves
+    // can be rebuilt. OpenZeppelin keeps the leaves of other trees on two levels, which a
+    // MerkleTree can't, so they return UnbalancedTree.
+    pub fn to_merkle_tree(&self) -> Result<MerkleTree> {
+        let n = self.values.len();
+        if !n.is_power_of_two() {
+            return Err(MerkleError::UnbalancedTree.into())
+        }
+        let mut merkle_tree = MerkleTree::new(HashingAlgorithm::Keccak, 32).with_pairing_mode(PairingMode::Sorted);
+        merkle_tree.add_hashes(self.tree[n - 1..].to_vec())?;
+        merkle_tree.merklize()?;
+        Ok(merkle_tree)
+    }
+
+    // Pairing hashes of the value at index i, from the leaf upwards
+    fn pairing_hashes(&self, i: usize) -> Result<Vec<Vec<u8>>> {
+        self.within_range(i)?;
+        let mut n = self.values[i].tree_index;
+        let mut hashes = vec![];
+        while n > 0 {
+            let sibling = match n % 2 {
+                1 => n + 1,
+                _ => n - 1
+            };
+            hashes.push(self.tree[sibling].clone());
+            n = (n - 1) / 2;
+        }
+        Ok(hashes)
+    }
+
+    // Get a proof of the value at index i as a list of 0x prefixed hashes, as OpenZeppelin does
+    pub fn get_proof(&self, i: usize) -> Result<Vec<String>> {
+        Ok(self.pairing_hashes(i)?.iter().map(|h| to_hex(h)).collect())
+    }
+
+    // Get a proof of the value at index i that can be merklized from its ABI encoded leaf. The
+    // proof's index is the position of the leaf in the dump's array order, as in to_merkle_tree.
+    pub fn merkle_proof_index(&self, i: usize) -> Result<MerkleProof> {
+        let hashes = self.pairing_hashes(i)?.concat();
+        Ok(MerkleProof::new(
+            HashingAlgorithm::Keccak,
+            32,
+            (self.values[i].tree_index + 1 - self.values.len()) as u32,
+            hashes
+        ).with_pairing_mode(PairingMode::Sorted))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+    use serde_json::json;
+
+    use super::{abi_encode, StandardMerkleTree};
+
+    fn encoding() -> Vec<String> {
+        vec!["address".to_string(), "uint256".to_string()]
+    }
+
+    // The tree.json example from the @openzeppelin/merkle-tree README
+    const OPENZEPPELIN_DUMP: &str = r#"{
+        "format": "standard-v1",
+        "tree": [
+            "0xd4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77",
+            "0xeb02c421cfa48976e66dfb29120745909ea3a0f843456c263cf8f1253483e283",
+            "0xb92c48e9d7abe27fd8dfd6b5dfdbfb1c9a463f80c712b66f3a5180a090cccafc"
+        ],
+        "values": [
+            { "value": ["0x1111111111111111111111111111111111111111", "5000000000000000000"], "treeIndex": 1 },
+            { "value": ["0x2222222222222222222222222222222222222222", "2500000000000000000"], "treeIndex": 2 }
+        ],
+        "leafEncoding": ["address", "uint256"]
+    }"#;
+
+    #[test]
+    fn standard_merkle_tree_test() {
+        let loaded = StandardMerkleTree::load(OPENZEPPELIN_DUMP).unwrap();
+        let tree = StandardMerkleTree::of(vec![
+            vec![json!("0x1111111111111111111111111111111111111111"), json!("5000000000000000000")],
+            vec![json!("0x2222222222222222222222222222222222222222"), json!("2500000000000000000")]
+        ], encoding()).unwrap();
+        assert_eq!(hex!("d4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77").to_vec(), loaded.get_merkle_root());
+        assert_eq!(loaded.get_merkle_root(), tree.get_merkle_root());
+        assert_eq!(loaded.get_values(), tree.get_values());
+        assert_eq!(
+            vec!["0xb92c48e9d7abe27fd8dfd6b5dfdbfb1c9a463f80c712b66f3a5180a090cccafc".to_string()],
+            loaded.get_proof(0).unwrap()
+        );
+        for i in 0..2 {
+            let proof = tree.merkle_proof_index(i).unwrap();
+            assert_eq!(tree.get_merkle_root(), proof.merklize(&tree.get_leaf(i).unwrap()).unwrap());
+            assert_eq!(1, tree.get_proof(i).unwrap().len());
+        }
+    }
+
+    #[test]
+    fn standard_merkle_tree_to_merkle_tree_test() {
+        let loaded = StandardMerkleTree::load(OPENZEPPELIN_DUMP).unwrap();
+        assert_eq!(loaded.get_merkle_root(), loaded.to_merkle_tree().unwrap().get_merkle_root().unwrap());
+        let values: Vec<_> = (0..8u64).map(|i| vec![json!(format!("0x{:040x}", i + 1)), json!(i * 1000)]).collect();
+        let tree = StandardMerkleTree::of(values.clone(), encoding()).unwrap();
+        let merkle_tree = tree.to_merkle_tree().unwrap();
+        assert_eq!(tree.get_merkle_root(), merkle_tree.get_merkle_root().unwrap());
+        for (i, v) in tree.get_values().iter().enumerate() {
+            let proof = merkle_tree.merkle_proof_index(v.get_tree_index() - 7).unwrap();
+            assert_eq!(tree.get_merkle_root(), proof.merklize_hash(&tree.get_leaf_hash(i).unwrap()).unwrap());
+            // Proofs index leaves by their position in the tree, so they verify strictly
+            let proof = tree.merkle_proof_index(i).unwrap();
+            assert_eq!(v.get_tree_index() - 7, proof.get_index() as usize);
+            assert_eq!(tree.get_merkle_root(), proof.merklize_strict(&tree.get_leaf(i).unwrap(), 8).unwrap());
+        }
+        // Leaves aren't in value order, and unbalanced trees can't be rebuilt
+        assert_ne!(tree.get_leaf_hash(0).unwrap(), merkle_tree.get_leaf_hash(0).unwrap());
+        assert!(StandardMerkleTree::of(values[..7].to_vec(), encoding()).unwrap().to_merkle_tree().is_err());
+    }
+
+    #[test]
+    fn standard_merkle_tree_dump_test() {
+        let values: Vec<_> = (0..7u64).map(|i| vec![json!(format!("0x{:040x}", i + 1)), json!(i * 1000)]).collect();
+        let tree = StandardMerkleTree::of(values, encoding()).unwrap();
+        let loaded = StandardMerkleTree::load(&tree.dump()).unwrap();
+        assert_eq!(tree.get_merkle_root(), loaded.get_merkle_root());
+        assert_eq!(tree.get_values(), loaded.get_values());
+        for i in 0..7 {
+            let proof = loaded.merkle_proof_index(i).unwrap();
+            assert_eq!(loaded.get_merkle_root(), proof.merklize(&loaded.get_leaf(i).unwrap()).unwrap());
+            assert_ne!(loaded.get_merkle_root(), proof.merklize(&loaded.get_leaf((i + 1) % 7).unwrap()).unwrap());
+        }
+        // Tampering with a value must be rejected
+        let tampered = tree.dump().replace(",3000]", ",3001]");
+        assert!(StandardMerkleTree::load(&tampered).is_err());
+        assert!(StandardMerkleTree::load(&tree.dump().replace("standard-v1", "simple-v1")).is_err());
+    }
+
+    #[test]
+    fn abi_encode_test() {
+        let encoding = ["int8", "bool", "string", "bytes4"].map(|t| t.to_string());
+        let encoded = abi_encode(&encoding, &[json!(-1), json!(true), json!("abc"), json!("0xdeadbeef")]).unwrap();
+        assert_eq!([
+            [0xffu8; 32].to_vec(),
+            [vec![0u8; 31], vec![1]].concat(),
+            [vec![0u8; 31], vec![0x80]].concat(),
+            [hex!("deadbeef").to_vec(), vec![0u8; 28]].concat(),
+            [vec![0u8; 31], vec![3]].concat(),
+            [b"abc".to_vec(), vec![0u8; 29]].concat()
+        ].concat(), encoded);
+        assert!(abi_encode(&encoding, &[json!(128), json!(true), json!("abc"), json!("0xdeadbeef")]).is_err());
+        assert!(abi_encode(&["uint8".to_string()], &[json!(256)]).is_err());
+        assert!(abi_encode(&["uint256[]".to_string()], &[json!([1])]).is_err());
+    }
+}
@@ -0,0 +1,263 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+#[cfg(not(target_arch = "wasm32"))]
+use anchor_lang::prelude::*;
+use crate::{HashingAlgorithm, MerkleError, MerkleHasher, Result};
+
+// Leaf version of BIP342 tapscript
+pub const TAPROOT_LEAF_TAPSCRIPT: u8 = 0xc0;
+// Control blocks hold at most 128 pairing hashes
+pub const TAPROOT_CONTROL_MAX_NODE_COUNT: usize = 128;
+
+// Bitcoin's variable length integer encoding
+pub fn compact_size(n: u64) -> Vec<u8> {
+    match n {
+        0..=0xfc => vec![n as u8],
+        0xfd..=0xffff => [&[0xfd], &(n as u16).to_le_bytes()[..]].concat(),
+        0x10000..=0xffffffff => [&[0xfe], &(n as u32).to_le_bytes()[..]].concat(),
+        _ => [&[0xff], &n.to_le_bytes()[..]].concat()
+    }
+}
+
+// BIP340 tagged hash: sha256(sha256(tag) || sha256(tag) || msg)
+pub fn tagged_hash(tag: &[u8], msg: &[u8]) -> [u8;32] {
+    let tag_hash = HashingAlgorithm::Sha256.hash(tag, 32);
+    HashingAlgorithm::Sha256.hashv(&[&tag_hash, &tag_hash, msg]).try_into().unwrap()
+}
+
+pub fn tap_leaf_hash(leaf_version: u8, script: &[u8]) -> [u8;32] {
+    tagged_hash(b"TapLeaf", &[&[leaf_version], &compact_size(script.len() as u64)[..], script].concat())
+}
+
+// Children are sorted lexicographically, so paths do not need to record the side of each sibling
+pub fn tap_branch_hash(a: &[u8;32], b: &[u8;32]) -> [u8;32] {
+    let (left, right) = match a <= b {
+        true => (a, b),
+        false => (b, a)
+    };
+    tagged_hash(b"TapBranch", &[&left[..], &right[..]].concat())
+}
+
+// Tweak committing an internal key to a script tree. A key without scripts has no Merkle root.
+pub fn tap_tweak_hash(internal_key: &[u8;32], merkle_root: Option<&[u8;32]>) -> [u8;32] {
+    match merkle_root {
+        Some(root) => tagged_hash(b"TapTweak", &[&internal_key[..], &root[..]].concat()),
+        None => tagged_hash(b"TapTweak", internal_key)
+    }
+}
+
+// Tweak an x-only internal key, returning the x-only output key and the parity of its y coordinate.
+// Requires elliptic curve arithmetic, so it is only available off-chain on native targets.
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "solana")))]
+pub fn tap_tweak_key(internal_key: &[u8;32], merkle_root: Option<&[u8;32]>) -> Result<([u8;32], u8)> {
+    use libsecp256k1::{PublicKey, PublicKeyFormat, SecretKey};
+    // BIP340 lifts an x-only key to the point with an even y coordinate
+    let mut key = PublicKey::parse_slice(&[&[0x02], &internal_key[..]].concat(), Some(PublicKeyFormat::Compressed))
+        .map_err(|_| MerkleError::InvalidTweak)?;
+    let tweak = SecretKey::parse(&tap_tweak_hash(internal_key, merkle_root)).map_err(|_| MerkleError::InvalidTweak)?;
+    key.tweak_add_assign(&tweak).map_err(|_| MerkleError::InvalidTweak)?;
+    let output_key = key.serialize_compressed();
+    Ok((output_key[1..].try_into().unwrap(), output_key[0] & 1))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub struct TapLeaf {
+    pub leaf_version: u8,
+    pub script: Vec<u8>
+}
+
+impl TapLeaf {
+    pub fn new(leaf_version: u8, script: Vec<u8>) -> Self {
+        Self {
+            leaf_version,
+            script
+        }
+    }
+
+    // A BIP342 tapscript leaf
+    pub fn tapscript(script: Vec<u8>) -> Self {
+        Self::new(TAPROOT_LEAF_TAPSCRIPT, script)
+    }
+
+    pub fn leaf_hash(&self) -> [u8;32] {
+        tap_leaf_hash(self.leaf_version, &self.script)
+    }
+}
+
+// A BIP341 taproot script tree. Scripts are weighted by how likely they are to be spent and
+// combined into a Huffman tree, so that likelier scripts get shorter control blocks.
+//
+// Two nodes of equal weight are combined in the order they were added, with leaves added before
+// any branch, so the same weighted scripts always produce the same tree.
+#[derive(Debug, Clone)]
+pub struct TaprootScriptTree {
+    root: [u8;32],
+    leaves: Vec<TapLeaf>,
+    paths: Vec<Vec<[u8;32]>>
+}
+
+impl TaprootScriptTree {
+    // Build a Huffman tree from (weight, leaf) pairs
+    pub fn with_huffman_tree(leaves: Vec<(u32, TapLeaf)>) -> Result<Self> {
+        if leaves.is_empty() {
+            return Err(MerkleError::TreeEmpty.into())
+        }
+        let mut paths: Vec<Vec<[u8;32]>> = vec![vec![]; leaves.len()];
+        // Each node is its hash and the leaves beneath it
+        let mut nodes: Vec<([u8;32], Vec<usize>)> = leaves.iter().enumerate().map(|(i, (_, leaf))| (leaf.leaf_hash(), vec![i])).collect();
+        let mut heap: BinaryHeap<Reverse<(u64, usize)>> = leaves.iter().enumerate().map(|(i, (weight, _))| Reverse((*weight as u64, i))).collect();
+        while heap.len() > 1 {
+            let Reverse((a_weight, a)) = heap.pop().ok_or(MerkleError::TreeEmpty)?;
+            let Reverse((b_weight, b)) = heap.pop().ok_or(MerkleError::TreeEmpty)?;
+            let (a_hash, a_leaves) = nodes[a].clone();
+            let (b_hash, b_leaves) = nodes[b].clone();
+            a_leaves.iter().for_each(|i| paths[*i].push(b_hash));
+            b_leaves.iter().for_each(|i| paths[*i].push(a_hash));
+            nodes.push((tap_branch_hash(&a_hash, &b_hash), [a_leaves, b_leaves].concat()));
+            heap.push(Reverse((a_weight + b_weight, nodes.len() - 1)));
+        }
+        if paths.iter().any(|path| path.len() > TAPROOT_CONTROL_MAX_NODE_COUNT) {
+            return Err(MerkleError::InvalidTreeSize.into())
+        }
+        let Reverse((_, root)) = heap.pop().ok_or(MerkleError::TreeEmpty)?;
+        Ok(Self {
+            root: nodes[root].0,
+            leaves: leaves.into_iter().map(|(_, leaf)| leaf).collect(),
+            paths
+        })
+    }
+
+    pub fn get_merkle_root(&self) -> [u8;32] {
+        self.root
+    }
+
+    pub fn get_leaf(&self, i: usize) -> Result<TapLeaf> {
+        self.leaves.get(i).cloned().ok_or(MerkleError::LeafOutOfRange.into())
+    }
+
+    // Pairing hashes from the leaf up to the root
+    pub fn merkle_path(&self, i: usize) -> Result<Vec<[u8;32]>> {
+        self.paths.get(i).cloned().ok_or(MerkleError::LeafOutOfRange.into())
+    }
+
+    // Control block spending the script at index i, given the parity of the output key
+    pub fn control_block(&self, i: usize, internal_key: [u8;32], output_key_parity: u8) -> Result<TaprootControlBlock> {
+        Ok(TaprootControlBlock {
+            leaf_version: self.get_leaf(i)?.leaf_version,
+            output_key_parity,
+            internal_key,
+            path: self.merkle_path(i)?
+        })
+    }
+
+    // Tweak an internal key with this tree, returning the x-only output key and its parity
+    #[cfg(all(not(target_arch = "wasm32"), not(target_os = "solana")))]
+    pub fn output_key(&self, internal_key: &[u8;32]) -> Result<([u8;32], u8)> {
+        tap_tweak_key(internal_key, Some(&self.root))
+    }
+}
+
+// A BIP341 control block: the leaf version and output key parity, the internal key and the Merkle
+// path of the script being spent.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub struct TaprootControlBlock {
+    pub leaf_version: u8,
+    pub output_key_parity: u8,
+    pub internal_key: [u8;32],
+    pub path: Vec<[u8;32]>
+}
+
+impl TaprootControlBlock {
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        if data.len() < 33 || (data.len() - 33) % 32 != 0 || (data.len() - 33) / 32 > TAPROOT_CONTROL_MAX_NODE_COUNT {
+            return Err(MerkleError::InvalidControlBlock.into())
+        }
+        Ok(Self {
+            leaf_version: data[0] & 0xfe,
+            output_key_parity: data[0] & 1,
+            internal_key: data[1..33].try_into().unwrap(),
+            path: data[33..].chunks(32).map(|c| c.try_into().unwrap()).collect()
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [
+            &[self.leaf_version | self.output_key_parity][..],
+            &self.internal_key,
+            &self.path.concat()
+        ].concat()
+    }
+
+    // Script tree root committed to by this control block and the script being spent
+    pub fn merkle_root(&self, script: &[u8]) -> [u8;32] {
+        self.path.iter().fold(tap_leaf_hash(self.leaf_version, script), |h, sibling| tap_branch_hash(&h, sibling))
+    }
+
+    // Tweak of the internal key committing to the script being spent. A verifier checks that the
+    // output key is the internal key plus this tweak times the generator, with a matching parity.
+    pub fn tweak(&self, script: &[u8]) -> [u8;32] {
+        tap_tweak_hash(&self.internal_key, Some(&self.merkle_root(script)))
+    }
+
+    // Tweak the internal key and check it against an x-only output key
+    #[cfg(all(not(target_arch = "wasm32"), not(target_os = "solana")))]
+    pub fn verify(&self, script: &[u8], output_key: &[u8;32]) -> Result<()> {
+        let (key, parity) = tap_tweak_key(&self.internal_key, Some(&self.merkle_root(script)))?;
+        if key != *output_key || parity != self.output_key_parity {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::{tap_tweak_hash, tap_tweak_key, TapLeaf, TaprootControlBlock, TaprootScriptTree};
+
+    #[test]
+    fn taproot_key_path_test() {
+        // BIP341 wallet test vector without scripts
+        let internal_key = hex!("d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d");
+        assert_eq!(hex!("b86e7be8f39bab32a6f2c0443abbc210f0edac0e2c53d501b36b64437d9c6c70"), tap_tweak_hash(&internal_key, None));
+        assert_eq!(hex!("53a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343"), tap_tweak_key(&internal_key, None).unwrap().0);
+    }
+
+    #[test]
+    fn taproot_single_leaf_test() {
+        // BIP341 wallet test vector with a single script
+        let internal_key = hex!("187791b6f712a8ea41c8ecdd0ee77fab3e85263b37e1ec18a3651926b3a6cf27");
+        let script = hex!("20d85a959b0290bf19bb89ed43c916be835475d013da4b362117393e25a48229b8ac").to_vec();
+        let tree = TaprootScriptTree::with_huffman_tree(vec![(1, TapLeaf::tapscript(script.clone()))]).unwrap();
+        assert_eq!(hex!("5b75adecf53548f3ec6ad7d78383bf84cc57b55a3127c72b9a2481752dd88b21"), tree.get_merkle_root());
+        let (output_key, parity) = tree.output_key(&internal_key).unwrap();
+        assert_eq!(hex!("147c9c57132f6e7ecddba9800bb0c4449251c92a1e60371ee77557b6620f3ea3"), output_key);
+        let control_block = tree.control_block(0, internal_key, parity).unwrap();
+        assert_eq!(hex!("c1187791b6f712a8ea41c8ecdd0ee77fab3e85263b37e1ec18a3651926b3a6cf27").to_vec(), control_block.to_bytes());
+        control_block.verify(&script, &output_key).unwrap();
+    }
+
+    #[test]
+    fn taproot_huffman_tree_test() {
+        let internal_key = hex!("187791b6f712a8ea41c8ecdd0ee77fab3e85263b37e1ec18a3651926b3a6cf27");
+        let leaves: Vec<(u32, TapLeaf)> = [1, 1, 2, 4, 8].iter().enumerate().map(|(i, weight)| {
+            (*weight, TapLeaf::tapscript(vec![0x51 + i as u8]))
+        }).collect();
+        let tree = TaprootScriptTree::with_huffman_tree(leaves.clone()).unwrap();
+        // Likelier scripts get shorter paths
+        let depths: Vec<usize> = (0..5).map(|i| tree.merkle_path(i).unwrap().len()).collect();
+        assert_eq!(vec![4, 4, 3, 2, 1], depths);
+        let (output_key, parity) = tree.output_key(&internal_key).unwrap();
+        for (i, (_, leaf)) in leaves.iter().enumerate() {
+            let control_block = TaprootControlBlock::parse(&tree.control_block(i, internal_key, parity).unwrap().to_bytes()).unwrap();
+            assert_eq!(tree.get_merkle_root(), control_block.merkle_root(&leaf.script));
+            control_block.verify(&leaf.script, &output_key).unwrap();
+            // Another script must not verify against the same control block
+            assert!(control_block.verify(&[0x00], &output_key).is_err());
+        }
+        assert!(TaprootControlBlock::parse(&[0xc0; 34]).is_err());
+        assert!(TaprootScriptTree::with_huffman_tree(vec![]).is_err());
+    }
+}
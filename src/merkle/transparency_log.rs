@@ -0,0 +1,464 @@
+#[cfg(not(target_arch = "wasm32"))]
+use anchor_lang::prelude::*;
+use crate::{HashingAlgorithm, MerkleError, Result};
+
+pub const TRANSPARENCY_LOG_LEAF_PREFIX: u8 = 0x00;
+pub const TRANSPARENCY_LOG_NODE_PREFIX: u8 = 0x01;
+
+// An append-only Merkle tree as specified by RFC 6962 and RFC 9162 (Certificate Transparency).
+//
+// Leaves are hashed as HASH(0x00 || leaf) and nodes as HASH(0x01 || left || right). A tree of n
+// leaves is split at the largest power of two smaller than n rather than duplicating the last
+// node, so the tree of any earlier size is a prefix of the tree of any later size. This is what
+// allows consistency proofs between two roots.
+//
+// Every node, including the root, is truncated to hash_size. Sha256 with a hash_size of 32 is
+// exactly RFC 6962. Perfect subtrees are cached as they complete, so any root or proof only needs
+// O(log n) hashes.
+#[derive(Debug, Clone)]
+pub struct TransparencyLog {
+    algorithm: HashingAlgorithm,
+    hash_size: u8,
+    nodes: Vec<Vec<Vec<u8>>>
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub struct TransparencyLogProof {
+    algorithm: HashingAlgorithm,
+    hash_size: u8,
+    leaf_count: u64,
+    index: u64,
+    hashes: Vec<u8>
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(AnchorSerialize, AnchorDeserialize))]
+pub struct TransparencyLogConsistencyProof {
+    algorithm: HashingAlgorithm,
+    hash_size: u8,
+    first_size: u64,
+    second_size: u64,
+    hashes: Vec<u8>
+}
+
+// Largest power of two strictly smaller than n, for n > 1
+fn split_point(n: u64) -> u64 {
+    1 << (63 - (n - 1).leading_zeros())
+}
+
+fn leaf_hash(algorithm: &HashingAlgorithm, hash_size: u8, leaf: &[u8]) -> Vec<u8> {
+    algorithm.hash(&[&[TRANSPARENCY_LOG_LEAF_PREFIX], leaf].concat(), hash_size as usize)
+}
+
+fn node_hash(algorithm: &HashingAlgorithm, hash_size: u8, left: &[u8], right: &[u8]) -> Vec<u8> {
+    algorithm.hash(&[&[TRANSPARENCY_LOG_NODE_PREFIX], left, right].concat(), hash_size as usize)
+}
+
+impl TransparencyLog {
+    pub fn new(algorithm: HashingAlgorithm, hash_size: u8) -> Self {
+        let mut hash_size = hash_size;
+        if hash_size == 0 || hash_size > 32 {
+            hash_size = 32
+        }
+        Self {
+            algorithm,
+            hash_size,
+            nodes: vec![vec![]]
+        }
+    }
+
+    // Hash and append a leaf
+    pub fn append(&mut self, leaf: &[u8]) {
+        self.append_hash_unchecked(leaf_hash(&self.algorithm, self.hash_size, leaf))
+    }
+
+    // Append a leaf hash with a length check. The hash must already include the leaf prefix.
+    pub fn append_hash(&mut self, hash: Vec<u8>) -> Result<()> {
+        if hash.len() != self.hash_size as usize {
+            return Err(MerkleError::InvalidHashSize.into())
+        }
+        self.append_hash_unchecked(hash);
+        Ok(())
+    }
+
+    // Append a leaf hash without a length check. Use with normalized data
+    pub fn append_hash_unchecked(&mut self, hash: Vec<u8>) {
+        self.nodes[0].push(hash);
+        let mut level = 0;
+        // Cache every perfect subtree completed by this leaf
+        while self.nodes[level].len() % 2 == 0 {
+            let len = self.nodes[level].len();
+            let parent = node_hash(&self.algorithm, self.hash_size, &self.nodes[level][len - 2], &self.nodes[level][len - 1]);
+            if self.nodes.len() == level + 1 {
+                self.nodes.push(vec![]);
+            }
+            self.nodes[level + 1].push(parent);
+            level += 1;
+        }
+    }
+
+    pub fn get_leaf_count(&self) -> u64 {
+        self.nodes[0].len() as u64
+    }
+
+    pub fn get_leaf_hash(&self, i: usize) -> Result<Vec<u8>> {
+        match self.nodes[0].get(i) {
+            Some(h) => Ok(h.clone()),
+            None => Err(MerkleError::LeafOutOfRange.into())
+        }
+    }
+
+    // Root of the leaves in [start, end). Subtrees in RFC 6962 always start on a boundary of their
+    // largest perfect subtree, so the range is made of cached perfect subtrees of descending size.
+    fn subtree_root(&self, start: u64, end: u64) -> Vec<u8> {
+        let mut peaks = vec![];
+        let mut s = start;
+        for height in (0..64).rev().filter(|h| ((end - start) >> h) & 1 == 1) {
+            peaks.push(&self.nodes[height as usize][(s >> height) as usize]);
+            s += 1 << height;
+        }
+        let mut peaks = peaks.into_iter().rev();
+        let mut root = peaks.next().cloned().unwrap_or_default();
+        for peak in peaks {
+            root = node_hash(&self.algorithm, self.hash_size, peak, &root);
+        }
+        root
+    }
+
+    pub fn get_merkle_root(&self) -> Vec<u8> {
+        self.subtree_root_or_empty(self.get_leaf_count())
+    }
+
+    // The root of the log as it was when it held `leaf_count` leaves
+    pub fn get_merkle_root_at(&self, leaf_count: u64) -> Result<Vec<u8>> {
+        if leaf_count > self.get_leaf_count() {
+            return Err(MerkleError::InvalidTreeSize.into())
+        }
+        Ok(self.subtree_root_or_empty(leaf_count))
+    }
+
+    // The root of an empty log is the hash of an empty string
+    fn subtree_root_or_empty(&self, leaf_count: u64) -> Vec<u8> {
+        match leaf_count {
+            0 => self.algorithm.hash(&[], self.hash_size as usize),
+            n => self.subtree_root(0, n)
+        }
+    }
+
+    pub fn merkle_proof_index(&self, i: u64) -> Result<TransparencyLogProof> {
+        self.merkle_proof_index_at(i, self.get_leaf_count())
+    }
+
+    // Audit path of a leaf in the log as it was when it held `leaf_count` leaves (RFC 6962 PATH)
+    pub fn merkle_proof_index_at(&self, i: u64, leaf_count: u64) -> Result<TransparencyLogProof> {
+        if leaf_count > self.get_leaf_count() {
+            return Err(MerkleError::InvalidTreeSize.into())
+        }
+        if i >= leaf_count {
+            return Err(MerkleError::LeafOutOfRange.into())
+        }
+        let mut hashes: Vec<Vec<u8>> = vec![];
+        let (mut start, mut end) = (0, leaf_count);
+        // Walk down from the root, collecting the subtree we did not descend into at each split
+        while end - start > 1 {
+            let k = start + split_point(end - start);
+            match i < k {
+                true => {
+                    hashes.push(self.subtree_root(k, end));
+                    end = k;
+                },
+                false => {
+                    hashes.push(self.subtree_root(start, k));
+                    start = k;
+                }
+            }
+        }
+        hashes.reverse();
+        Ok(TransparencyLogProof::new(
+            self.algorithm.clone(),
+            self.hash_size,
+            leaf_count,
+            i,
+            hashes.concat()
+        ))
+    }
+
+    // Prove that the log at `second_size` leaves extends the log at `first_size` (RFC 6962 PROOF)
+    pub fn consistency_proof(&self, first_size: u64, second_size: u64) -> Result<TransparencyLogConsistencyProof> {
+        if first_size == 0 || first_size > second_size || second_size > self.get_leaf_count() {
+            return Err(MerkleError::InvalidTreeSize.into())
+        }
+        let mut hashes: Vec<Vec<u8>> = vec![];
+        let (mut m, mut start, mut end) = (first_size, 0, second_size);
+        // Whether the old root is itself a node of the new tree and may be left out of the proof
+        let mut complete = true;
+        while m < end - start {
+            let k = split_point(end - start);
+            match m <= k {
+                true => {
+                    hashes.push(self.subtree_root(start + k, end));
+                    end = start + k;
+                },
+                false => {
+                    hashes.push(self.subtree_root(start, start + k));
+                    m -= k;
+                    start += k;
+                    complete = false;
+                }
+            }
+        }
+        if !complete {
+            hashes.push(self.subtree_root(start, end));
+        }
+        hashes.reverse();
+        Ok(TransparencyLogConsistencyProof::new(
+            self.algorithm.clone(),
+            self.hash_size,
+            first_size,
+            second_size,
+            hashes.concat()
+        ))
+    }
+}
+
+impl TransparencyLogProof {
+    pub fn new(algorithm: HashingAlgorithm, hash_size: u8, leaf_count: u64, index: u64, hashes: Vec<u8>) -> Self {
+        let mut hash_size = hash_size;
+        if hash_size == 0 || hash_size > 32 {
+            hash_size = 32
+        }
+        Self {
+            algorithm,
+            hash_size,
+            leaf_count,
+            index,
+            hashes
+        }
+    }
+
+    // Merklize from a leaf
+    pub fn merklize(&self, leaf: &[u8]) -> Result<Vec<u8>> {
+        self.merklize_hash_unchecked(&leaf_hash(&self.algorithm, self.hash_size, leaf))
+    }
+
+    // Merklize from a leaf hash
+    pub fn merklize_hash(&self, hash: &[u8]) -> Result<Vec<u8>> {
+        if hash.len() != self.hash_size as usize {
+            return Err(MerkleError::InvalidHashSize.into())
+        }
+        self.merklize_hash_unchecked(hash)
+    }
+
+    // Merklize from a hash (RFC 9162 2.1.3.2). NOTE: There are no length checks being performed on the hash here.
+    fn merklize_hash_unchecked(&self, hash: &[u8]) -> Result<Vec<u8>> {
+        let size = self.hash_size as usize;
+        if self.index >= self.leaf_count || self.hashes.len() % size != 0 {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        let (mut f, mut s) = (self.index, self.leaf_count - 1);
+        let mut h = hash.to_vec();
+        for sibling in self.hashes.chunks(size) {
+            if s == 0 {
+                return Err(MerkleError::InvalidProof.into())
+            }
+            if f % 2 == 1 || f == s {
+                h = node_hash(&self.algorithm, self.hash_size, sibling, &h);
+                // Skip the levels where we are the last node with no right sibling
+                while f % 2 == 0 && f != 0 {
+                    f >>= 1;
+                    s >>= 1;
+                }
+            } else {
+                h = node_hash(&self.algorithm, self.hash_size, &h, sibling);
+            }
+            f >>= 1;
+            s >>= 1;
+        }
+        if s != 0 {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        Ok(h)
+    }
+
+    pub fn get_leaf_count(&self) -> u64 {
+        self.leaf_count
+    }
+
+    pub fn get_index(&self) -> u64 {
+        self.index
+    }
+
+    pub fn get_pairing_hashes(&self) -> Vec<u8> {
+        self.hashes.clone()
+    }
+}
+
+impl TransparencyLogConsistencyProof {
+    pub fn new(algorithm: HashingAlgorithm, hash_size: u8, first_size: u64, second_size: u64, hashes: Vec<u8>) -> Self {
+        let mut hash_size = hash_size;
+        if hash_size == 0 || hash_size > 32 {
+            hash_size = 32
+        }
+        Self {
+            algorithm,
+            hash_size,
+            first_size,
+            second_size,
+            hashes
+        }
+    }
+
+    // Verify that second_root extends first_root (RFC 9162 2.1.4.2)
+    pub fn verify(&self, first_root: &[u8], second_root: &[u8]) -> Result<()> {
+        let size = self.hash_size as usize;
+        if self.first_size == 0 || self.first_size > self.second_size || self.hashes.len() % size != 0 {
+            return Err(MerkleError::InvalidProof.into())
+        }
+        if self.first_size == self.second_size {
+            return match self.hashes.is_empty() && first_root == second_root {
+                true => Ok(()),
+                false => Err(MerkleError::InvalidProof.into())
+            }
+        }
+        let mut hashes: Vec<&[u8]> = self.hashes.chunks(size).collect();
+        // If the old tree is a perfect subtree of the new one, its root is the starting node
+        if self.first_size.is_power_of_two() {
+            hashes.insert(0, first_root);
+        }
+        let (mut f, mut s) = (self.first_size - 1, self.second_size - 1);
+        while f % 2 == 1 {
+            f >>= 1;
+            s >>= 1;
+        }
+        let mut hashes = hashes.into_iter();
+        let mut fr = hashes.next().ok_or(MerkleError::InvalidProof)?.to_vec();
+        let mut sr = fr.clone();
+        for c in hashes {
+            if s == 0 {
+                return Err(MerkleError::InvalidProof.into())
+            }
+            if f % 2 == 1 || f == s {
+                fr = node_hash(&self.algorithm, self.hash_size, c, &fr);
+                sr = node_hash(&self.algorithm, self.hash_size, c, &sr);
+                while f % 2 == 0 && f != 0 {
+                    f >>= 1;
+                    s >>= 1;
+                }
+            } else {
+                sr = node_hash(&self.algorithm, self.hash_size, &sr, c);
+            }
+            f >>= 1;
+            s >>= 1;
+        }
+        match s == 0 && fr == first_root && sr == second_root {
+            true => Ok(()),
+            false => Err(MerkleError::InvalidProof.into())
+        }
+    }
+
+    pub fn get_first_size(&self) -> u64 {
+        self.first_size
+    }
+
+    pub fn get_second_size(&self) -> u64 {
+        self.second_size
+    }
+
+    pub fn get_pairing_hashes(&self) -> Vec<u8> {
+        self.hashes.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::HashingAlgorithm;
+    use hex_literal::hex;
+
+    use super::TransparencyLog;
+
+    fn leaves() -> Vec<Vec<u8>> {
+        vec![
+            vec![],
+            hex!("00").to_vec(),
+            hex!("10").to_vec(),
+            hex!("2021").to_vec(),
+            hex!("3031").to_vec(),
+            hex!("40414243").to_vec(),
+            hex!("5051525354555657").to_vec(),
+            hex!("606162636465666768696a6b6c6d6e6f").to_vec()
+        ]
+    }
+
+    #[test]
+    fn transparency_log_rfc6962_test() {
+        let roots = [
+            hex!("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+            hex!("6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d"),
+            hex!("fac54203e7cc696cf0dfcb42c92a1d9dbaf70ad9e621f4bd8d98662f00e3c125"),
+            hex!("aeb6bcfe274b70a14fb067a5e5578264db0fa9b51af5e0ba159158f329e06e77"),
+            hex!("d37ee418976dd95753c1c73862b9398fa2a2cf9b4ff0fdfe8b30cd95209614b7"),
+            hex!("4e3bbb1f7b478dcfe71fb631631519a3bca12c9aefca1612bfce4c13a86264d4"),
+            hex!("76e67dadbcdf1e10e1b74ddc608abd2f98dfb16fbce75277b5232a127f2087ef"),
+            hex!("ddb89be403809e325750d3d263cd78929c2942b7942a34b77e122c9594a74c8c"),
+            hex!("5dc9da79a70659a9ad559cb701ded9a2ab9d823aad2f4960cfe370eff4604328")
+        ];
+        let mut log = TransparencyLog::new(HashingAlgorithm::Sha256, 32);
+        assert_eq!(roots[0].to_vec(), log.get_merkle_root());
+        for (i, leaf) in leaves().iter().enumerate() {
+            log.append(leaf);
+            assert_eq!(roots[i + 1].to_vec(), log.get_merkle_root());
+        }
+        for (i, root) in roots.iter().enumerate() {
+            assert_eq!(root.to_vec(), log.get_merkle_root_at(i as u64).unwrap());
+        }
+        let proof = log.merkle_proof_index(0).unwrap();
+        assert_eq!([
+            hex!("96a296d224f285c67bee93c30f8a309157f0daa35dc5b87e410b78630a09cfc7"),
+            hex!("5f083f0a1a33ca076a95279832580db3e0ef4584bdff1f54c8a360f50de3031e"),
+            hex!("6b47aaf29ee3c2af9af889bc1fb9254dabd31177f16232dd6aab035ca39bf6e4")
+        ].concat(), proof.get_pairing_hashes());
+        assert_eq!(roots[8].to_vec(), proof.merklize(&[]).unwrap());
+    }
+
+    #[test]
+    fn transparency_log_proof_test() {
+        let mut log = TransparencyLog::new(HashingAlgorithm::Keccak, 20);
+        for n in 0..40u32 {
+            log.append(&n.to_le_bytes());
+        }
+        for size in 1..=40u64 {
+            let root = log.get_merkle_root_at(size).unwrap();
+            for i in 0..size {
+                let proof = log.merkle_proof_index_at(i, size).unwrap();
+                assert_eq!(root, proof.merklize(&(i as u32).to_le_bytes()).unwrap());
+                assert_ne!(root, proof.merklize(&(i as u32 + 1).to_le_bytes()).unwrap());
+            }
+            assert!(log.merkle_proof_index_at(size, size).is_err());
+        }
+    }
+
+    #[test]
+    fn transparency_log_consistency_test() {
+        let mut log = TransparencyLog::new(HashingAlgorithm::Sha256, 32);
+        for n in 0..33u32 {
+            log.append(&n.to_le_bytes());
+        }
+        for second in 1..=33u64 {
+            let second_root = log.get_merkle_root_at(second).unwrap();
+            for first in 1..=second {
+                let first_root = log.get_merkle_root_at(first).unwrap();
+                let proof = log.consistency_proof(first, second).unwrap();
+                proof.verify(&first_root, &second_root).unwrap();
+                if first != second {
+                    let other_root = log.get_merkle_root_at(first - 1).unwrap();
+                    assert!(proof.verify(&other_root, &second_root).is_err());
+                    assert!(proof.verify(&first_root, &first_root).is_err());
+                }
+            }
+        }
+        assert!(log.consistency_proof(0, 5).is_err());
+        assert!(log.consistency_proof(6, 5).is_err());
+        assert!(log.consistency_proof(5, 34).is_err());
+    }
+}
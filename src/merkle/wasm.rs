@@ -2,9 +2,12 @@ use wasm_bindgen::prelude::*;
 
 use crate::{
     HashingAlgorithm as RustHashingAlgorithm, 
+    PairingMode as RustPairingMode,
+    DomainSeparation as RustDomainSeparation,
     MerkleProof as RustMerkleProof,
+    MultiProof as RustMultiProof,
     MerkleTree as RustMerkleTree,
-    hashing_wasm::{sha256 as sha256_hash, keccak256 as keccak256_hash}
+    hashing_wasm::{sha256 as sha256_hash, keccak256 as keccak256_hash, blake3 as blake3_hash}
 };
 
 #[wasm_bindgen]
@@ -12,7 +15,22 @@ pub enum HashingAlgorithm {
     Sha256 = 0,
     Sha256d = 1,
     Keccak = 2,
-    Keccakd = 3
+    Keccakd = 3,
+    Blake3 = 4,
+    Blake3d = 5,
+    Poseidon = 6
+}
+
+#[wasm_bindgen]
+pub enum PairingMode {
+    Indexed = 0,
+    Sorted = 1
+}
+
+#[wasm_bindgen]
+pub enum DomainSeparation {
+    Untagged = 0,
+    Tagged = 1
 }
 
 #[wasm_bindgen]
@@ -25,6 +43,11 @@ pub fn keccak256(val: Vec<u8>) -> Vec<u8> {
     keccak256_hash(&val).to_vec()
 }
 
+#[wasm_bindgen]
+pub fn blake3(val: Vec<u8>) -> Vec<u8> {
+    blake3_hash(&val).to_vec()
+}
+
 impl From<HashingAlgorithm> for RustHashingAlgorithm {
     fn from(value: HashingAlgorithm) -> Self {
         match value {
@@ -32,6 +55,27 @@ impl From<HashingAlgorithm> for RustHashingAlgorithm {
             HashingAlgorithm::Sha256d => RustHashingAlgorithm::Sha256d,
             HashingAlgorithm::Keccak => RustHashingAlgorithm::Keccak,
             HashingAlgorithm::Keccakd => RustHashingAlgorithm::Keccakd,
+            HashingAlgorithm::Blake3 => RustHashingAlgorithm::Blake3,
+            HashingAlgorithm::Blake3d => RustHashingAlgorithm::Blake3d,
+            HashingAlgorithm::Poseidon => RustHashingAlgorithm::Poseidon,
+        }
+    }
+}
+
+impl From<PairingMode> for RustPairingMode {
+    fn from(value: PairingMode) -> Self {
+        match value {
+            PairingMode::Indexed => RustPairingMode::Indexed,
+            PairingMode::Sorted => RustPairingMode::Sorted,
+        }
+    }
+}
+
+impl From<DomainSeparation> for RustDomainSeparation {
+    fn from(value: DomainSeparation) -> Self {
+        match value {
+            DomainSeparation::Untagged => RustDomainSeparation::Untagged,
+            DomainSeparation::Tagged => RustDomainSeparation::Tagged,
         }
     }
 }
@@ -43,6 +87,9 @@ pub struct MerkleTree(RustMerkleTree);
 #[wasm_bindgen]
 pub struct MerkleProof(RustMerkleProof);
 
+#[wasm_bindgen]
+pub struct MultiProof(RustMultiProof);
+
 #[wasm_bindgen]
 impl MerkleTree {
     #[wasm_bindgen(constructor)]
@@ -53,6 +100,14 @@ impl MerkleTree {
         ))
     }
 
+    pub fn with_pairing_mode(self, pairing_mode: PairingMode) -> MerkleTree {
+        Self(self.0.with_pairing_mode(RustPairingMode::from(pairing_mode)))
+    }
+
+    pub fn with_domain_separation(self, domain_separation: DomainSeparation) -> MerkleTree {
+        Self(self.0.with_domain_separation(RustDomainSeparation::from(domain_separation)))
+    }
+
     pub fn add_leaf(&mut self, leaf: &[u8]) {
         self.0.add_leaf(leaf);
     }
@@ -61,6 +116,18 @@ impl MerkleTree {
         self.0.merklize().map_err(|e| JsError::new(&e.to_string()))
     }
 
+    pub fn merklize_strict(&mut self) -> Result<(), JsError> {
+        self.0.merklize_strict().map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    pub fn set_leaf(&mut self, i: usize, leaf: &[u8]) -> Result<(), JsError> {
+        self.0.set_leaf(i, leaf).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    pub fn validate(&self) -> Result<(), JsError> {
+        self.0.validate().map_err(|e| JsError::new(&e.to_string()))
+    }
+
     pub fn get_merkle_root(&self) -> Result<Vec<u8>, JsError> {
         self.0.get_merkle_root().map_err(|e| JsError::new(&e.to_string()))
     }
@@ -72,6 +139,10 @@ impl MerkleTree {
     pub fn merkle_proof_index(&self, i: usize) -> Result<MerkleProof, JsError> {
         Ok(MerkleProof(self.0.merkle_proof_index(i).map_err(|e| JsError::new(&e.to_string()))?))
     }
+
+    pub fn multiproof(&self, indices: Vec<u32>) -> Result<MultiProof, JsError> {
+        Ok(MultiProof(self.0.multiproof(&indices).map_err(|e| JsError::new(&e.to_string()))?))
+    }
 }
 
 #[wasm_bindgen]
@@ -86,6 +157,14 @@ impl MerkleProof {
         ))
     }
 
+    pub fn with_pairing_mode(self, pairing_mode: PairingMode) -> MerkleProof {
+        Self(self.0.with_pairing_mode(RustPairingMode::from(pairing_mode)))
+    }
+
+    pub fn with_domain_separation(self, domain_separation: DomainSeparation) -> MerkleProof {
+        Self(self.0.with_domain_separation(RustDomainSeparation::from(domain_separation)))
+    }
+
     pub fn merklize(&self, leaf: &[u8]) -> Result<Vec<u8>, JsError> {
         Ok(self.0.merklize(leaf).map_err(|e| JsError::new(&e.to_string()))?)
     }
@@ -94,7 +173,53 @@ impl MerkleProof {
         Ok(self.0.merklize_hash(hash).map_err(|e| JsError::new(&e.to_string()))?)
     }
 
+    pub fn merklize_strict(&self, leaf: &[u8], leaf_count: u32) -> Result<Vec<u8>, JsError> {
+        Ok(self.0.merklize_strict(leaf, leaf_count).map_err(|e| JsError::new(&e.to_string()))?)
+    }
+
+    pub fn update_root(&self, root: &[u8], old_leaf: &[u8], new_leaf: &[u8], leaf_count: u32) -> Result<Vec<u8>, JsError> {
+        Ok(self.0.update_root(root, old_leaf, new_leaf, leaf_count).map_err(|e| JsError::new(&e.to_string()))?)
+    }
+
+    pub fn append_root(&self, root: &[u8], last_leaf: &[u8], new_leaf: &[u8]) -> Result<Vec<u8>, JsError> {
+        Ok(self.0.append_root(root, last_leaf, new_leaf).map_err(|e| JsError::new(&e.to_string()))?)
+    }
+
+    pub fn get_pairing_hashes(&self) -> Vec<u8> {
+        self.0.get_pairing_hashes()
+    }
+}
+
+#[wasm_bindgen]
+impl MultiProof {
+    #[wasm_bindgen(constructor)]
+    pub fn new(algorithm: HashingAlgorithm, hash_size: u8, leaf_count: u32, indices: Vec<u32>, hashes: Vec<u8>) -> Self {
+        Self(RustMultiProof::new(
+            RustHashingAlgorithm::from(algorithm),
+            hash_size,
+            leaf_count,
+            indices,
+            hashes
+        ))
+    }
+
+    pub fn with_pairing_mode(self, pairing_mode: PairingMode) -> MultiProof {
+        Self(self.0.with_pairing_mode(RustPairingMode::from(pairing_mode)))
+    }
+
+    pub fn with_domain_separation(self, domain_separation: DomainSeparation) -> MultiProof {
+        Self(self.0.with_domain_separation(RustDomainSeparation::from(domain_separation)))
+    }
+
+    pub fn get_leaf_count(&self) -> u32 {
+        self.0.get_leaf_count()
+    }
+
+    pub fn get_indices(&self) -> Vec<u32> {
+        self.0.get_indices()
+    }
+
     pub fn get_pairing_hashes(&self) -> Vec<u8> {
         self.0.get_pairing_hashes()
     }
-}
\ No newline at end of file
+}